@@ -0,0 +1,174 @@
+//! Proof of work solver for the `register` extrinsic.
+//! Seals are computed with the pallet's own `create_seal_hash_for_block_hash` and checked
+//! with `hash_meets_difficulty`, so a solution found here is exactly what the runtime accepts.
+
+use std::{
+	str::FromStr,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		mpsc, Arc,
+	},
+	thread,
+};
+
+use node_subtensor_runtime::{
+	pallet_subtensor::{Pallet, SubtensorHyperparams, SEAL_VERSION},
+	AccountId, Balance, Runtime,
+};
+use serde_json::{json, Value};
+use sp_core::{crypto::Ss58Codec, H256, U256};
+
+type Subtensor = Pallet<Runtime>;
+
+/// Proof of work utilities.
+#[derive(Debug, clap::Subcommand)]
+pub enum PowCmd {
+	/// Solve the registration proof of work for a hotkey.
+	Register(RegisterCmd),
+}
+
+impl PowCmd {
+	/// Run the selected proof of work command.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		match self {
+			PowCmd::Register(cmd) => cmd.run(),
+		}
+	}
+}
+
+/// Searches nonces for a seal bound to `hotkey` and prints the `(block_number, nonce, work)`
+/// triple to pass to `register`. The block and difficulty are read from a node over RPC
+/// unless all of `--block-number`, `--block-hash` and `--difficulty` are given.
+#[derive(Debug, clap::Parser)]
+pub struct RegisterCmd {
+	/// SS58 address of the hotkey to register. The seal is only valid for this hotkey.
+	#[clap(long)]
+	pub hotkey: String,
+
+	/// HTTP RPC endpoint of the node to read the best block and difficulty from.
+	#[clap(long, default_value = "http://127.0.0.1:9933")]
+	pub rpc_url: String,
+
+	/// Number of the block to work on instead of the node's best block.
+	#[clap(long, requires_all = &["block_hash", "difficulty"])]
+	pub block_number: Option<u64>,
+
+	/// Hash of `--block-number`, as 0x prefixed hex.
+	#[clap(long, requires_all = &["block_number", "difficulty"])]
+	pub block_hash: Option<String>,
+
+	/// Difficulty the seal must meet instead of the node's current difficulty.
+	#[clap(long, requires_all = &["block_number", "block_hash"])]
+	pub difficulty: Option<u64>,
+
+	/// Number of threads searching nonces. Defaults to the number of CPUs.
+	#[clap(long)]
+	pub threads: Option<usize>,
+
+	/// First nonce to try.
+	#[clap(long, default_value = "0")]
+	pub start_nonce: u64,
+}
+
+impl RegisterCmd {
+	/// Solve the proof of work and print the solution as JSON.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let hotkey = AccountId::from_ss58check(&self.hotkey)
+			.map_err(|e| format!("invalid hotkey {}: {:?}", self.hotkey, e))?;
+		let (block_number, block_hash, difficulty) = match (self.block_number, &self.block_hash, self.difficulty) {
+			(Some(block_number), Some(block_hash), Some(difficulty)) =>
+				(block_number, parse_hash(block_hash)?, difficulty),
+			_ => self.fetch_work_params()?,
+		};
+		let threads = self
+			.threads
+			.unwrap_or_else(|| thread::available_parallelism().map(|n| n.get()).unwrap_or(1))
+			.max(1);
+
+		let (nonce, work) = solve(block_hash, hotkey, U256::from(difficulty), self.start_nonce, threads);
+		let solution = json!({
+			"block_number": block_number,
+			"nonce": nonce,
+			"work": format!("{:?}", work),
+		});
+		println!("{}", solution);
+		Ok(())
+	}
+
+	/// Reads the best block and the difficulty at it from the node, checking the runtime
+	/// expects the seal version this solver produces. Every query is pinned to the best block's
+	/// hash so the number, hash and difficulty all come from the same block.
+	fn fetch_work_params(&self) -> sc_cli::Result<(u64, H256, u64)> {
+		let block_hash = rpc_call(&self.rpc_url, "chain_getBlockHash", json!([]))?;
+		let block_hash = parse_hash(block_hash.as_str().ok_or("chain_getBlockHash returned no hash")?)?;
+
+		let hyperparams: SubtensorHyperparams<Balance> =
+			serde_json::from_value(rpc_call(&self.rpc_url, "subtensor_getHyperparameters", json!([block_hash]))?)
+				.map_err(|e| format!("invalid subtensor_getHyperparameters response: {}", e))?;
+		if hyperparams.seal_version != SEAL_VERSION {
+			return Err(format!(
+				"the node expects seal version {}, this solver produces version {}",
+				hyperparams.seal_version, SEAL_VERSION
+			)
+			.into())
+		}
+
+		let header = rpc_call(&self.rpc_url, "chain_getHeader", json!([block_hash]))?;
+		let number = header["number"].as_str().ok_or("chain_getHeader returned no block number")?;
+		let block_number = u64::from_str_radix(number.trim_start_matches("0x"), 16)
+			.map_err(|e| format!("invalid block number {}: {}", number, e))?;
+
+		Ok((block_number, block_hash, hyperparams.difficulty))
+	}
+}
+
+/// Searches nonces from `start_nonce` on `threads` threads, thread `i` trying every nonce
+/// congruent to `start_nonce + i` modulo `threads`. Returns the first nonce found with its seal.
+fn solve(block_hash: H256, hotkey: AccountId, difficulty: U256, start_nonce: u64, threads: usize) -> (u64, H256) {
+	let found = Arc::new(AtomicBool::new(false));
+	let (sender, receiver) = mpsc::channel();
+	for i in 0..threads {
+		let found = found.clone();
+		let sender = sender.clone();
+		let hotkey = hotkey.clone();
+		thread::spawn(move || {
+			let mut nonce = start_nonce.wrapping_add(i as u64);
+			while !found.load(Ordering::Relaxed) {
+				let seal = Subtensor::create_seal_hash_for_block_hash(&block_hash, nonce, &hotkey);
+				if Subtensor::hash_meets_difficulty(&seal, difficulty) {
+					found.store(true, Ordering::Relaxed);
+					let _ = sender.send((nonce, seal));
+					return
+				}
+				nonce = nonce.wrapping_add(threads as u64);
+			}
+		});
+	}
+	receiver.recv().expect("a solver thread sends its solution before exiting")
+}
+
+/// Makes a JSON-RPC call over HTTP and returns its result.
+fn rpc_call(url: &str, method: &str, params: Value) -> sc_cli::Result<Value> {
+	let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+	let request = hyper::Request::post(url)
+		.header(hyper::header::CONTENT_TYPE, "application/json")
+		.body(hyper::Body::from(request.to_string()))
+		.map_err(|e| format!("invalid rpc url {}: {}", url, e))?;
+	let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+	let body = runtime
+		.block_on(async {
+			let response = hyper::Client::new().request(request).await?;
+			hyper::body::to_bytes(response.into_body()).await
+		})
+		.map_err(|e| format!("{} failed: {}", method, e))?;
+	let mut response: Value =
+		serde_json::from_slice(&body).map_err(|e| format!("invalid {} response: {}", method, e))?;
+	if let Some(error) = response.get("error") {
+		return Err(format!("{} failed: {}", method, error).into())
+	}
+	Ok(response["result"].take())
+}
+
+fn parse_hash(hash: &str) -> sc_cli::Result<H256> {
+	H256::from_str(hash.trim_start_matches("0x")).map_err(|e| format!("invalid block hash {}: {:?}", hash, e).into())
+}
@@ -0,0 +1,224 @@
+//! JSON-RPC methods for the subtensor pallet.
+//! Every method takes an optional block hash and falls back to the best block,
+//! so clients can read the metagraph as it was at any block still in state.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+use pallet_subtensor::{DelegateInfo, InvariantViolation, NeuronMetadata, OwnedHotkeyInfo, RegistrationWorkError, SubtensorHyperparams, UnlockChunk};
+pub use subtensor_custom_rpc_runtime_api::SubtensorApi as SubtensorRuntimeApi;
+
+#[rpc]
+pub trait SubtensorApi<BlockHash, AccountId, Balance> {
+	/// Returns the neuron registered under `uid`, or null.
+	#[rpc(name = "subtensor_getNeuron")]
+	fn get_neuron(&self, uid: u32, at: Option<BlockHash>) -> Result<Option<NeuronMetadata<AccountId, Balance>>>;
+
+	/// Returns all registered neurons ordered by uid.
+	#[rpc(name = "subtensor_getNeurons")]
+	fn get_neurons(&self, at: Option<BlockHash>) -> Result<Vec<NeuronMetadata<AccountId, Balance>>>;
+
+	/// Returns the neuron registered with `hotkey`, or null.
+	#[rpc(name = "subtensor_getNeuronByHotkey")]
+	fn get_neuron_by_hotkey(
+		&self,
+		hotkey: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<Option<NeuronMetadata<AccountId, Balance>>>;
+
+	/// Returns the hotkeys registered by `coldkey` with their uid, stake and last emission.
+	#[rpc(name = "subtensor_getHotkeysForColdkey")]
+	fn get_hotkeys_for_coldkey(
+		&self,
+		coldkey: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<OwnedHotkeyInfo<AccountId, Balance>>>;
+
+	/// Returns a versioned snapshot of every sudo settable hyperparameter.
+	#[rpc(name = "subtensor_getHyperparameters")]
+	fn get_hyperparameters(&self, at: Option<BlockHash>) -> Result<SubtensorHyperparams<Balance>>;
+
+	/// Returns every delegate hotkey with its nominators.
+	#[rpc(name = "subtensor_getDelegates")]
+	fn get_delegates(&self, at: Option<BlockHash>) -> Result<Vec<DelegateInfo<AccountId, Balance>>>;
+
+	/// Returns the delegate registered with `hotkey`, or null.
+	#[rpc(name = "subtensor_getDelegate")]
+	fn get_delegate(&self, hotkey: AccountId, at: Option<BlockHash>) -> Result<Option<DelegateInfo<AccountId, Balance>>>;
+
+	/// Returns each delegate `coldkey` nominates with the stake its shares are worth.
+	#[rpc(name = "subtensor_getNominationsForColdkey")]
+	fn get_nominations_for_coldkey(
+		&self,
+		coldkey: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(AccountId, Balance)>>;
+
+	/// Returns the stake `coldkey` has removed and not yet withdrawn, oldest chunk first.
+	#[rpc(name = "subtensor_getPendingUnlocks")]
+	fn get_pending_unlocks(&self, coldkey: AccountId, at: Option<BlockHash>) -> Result<Vec<UnlockChunk<Balance>>>;
+
+	/// Returns every broken stake and issuance accounting invariant, empty when the state is consistent.
+	#[rpc(name = "subtensor_getInvariantViolations")]
+	fn get_invariant_violations(&self, at: Option<BlockHash>) -> Result<Vec<InvariantViolation<Balance>>>;
+
+	/// Runs the checks `register` makes on a proof of work without submitting it.
+	/// Returns why the work would be refused for `hotkey`, or null when it would be accepted.
+	#[rpc(name = "subtensor_checkRegistrationWork")]
+	fn check_registration_work(
+		&self,
+		block_number: u64,
+		nonce: u64,
+		work: Vec<u8>,
+		hotkey: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<Option<RegistrationWorkError>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Implements the [`SubtensorApi`] RPC trait for interacting with the subtensor pallet.
+pub struct Subtensor<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Subtensor<C, B> {
+	/// Creates a new instance of the subtensor RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId, Balance> SubtensorApi<<Block as BlockT>::Hash, AccountId, Balance> for Subtensor<C, Block>
+where
+	Block: BlockT,
+	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: SubtensorRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec,
+	Balance: Codec,
+{
+	fn get_neuron(
+		&self,
+		uid: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<NeuronMetadata<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.get_neuron(&at, uid).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_neurons(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<NeuronMetadata<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.get_neurons(&at).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_neuron_by_hotkey(
+		&self,
+		hotkey: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<NeuronMetadata<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.get_neuron_by_hotkey(&at, hotkey).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_hotkeys_for_coldkey(
+		&self,
+		coldkey: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<OwnedHotkeyInfo<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.get_hotkeys_for_coldkey(&at, coldkey).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_hyperparameters(&self, at: Option<<Block as BlockT>::Hash>) -> Result<SubtensorHyperparams<Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.get_hyperparameters(&at).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_delegates(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<DelegateInfo<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.get_delegates(&at).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_delegate(
+		&self,
+		hotkey: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<DelegateInfo<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.get_delegate(&at, hotkey).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_nominations_for_coldkey(
+		&self,
+		coldkey: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(AccountId, Balance)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.get_nominations_for_coldkey(&at, coldkey).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_pending_unlocks(
+		&self,
+		coldkey: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<UnlockChunk<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.get_pending_unlocks(&at, coldkey).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_invariant_violations(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<InvariantViolation<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.get_invariant_violations(&at).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn check_registration_work(
+		&self,
+		block_number: u64,
+		nonce: u64,
+		work: Vec<u8>,
+		hotkey: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<RegistrationWorkError>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.check_registration_work(&at, block_number, nonce, work, hotkey).map_err(runtime_error_into_rpc_err)
+	}
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: "Runtime error".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
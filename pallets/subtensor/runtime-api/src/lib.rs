@@ -0,0 +1,49 @@
+//! Runtime API definitions for the subtensor pallet.
+//! These are implemented by the runtime in `runtime/src/lib.rs` and served
+//! to clients by the `subtensor-custom-rpc` crate.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+use pallet_subtensor::{DelegateInfo, InvariantViolation, NeuronMetadata, OwnedHotkeyInfo, RegistrationWorkError, SubtensorHyperparams, UnlockChunk};
+
+sp_api::decl_runtime_apis! {
+	pub trait SubtensorApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Returns the neuron registered under `uid`, if any.
+		fn get_neuron( uid: u32 ) -> Option<NeuronMetadata<AccountId, Balance>>;
+
+		/// Returns all registered neurons ordered by uid.
+		fn get_neurons() -> Vec<NeuronMetadata<AccountId, Balance>>;
+
+		/// Returns the neuron registered with `hotkey`, if any.
+		fn get_neuron_by_hotkey( hotkey: AccountId ) -> Option<NeuronMetadata<AccountId, Balance>>;
+
+		/// Returns the hotkeys registered by `coldkey` with their uid, stake and last emission.
+		fn get_hotkeys_for_coldkey( coldkey: AccountId ) -> Vec<OwnedHotkeyInfo<AccountId, Balance>>;
+
+		/// Returns a versioned snapshot of every sudo settable hyperparameter.
+		fn get_hyperparameters() -> SubtensorHyperparams<Balance>;
+
+		/// Returns every delegate hotkey with its nominators.
+		fn get_delegates() -> Vec<DelegateInfo<AccountId, Balance>>;
+
+		/// Returns the delegate registered with `hotkey`, if any.
+		fn get_delegate( hotkey: AccountId ) -> Option<DelegateInfo<AccountId, Balance>>;
+
+		/// Returns each delegate `coldkey` nominates with the stake its shares are worth.
+		fn get_nominations_for_coldkey( coldkey: AccountId ) -> Vec<(AccountId, Balance)>;
+
+		/// Returns the stake `coldkey` has removed and not yet withdrawn, oldest chunk first.
+		fn get_pending_unlocks( coldkey: AccountId ) -> Vec<UnlockChunk<Balance>>;
+
+		/// Returns every broken stake and issuance accounting invariant, empty when the state is consistent.
+		fn get_invariant_violations() -> Vec<InvariantViolation<Balance>>;
+
+		/// Returns why register would refuse this proof of work for `hotkey`, if it would.
+		fn check_registration_work( block_number: u64, nonce: u64, work: Vec<u8>, hotkey: AccountId ) -> Option<RegistrationWorkError>;
+	}
+}
@@ -1,20 +1,441 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarks for pallet-subtensor. Run through the node's `benchmark` subcommand
+//! and write the results to `weight_info.rs`.
 
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use crate::Pallet as Subtensor;
+use frame_benchmarking::{account, benchmarks};
 use frame_system::RawOrigin;
 
+const SEED: u32 = 0;
+
+/// Largest graph the parameterised benchmarks are run against.
+const MAX_NEURONS: u32 = 4096;
+
+/// Writes a neuron under the next uid the way do_registration does, skipping the proof of work.
+fn register_neuron<T: Config>( index: u32 ) -> ( T::AccountId, T::AccountId, u32 ) {
+	let hotkey: T::AccountId = account( "hotkey", index, SEED );
+	let coldkey: T::AccountId = account( "coldkey", index, SEED );
+	let uid: u32 = Subtensor::<T>::get_next_uid();
+	BlockAtRegistration::<T>::insert( uid, 0 );
+	Subtensor::<T>::init_neuron( uid, &hotkey, &coldkey, 0 );
+	Hotkeys::<T>::insert( &hotkey, uid );
+	ColdkeyHotkeys::<T>::append( &coldkey, &hotkey );
+	( hotkey, coldkey, uid )
+}
+
+/// Queues `chunks` unlocking chunks on the coldkey, the i-th unlocking at block i + 1.
+fn queue_unlocking_chunks<T: Config>( coldkey: &T::AccountId, chunks: u32 ) {
+	let queue: Vec<UnlockChunk<BalanceOf<T>>> = ( 0..chunks as u64 ).map( |i| UnlockChunk { amount: 1_000u32.into(), unlock_block: i + 1 } ).collect();
+	Unbonding::<T>::insert( coldkey, queue );
+}
+
+/// Schedules `swaps` coldkey swaps at the block a swap announced now would execute at.
+fn fill_coldkey_swaps_due<T: Config>( swaps: u32 ) {
+	let execution_block: u64 = Subtensor::<T>::get_current_block_as_u64() + Subtensor::<T>::get_coldkey_swap_delay().max( 1 );
+	for index in 0..swaps {
+		let coldkey: T::AccountId = account( "announcer", index, SEED );
+		ColdkeySwapsDue::<T>::append( execution_block, &coldkey );
+	}
+}
+
+/// Opens the hotkey to nominators and has `nominators` coldkeys delegate stake to it.
+fn add_nominators<T: Config>( hotkey: &T::AccountId, coldkey: &T::AccountId, nominators: u32 ) {
+	let amount: BalanceOf<T> = 1_000_000_000u32.into();
+	Subtensor::<T>::do_become_delegate( RawOrigin::Signed( coldkey.clone() ).into(), hotkey.clone() ).unwrap();
+	for index in 0..nominators {
+		let nominator: T::AccountId = account( "nominator", index, SEED );
+		Subtensor::<T>::add_balance_to_coldkey_account( &nominator, amount + amount );
+		Subtensor::<T>::do_delegate_stake( RawOrigin::Signed( nominator ).into(), hotkey.clone(), amount ).unwrap();
+	}
+}
+
+/// Fills the graph with n neurons.
+fn register_neurons<T: Config>( n: u32 ) {
+	for index in 0..n {
+		register_neuron::<T>( index );
+	}
+}
+
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
-		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+	set_weights {
+		let n in 1 .. MAX_NEURONS;
+		Subtensor::<T>::set_max_allowed_uids( n as u64 );
+		Subtensor::<T>::set_min_allowed_weights( 0 );
+		Subtensor::<T>::set_max_weight_limit( u32::MAX );
+		register_neurons::<T>( n );
+		let hotkey: T::AccountId = account( "hotkey", 0, SEED );
+		let dests: Vec<u32> = (0..n).collect();
+		let weights: Vec<u32> = vec![ u32::MAX / n; n as usize ];
+	}: _( RawOrigin::Signed( hotkey ), dests, weights )
+	verify {
+		assert_eq!( Weights::<T>::get( 0 ).len(), n as usize );
+	}
+
+	add_stake {
+		let ( hotkey, coldkey, uid ) = register_neuron::<T>( 0 );
+		let amount: BalanceOf<T> = 1_000_000_000u32.into();
+		Subtensor::<T>::add_balance_to_coldkey_account( &coldkey, amount + amount );
+	}: _( RawOrigin::Signed( coldkey ), hotkey, amount )
+	verify {
+		assert_eq!( Stake::<T>::get( uid ), amount );
+	}
+
+	remove_stake {
+		let ( hotkey, coldkey, uid ) = register_neuron::<T>( 0 );
+		let amount: BalanceOf<T> = 1_000_000_000u32.into();
+		Stake::<T>::insert( uid, amount );
+		Subtensor::<T>::increase_total_stake( amount );
+		queue_unlocking_chunks::<T>( &coldkey, MAX_UNBONDING_CHUNKS - 1 );
+	}: _( RawOrigin::Signed( coldkey.clone() ), hotkey, amount )
+	verify {
+		assert!( Stake::<T>::get( uid ).is_zero() );
+		assert_eq!( Subtensor::<T>::get_pending_unlocks( &coldkey ).len() as u32, MAX_UNBONDING_CHUNKS );
+	}
+
+	move_stake {
+		let ( from_hotkey, coldkey, from_uid ) = register_neuron::<T>( 0 );
+		let to_hotkey: T::AccountId = account( "hotkey", 1, SEED );
+		let to_uid: u32 = Subtensor::<T>::get_next_uid();
+		BlockAtRegistration::<T>::insert( to_uid, 0 );
+		Subtensor::<T>::init_neuron( to_uid, &to_hotkey, &coldkey, 0 );
+		Hotkeys::<T>::insert( &to_hotkey, to_uid );
+		ColdkeyHotkeys::<T>::append( &coldkey, &to_hotkey );
+		let amount: BalanceOf<T> = 1_000_000_000u32.into();
+		Subtensor::<T>::add_stake_to_neuron_hotkey_account( from_uid, amount );
+	}: _( RawOrigin::Signed( coldkey ), from_hotkey, to_hotkey, amount )
+	verify {
+		assert!( Stake::<T>::get( from_uid ).is_zero() );
+		assert_eq!( Stake::<T>::get( to_uid ), amount );
+	}
+
+	set_emission_destination {
+		let ( hotkey, coldkey, _ ) = register_neuron::<T>( 0 );
+	}: _( RawOrigin::Signed( coldkey ), hotkey.clone(), EmissionDestination::Coldkey )
+	verify {
+		assert_eq!( Subtensor::<T>::emission_destination( &hotkey ), EmissionDestination::Coldkey );
+	}
+
+	become_delegate {
+		let ( hotkey, coldkey, _ ) = register_neuron::<T>( 0 );
+	}: _( RawOrigin::Signed( coldkey ), hotkey.clone() )
+	verify {
+		assert!( Subtensor::<T>::is_delegate( &hotkey ) );
+	}
+
+	// Worst case: the take changed before, so the rate limit is checked.
+	set_take {
+		let ( hotkey, coldkey, _ ) = register_neuron::<T>( 0 );
+		Subtensor::<T>::set_take_rate_limit( 0 );
+		Subtensor::<T>::do_set_take( RawOrigin::Signed( coldkey.clone() ).into(), hotkey.clone(), 0 ).unwrap();
+		let take: u16 = Subtensor::<T>::get_max_take();
+	}: _( RawOrigin::Signed( coldkey ), hotkey.clone(), take )
+	verify {
+		assert_eq!( Subtensor::<T>::take( &hotkey ), take );
+	}
+
+	// A new nominator joins a delegate that already has every other nominator slot taken.
+	delegate_stake {
+		let ( hotkey, coldkey, _ ) = register_neuron::<T>( 0 );
+		add_nominators::<T>( &hotkey, &coldkey, MAX_NOMINATORS_PER_DELEGATE - 1 );
+		let nominator: T::AccountId = account( "nominator", MAX_NOMINATORS_PER_DELEGATE, SEED );
+		let amount: BalanceOf<T> = 1_000_000_000u32.into();
+		Subtensor::<T>::add_balance_to_coldkey_account( &nominator, amount + amount );
+	}: _( RawOrigin::Signed( nominator.clone() ), hotkey.clone(), amount )
+	verify {
+		assert_eq!( Subtensor::<T>::get_nominator_stake( &hotkey, &nominator ), amount );
+	}
+
+	// A nominator withdraws part of its stake, keeping its shares entry.
+	undelegate_stake {
+		let ( hotkey, coldkey, _ ) = register_neuron::<T>( 0 );
+		add_nominators::<T>( &hotkey, &coldkey, 2 );
+		let nominator: T::AccountId = account( "nominator", 0, SEED );
+	}: _( RawOrigin::Signed( nominator.clone() ), hotkey.clone(), 500_000_000u32.into() )
+	verify {
+		assert_eq!( Subtensor::<T>::get_nominator_stake( &hotkey, &nominator ), BalanceOf::<T>::from( 500_000_000u32 ) );
+	}
+
+	// Worst case: a full queue of which half has unlocked.
+	withdraw_unbonded {
+		let coldkey: T::AccountId = account( "coldkey", 0, SEED );
+		queue_unlocking_chunks::<T>( &coldkey, MAX_UNBONDING_CHUNKS );
+		frame_system::Pallet::<T>::set_block_number( ( MAX_UNBONDING_CHUNKS / 2 ).into() );
+	}: _( RawOrigin::Signed( coldkey.clone() ) )
+	verify {
+		assert_eq!( Subtensor::<T>::get_pending_unlocks( &coldkey ).len() as u32, MAX_UNBONDING_CHUNKS / 2 );
+	}
+
+	// Worst case: the hotkey is a delegate with a full set of nominators.
+	swap_hotkey {
+		let ( old_hotkey, coldkey, uid ) = register_neuron::<T>( 0 );
+		add_nominators::<T>( &old_hotkey, &coldkey, MAX_NOMINATORS_PER_DELEGATE );
+		let new_hotkey: T::AccountId = account( "hotkey", 1, SEED );
+		let cost: BalanceOf<T> = Subtensor::<T>::get_hotkey_swap_cost();
+		Subtensor::<T>::add_balance_to_coldkey_account( &coldkey, cost + cost + 1_000_000_000u32.into() );
+		Subtensor::<T>::do_set_emission_destination( RawOrigin::Signed( coldkey.clone() ).into(), old_hotkey.clone(), EmissionDestination::Coldkey ).unwrap();
+		Subtensor::<T>::do_set_take( RawOrigin::Signed( coldkey.clone() ).into(), old_hotkey.clone(), 1 ).unwrap();
+	}: _( RawOrigin::Signed( coldkey ), old_hotkey, new_hotkey.clone() )
+	verify {
+		assert_eq!( Subtensor::<T>::get_uid_for_hotkey( &new_hotkey ), uid );
+		assert!( Subtensor::<T>::is_delegate( &new_hotkey ) );
+	}
+
+	// Worst case: the execution block already holds all but one swap, and the hotkeys of each are counted.
+	announce_coldkey_swap {
+		let ( _, coldkey, _ ) = register_neuron::<T>( 0 );
+		let new_coldkey: T::AccountId = account( "coldkey", 1, SEED );
+		fill_coldkey_swaps_due::<T>( MAX_COLDKEY_SWAPS_PER_BLOCK - 1 );
+	}: _( RawOrigin::Signed( coldkey.clone() ), new_coldkey )
+	verify {
+		assert!( ColdkeySwaps::<T>::contains_key( &coldkey ) );
+	}
+
+	// Worst case: the swap shares its execution block with a full set of swaps.
+	cancel_coldkey_swap {
+		let ( _, coldkey, _ ) = register_neuron::<T>( 0 );
+		let new_coldkey: T::AccountId = account( "coldkey", 1, SEED );
+		fill_coldkey_swaps_due::<T>( MAX_COLDKEY_SWAPS_PER_BLOCK - 1 );
+		Subtensor::<T>::do_announce_coldkey_swap( RawOrigin::Signed( coldkey.clone() ).into(), new_coldkey ).unwrap();
+	}: _( RawOrigin::Signed( coldkey.clone() ) )
+	verify {
+		assert!( !ColdkeySwaps::<T>::contains_key( &coldkey ) );
+	}
+
+	serve_axon {
+		let ( hotkey, _, uid ) = register_neuron::<T>( 0 );
+		let ip: u128 = 0x08080808; // 8.8.8.8
+	}: _( RawOrigin::Signed( hotkey ), 1, ip, 8883, 4, 0 )
+	verify {
+		assert_eq!( Axons::<T>::get( uid ).ip, ip );
+	}
+
+	// Worst case: the graph is full so every neuron is scored and the lowest one pruned.
+	// Neurons are scored by incentive, so uid 0 is pruned and pays out a full set of nominators.
+	// The work is a legacy seal, which is only accepted after the hotkey bound seal is checked.
+	register {
+		let n in 1 .. MAX_NEURONS;
+		Subtensor::<T>::set_max_allowed_uids( n as u64 );
+		Subtensor::<T>::set_immunity_period( 0 );
+		Subtensor::<T>::set_stake_pruning_min( u64::MAX );
+		Subtensor::<T>::set_difficulty_from_u64( 1 );
+		Subtensor::<T>::set_legacy_seal_cutoff( u64::MAX );
+		register_neurons::<T>( n );
+		add_nominators::<T>( &account( "hotkey", 0, SEED ), &account( "coldkey", 0, SEED ), MAX_NOMINATORS_PER_DELEGATE );
+		let hotkey: T::AccountId = account( "hotkey", n, SEED );
+		let coldkey: T::AccountId = account( "coldkey", n, SEED );
+		let block_number: u64 = Subtensor::<T>::get_current_block_as_u64();
+		let ( nonce, work ): ( u64, Vec<u8> ) = Subtensor::<T>::create_work_for_block_number( block_number, 0 );
+	}: _( RawOrigin::Signed( hotkey.clone() ), block_number, nonce, work, hotkey.clone(), coldkey )
+	verify {
+		assert!( Subtensor::<T>::is_hotkey_active( &hotkey ) );
+	}
+
+	// Worst case: as for register, with the burn withdrawn from the coldkey.
+	burned_register {
+		let n in 1 .. MAX_NEURONS;
+		Subtensor::<T>::set_max_allowed_uids( n as u64 );
+		Subtensor::<T>::set_immunity_period( 0 );
+		Subtensor::<T>::set_stake_pruning_min( u64::MAX );
+		register_neurons::<T>( n );
+		add_nominators::<T>( &account( "hotkey", 0, SEED ), &account( "coldkey", 0, SEED ), MAX_NOMINATORS_PER_DELEGATE );
+		let hotkey: T::AccountId = account( "hotkey", n, SEED );
+		let coldkey: T::AccountId = account( "coldkey", n, SEED );
+		let burn: BalanceOf<T> = Subtensor::<T>::get_burn();
+		Subtensor::<T>::add_balance_to_coldkey_account( &coldkey, burn + burn );
+	}: _( RawOrigin::Signed( coldkey.clone() ), hotkey.clone() )
+	verify {
+		assert!( Subtensor::<T>::is_hotkey_active( &hotkey ) );
+	}
+
+	sudo_set_blocks_per_step {}: _( RawOrigin::Root, 100 )
+	verify { assert_eq!( Subtensor::<T>::get_blocks_per_step(), 100 ); }
+
+	sudo_set_bonds_moving_average {}: _( RawOrigin::Root, 900_000 )
+	verify { assert_eq!( Subtensor::<T>::get_bonds_moving_average(), 900_000 ); }
+
+	sudo_set_difficulty {}: _( RawOrigin::Root, 10_000_000 )
+	verify { assert_eq!( Subtensor::<T>::get_difficulty_as_u64(), 10_000_000 ); }
+
+	sudo_set_adjustment_interval {}: _( RawOrigin::Root, 100 )
+	verify { assert_eq!( Subtensor::<T>::get_adjustment_interval(), 100 ); }
+
+	sudo_set_activity_cutoff {}: _( RawOrigin::Root, 5000 )
+	verify { assert_eq!( Subtensor::<T>::get_activity_cutoff(), 5000 ); }
+
+	sudo_target_registrations_per_interval {}: _( RawOrigin::Root, 2 )
+	verify { assert_eq!( Subtensor::<T>::get_target_registrations_per_interval(), 2 ); }
+
+	sudo_set_rho {}: _( RawOrigin::Root, 10 )
+	verify { assert_eq!( Subtensor::<T>::get_rho(), 10 ); }
+
+	sudo_set_kappa {}: _( RawOrigin::Root, 2 )
+	verify { assert_eq!( Subtensor::<T>::get_kappa(), 2 ); }
+
+	sudo_set_max_allowed_uids {}: _( RawOrigin::Root, 4096 )
+	verify { assert_eq!( Subtensor::<T>::get_max_allowed_uids(), 4096 ); }
+
+	sudo_set_min_allowed_weights {}: _( RawOrigin::Root, 1024 )
+	verify { assert_eq!( Subtensor::<T>::get_min_allowed_weights(), 1024 ); }
+
+	sudo_set_max_allowed_max_min_ratio {}: _( RawOrigin::Root, 64 )
+	verify { assert_eq!( Subtensor::<T>::get_max_allowed_max_min_ratio(), 64 ); }
+
+	sudo_set_max_weight_limit {}: _( RawOrigin::Root, u32::MAX )
+	verify { assert_eq!( Subtensor::<T>::get_max_weight_limit(), u32::MAX ); }
+
+	sudo_set_validator_batch_size {}: _( RawOrigin::Root, 32 )
+	verify { assert_eq!( Subtensor::<T>::get_validator_batch_size(), 32 ); }
+
+	sudo_set_validator_sequence_length {}: _( RawOrigin::Root, 128 )
+	verify { assert_eq!( Subtensor::<T>::get_validator_sequence_length(), 128 ); }
+
+	sudo_set_validator_epoch_len {}: _( RawOrigin::Root, 250 )
+	verify { assert_eq!( Subtensor::<T>::get_validator_epoch_len(), 250 ); }
+
+	sudo_set_validator_epochs_per_reset {}: _( RawOrigin::Root, 60 )
+	verify { assert_eq!( Subtensor::<T>::get_validator_epochs_per_reset(), 60 ); }
+
+	sudo_set_incentive_pruning_denominator {}: _( RawOrigin::Root, 1 )
+	verify { assert_eq!( Subtensor::<T>::get_incentive_pruning_denominator(), 1 ); }
+
+	sudo_set_stake_pruning_denominator {}: _( RawOrigin::Root, 20 )
+	verify { assert_eq!( Subtensor::<T>::get_stake_pruning_denominator(), 20 ); }
+
+	sudo_set_stake_pruning_min {}: _( RawOrigin::Root, 1024 )
+	verify { assert_eq!( Subtensor::<T>::get_stake_pruning_min(), 1024 ); }
+
+	sudo_set_immunity_period {}: _( RawOrigin::Root, 2048 )
+	verify { assert_eq!( Subtensor::<T>::get_immunity_period(), 2048 ); }
+
+	sudo_set_scaling_law_power {}: _( RawOrigin::Root, 50 )
+	verify { assert_eq!( Subtensor::<T>::get_scaling_law_power(), 50 ); }
+
+	sudo_set_synergy_scaling_law_power {}: _( RawOrigin::Root, 60 )
+	verify { assert_eq!( Subtensor::<T>::get_synergy_scaling_law_power(), 60 ); }
+
+	sudo_set_validator_exclude_quantile {}: _( RawOrigin::Root, 10 )
+	verify { assert_eq!( Subtensor::<T>::get_validator_exclude_quantile(), 10 ); }
+
+	sudo_set_used_work_retention {}: _( RawOrigin::Root, 100 )
+	verify { assert_eq!( Subtensor::<T>::get_used_work_retention(), 100 ); }
+
+	sudo_set_unbonding_period {}: _( RawOrigin::Root, 100 )
+	verify { assert_eq!( Subtensor::<T>::get_unbonding_period(), 100 ); }
+
+	sudo_set_hotkey_swap_cost {
+		let cost: BalanceOf<T> = 100u32.into();
+	}: _( RawOrigin::Root, cost )
+	verify { assert_eq!( Subtensor::<T>::get_hotkey_swap_cost(), cost ); }
+
+	sudo_set_coldkey_swap_delay {}: _( RawOrigin::Root, 100 )
+	verify { assert_eq!( Subtensor::<T>::get_coldkey_swap_delay(), 100 ); }
+
+	sudo_set_max_take {}: _( RawOrigin::Root, 100 )
+	verify { assert_eq!( Subtensor::<T>::get_max_take(), 100 ); }
+
+	sudo_set_take_rate_limit {}: _( RawOrigin::Root, 100 )
+	verify { assert_eq!( Subtensor::<T>::get_take_rate_limit(), 100 ); }
+
+	sudo_set_burn {
+		let burn: BalanceOf<T> = Subtensor::<T>::get_min_burn();
+	}: _( RawOrigin::Root, burn )
+	verify { assert_eq!( Subtensor::<T>::get_burn(), burn ); }
+
+	sudo_set_legacy_seal_cutoff {}: _( RawOrigin::Root, 100 )
+	verify { assert_eq!( Subtensor::<T>::get_legacy_seal_cutoff(), 100 ); }
+
+	sudo_set_min_difficulty {}: _( RawOrigin::Root, 100 )
+	verify { assert_eq!( Subtensor::<T>::get_min_difficulty(), 100 ); }
+
+	sudo_set_max_difficulty {}: _( RawOrigin::Root, u64::MAX )
+	verify { assert_eq!( Subtensor::<T>::get_max_difficulty(), u64::MAX ); }
+
+	sudo_set_min_burn {
+		let min_burn: BalanceOf<T> = Zero::zero();
+	}: _( RawOrigin::Root, min_burn )
+	verify { assert_eq!( Subtensor::<T>::get_min_burn(), min_burn ); }
+
+	sudo_set_max_burn {
+		let max_burn: BalanceOf<T> = BalanceOf::<T>::max_value();
+	}: _( RawOrigin::Root, max_burn )
+	verify { assert_eq!( Subtensor::<T>::get_max_burn(), max_burn ); }
+
+	// Worst case: every neuron holds a bond in every other neuron.
+	sudo_reset_bonds {
+		let n in 1 .. 256;
+		register_neurons::<T>( n );
+		Subtensor::<T>::set_bonds_from_matrix( vec![ vec![ 1; n as usize ]; n as usize ] );
+	}: _( RawOrigin::Root )
+	verify {
+		assert_eq!( Subtensor::<T>::get_bond( 0, 0 ), 0 );
+	}
+
+	// The stake of every neuron is summed.
+	sudo_repair_totals {
+		let n in 1 .. MAX_NEURONS;
+		register_neurons::<T>( n );
+		TotalStake::<T>::put( BalanceOf::<T>::zero() );
+	}: _( RawOrigin::Root )
+	verify {
+		assert!( Subtensor::<T>::check_invariants().is_empty() );
+	}
+
+	// Every neuron is active and staked, sets w weights and holds b bonds, so each of them is
+	// scored and its bond row rewritten. The dense n by n bond matrix makes the step quadratic
+	// in n, so n runs over every graph size up to MaxAllowedUids.
+	mechanism_step {
+		let n in 1 .. MAX_NEURONS;
+		let w in 0 .. 256;
+		let b in 0 .. 256;
+		let stake: u64 = 1_000_000_000;
+		Subtensor::<T>::set_max_allowed_uids( n as u64 );
+		register_neurons::<T>( n );
+		let block: u64 = Subtensor::<T>::get_current_block_as_u64();
+		for uid in 0..n {
+			Stake::<T>::insert( uid, stake.saturated_into::<BalanceOf<T>>() );
+			LastUpdate::<T>::insert( uid, block );
+			let row: Vec<(u32,u32)> = (0..w.min( n )).map( |j| ( (uid + 1 + j) % n, u32::MAX / w ) ).collect();
+			Weights::<T>::insert( uid, row );
+			for j in 0..b.min( n ) {
+				Bonds::<T>::insert( uid, (uid + j) % n, stake );
+			}
+		}
+		Subtensor::<T>::increase_total_stake( ( stake * n as u64 ).saturated_into() );
+	}: {
+		Subtensor::<T>::mechanism_step( stake );
+	}
+	verify {
+		assert_eq!( Subtensor::<T>::get_last_mechanism_step_block(), block );
+	}
+
+	// A block that only advances the step counter. The difficulty update, coldkey swaps and seal
+	// pruning the hook also runs are left with nothing to do, they are benchmarked on their own.
+	on_initialize {
+		Subtensor::<T>::set_blocks_per_step( 2 );
+		Subtensor::<T>::set_blocks_since_last_step( 0 );
+		Subtensor::<T>::set_adjustment_interval( u64::MAX );
+		let block: T::BlockNumber = frame_system::Pallet::<T>::block_number();
+	}: {
+		<Subtensor<T> as frame_support::traits::Hooks<T::BlockNumber>>::on_initialize( block );
+	}
+	verify {
+		assert_eq!( Subtensor::<T>::get_blocks_since_last_step(), 1 );
+	}
+
+	// An adjustment interval of zero makes every block an adjustment block.
+	// The history is full so the oldest adjustment is dropped.
+	update_difficulty {
+		Subtensor::<T>::set_adjustment_interval( 0 );
+		Subtensor::<T>::set_difficulty_from_u64( 10_000 );
+		DifficultyHistory::<T>::put( vec![ DifficultyAdjustment::default(); DIFFICULTY_HISTORY_LENGTH ] );
+	}: {
+		Subtensor::<T>::update_difficulty();
+	}
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert_eq!( Subtensor::<T>::get_registrations_this_interval(), 0 );
 	}
 
-	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+	impl_benchmark_test_suite!( Subtensor, crate::mock::new_test_ext(), crate::mock::Test );
 }
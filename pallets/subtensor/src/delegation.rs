@@ -0,0 +1,268 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /***********************************************************
+     * do_become_delegate() - opts a hotkey into accepting stake from any coldkey
+     ***********************************************************/
+
+    pub fn do_become_delegate(origin: T::Origin, hotkey: T::AccountId) -> dispatch::DispatchResult {
+
+        // ---- We check the transaction is signed by the owning coldkey.
+        let coldkey = ensure_signed(origin)?;
+        ensure!(Self::is_hotkey_active(&hotkey), Error::<T>::NotRegistered);
+        let uid = Self::get_uid_for_hotkey(&hotkey);
+        ensure!(Self::uid_belongs_to_coldkey(uid, &coldkey), Error::<T>::NonAssociatedColdKey);
+
+        // ---- A hotkey only opts in once.
+        ensure!(!Self::is_delegate(&hotkey), Error::<T>::AlreadyDelegate);
+        Delegates::<T>::insert(&hotkey, true);
+
+        // ---- Emit the event and return ok.
+        Self::deposit_event(Event::DelegateAdded(hotkey));
+        Ok(())
+    }
+
+    /// Sets the operator's cut of the hotkey's emission, taken before the rest is split
+    /// pro-rata between the owner's and the nominators' stake. Changes are rate limited so an
+    /// operator cannot raise the take just before a step and lower it again after.
+    ///
+    /// It throws the following errors if there is something wrong
+    /// - NotRegistered : The hotkey is not registered
+    /// - NonAssociatedColdKey : The hotkey was not registered by the calling coldkey
+    /// - TakeTooHigh : The take is above the max take
+    /// - TakeRateLimitExceeded : The take changed less than take_rate_limit blocks ago
+    ///
+    pub fn do_set_take(origin: T::Origin, hotkey: T::AccountId, take: u16) -> dispatch::DispatchResult {
+
+        // ---- We check the transaction is signed by the owning coldkey.
+        let coldkey = ensure_signed(origin)?;
+        ensure!(Self::is_hotkey_active(&hotkey), Error::<T>::NotRegistered);
+        let uid = Self::get_uid_for_hotkey(&hotkey);
+        ensure!(Self::uid_belongs_to_coldkey(uid, &coldkey), Error::<T>::NonAssociatedColdKey);
+
+        // ---- We check the take is allowed and the last change is old enough.
+        ensure!(take <= Self::get_max_take(), Error::<T>::TakeTooHigh);
+        let current_block: u64 = Self::get_current_block_as_u64();
+        if let Some(last_change) = LastTakeChange::<T>::get(&hotkey) {
+            ensure!(current_block >= last_change.saturating_add(Self::get_take_rate_limit()), Error::<T>::TakeRateLimitExceeded);
+        }
+        Takes::<T>::insert(&hotkey, take);
+        LastTakeChange::<T>::insert(&hotkey, current_block);
+
+        // ---- Emit the event and return ok.
+        Self::deposit_event(Event::TakeSet(hotkey, take));
+        Ok(())
+    }
+
+    /// Moves stake from the calling coldkey into a delegate hotkey. The caller receives
+    /// shares in the part of the hotkey's stake owned by nominators, priced so that the
+    /// shares already issued keep their value. Emission later grows that part pro-rata,
+    /// so the value of every share grows with it. The funds are held in the delegation pool,
+    /// as a share is not tied to any one nominator's account until it is withdrawn.
+    ///
+    /// It throws the following errors if there is something wrong
+    /// - NotRegistered : The hotkey is not registered
+    /// - NotDelegate : The hotkey has not opted in as a delegate
+    /// - NotEnoughBalanceToStake : The coldkey does not hold the amount
+    /// - DelegatedStakeTooSmall : The amount does not buy a single share
+    /// - TooManyNominators : The caller is a new nominator and the hotkey has no room for it
+    /// - StakeBelowExistentialDeposit : The delegation pool is empty and the amount is below the existential deposit
+    ///
+    pub fn do_delegate_stake(origin: T::Origin, hotkey: T::AccountId, stake_to_be_delegated: BalanceOf<T>) -> dispatch::DispatchResult {
+
+        // ---- We check the transaction is signed by the nominating coldkey.
+        let coldkey = ensure_signed(origin)?;
+        ensure!(Self::is_hotkey_active(&hotkey), Error::<T>::NotRegistered);
+        let uid = Self::get_uid_for_hotkey(&hotkey);
+        ensure!(Self::is_delegate(&hotkey), Error::<T>::NotDelegate);
+
+        // ---- We price the shares before any funds move.
+        let shares: BalanceOf<T> = Self::shares_for_delegated_stake(&hotkey, stake_to_be_delegated);
+        ensure!(!shares.is_zero(), Error::<T>::DelegatedStakeTooSmall);
+        let new_nominator: bool = !NominatorShares::<T>::contains_key(&hotkey, &coldkey);
+        ensure!(!new_nominator || NominatorCount::<T>::get(&hotkey) < MAX_NOMINATORS_PER_DELEGATE, Error::<T>::TooManyNominators);
+
+        // ---- We reserve the funds on the coldkey and move them into the delegation pool.
+        let pool: T::AccountId = Self::get_delegation_pool_account();
+        ensure!(Self::can_receive_reserved_stake(&pool, stake_to_be_delegated), Error::<T>::StakeBelowExistentialDeposit);
+        ensure!(Self::can_reserve_stake_on_coldkey(&coldkey, stake_to_be_delegated), Error::<T>::NotEnoughBalanceToStake);
+        ensure!(Self::reserve_stake_on_coldkey(&coldkey, stake_to_be_delegated) == true, Error::<T>::BalanceWithdrawalError);
+        let delegated: BalanceOf<T> = Self::move_reserved_stake(&coldkey, &pool, stake_to_be_delegated);
+        ensure!(delegated == stake_to_be_delegated, Error::<T>::BalanceWithdrawalError);
+
+        // ---- We issue the shares and add the funds to the hotkey.
+        if new_nominator {
+            NominatorCount::<T>::mutate(&hotkey, |count| *count += 1);
+        }
+        NominatorShares::<T>::mutate(&hotkey, &coldkey, |shares_i| *shares_i = shares_i.saturating_add(shares));
+        TotalNominatorShares::<T>::mutate(&hotkey, |total| *total = total.saturating_add(shares));
+        DelegatedStake::<T>::mutate(&hotkey, |delegated| *delegated = delegated.saturating_add(stake_to_be_delegated));
+        Self::add_stake_to_neuron_hotkey_account(uid, stake_to_be_delegated);
+
+        // ---- Emit the event and return ok.
+        Self::deposit_event(Event::StakeDelegated(coldkey, hotkey, stake_to_be_delegated));
+        Ok(())
+    }
+
+    /// Returns stake a nominator delegated to a hotkey, together with its share of the
+    /// emission earned since, to the nominator's unbonding queue. The shares covering the
+    /// amount are burned. The last nominator to leave also takes any rounding dust.
+    ///
+    /// It throws the following errors if there is something wrong
+    /// - NotRegistered : The hotkey is not registered
+    /// - NotEnoughStaketoWithdraw : The caller's shares are worth less than the amount
+    /// - TooManyUnbondingChunks : The caller already has MAX_UNBONDING_CHUNKS chunks queued
+    /// - StakeBelowExistentialDeposit : The caller holds no funds and the payout is below the existential deposit
+    ///
+    pub fn do_undelegate_stake(origin: T::Origin, hotkey: T::AccountId, stake_to_be_undelegated: BalanceOf<T>) -> dispatch::DispatchResult {
+
+        // ---- We check the transaction is signed by the nominating coldkey.
+        let coldkey = ensure_signed(origin)?;
+        ensure!(Self::is_hotkey_active(&hotkey), Error::<T>::NotRegistered);
+        let uid = Self::get_uid_for_hotkey(&hotkey);
+
+        // ---- We check the caller's shares cover the amount.
+        let shares: BalanceOf<T> = NominatorShares::<T>::get(&hotkey, &coldkey);
+        let total_shares: BalanceOf<T> = TotalNominatorShares::<T>::get(&hotkey);
+        let delegated: BalanceOf<T> = DelegatedStake::<T>::get(&hotkey);
+        let nominated: BalanceOf<T> = Self::stake_for_shares(shares, total_shares, delegated);
+        ensure!(!shares.is_zero() && stake_to_be_undelegated <= nominated, Error::<T>::NotEnoughStaketoWithdraw);
+
+        // ---- We burn the shares covering the amount, rounding against the caller.
+        let shares_to_burn: BalanceOf<T> = if stake_to_be_undelegated == nominated {
+            shares
+        } else {
+            Self::multiply_by_ratio_rounding_up(stake_to_be_undelegated, total_shares, delegated).min(shares)
+        };
+        let remaining_total_shares: BalanceOf<T> = total_shares - shares_to_burn;
+        let payout: BalanceOf<T> = if remaining_total_shares.is_zero() { delegated } else { stake_to_be_undelegated };
+        ensure!(Self::can_unbond(&coldkey), Error::<T>::TooManyUnbondingChunks);
+        ensure!(Self::can_receive_reserved_stake(&coldkey, payout), Error::<T>::StakeBelowExistentialDeposit);
+
+        // ---- We update the share accounts and queue the payout for the nominator.
+        if shares == shares_to_burn {
+            NominatorShares::<T>::remove(&hotkey, &coldkey);
+            NominatorCount::<T>::mutate_exists(&hotkey, |count| {
+                *count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+            });
+        } else {
+            NominatorShares::<T>::insert(&hotkey, &coldkey, shares - shares_to_burn);
+        }
+        if remaining_total_shares.is_zero() {
+            TotalNominatorShares::<T>::remove(&hotkey);
+            DelegatedStake::<T>::remove(&hotkey);
+        } else {
+            TotalNominatorShares::<T>::insert(&hotkey, remaining_total_shares);
+            DelegatedStake::<T>::insert(&hotkey, delegated - payout);
+        }
+        Self::remove_stake_from_neuron_hotkey_account(uid, payout);
+        let paid: BalanceOf<T> = Self::move_reserved_stake(&Self::get_delegation_pool_account(), &coldkey, payout);
+        Self::unbond_to_coldkey(&coldkey, paid);
+
+        // ---- Emit the event and return ok.
+        Self::deposit_event(Event::StakeUndelegated(coldkey, hotkey, payout));
+        Ok(())
+    }
+
+    /********************************
+    --==[[  Helper functions   ]]==--
+    *********************************/
+
+    /// Returns true if the hotkey accepts stake from coldkeys other than its owner.
+    ///
+    pub fn is_delegate(hotkey: &T::AccountId) -> bool {
+        return Delegates::<T>::get(hotkey);
+    }
+
+    /// Returns the part of the hotkey's stake owned by the coldkey that registered it.
+    ///
+    pub fn get_owned_stake_for_uid(uid: u32, hotkey: &T::AccountId) -> BalanceOf<T> {
+        return Stake::<T>::get(uid).saturating_sub(DelegatedStake::<T>::get(hotkey));
+    }
+
+    /// Returns the stake the nominator's shares in the hotkey are currently worth.
+    ///
+    pub fn get_nominator_stake(hotkey: &T::AccountId, coldkey: &T::AccountId) -> BalanceOf<T> {
+        return Self::stake_for_shares(
+            NominatorShares::<T>::get(hotkey, coldkey),
+            TotalNominatorShares::<T>::get(hotkey),
+            DelegatedStake::<T>::get(hotkey),
+        );
+    }
+
+    /// Returns the number of shares the amount buys in the hotkey's delegated stake.
+    /// The first nominator buys shares one to one.
+    ///
+    pub fn shares_for_delegated_stake(hotkey: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+        let total_shares: BalanceOf<T> = TotalNominatorShares::<T>::get(hotkey);
+        let delegated: BalanceOf<T> = DelegatedStake::<T>::get(hotkey);
+        if total_shares.is_zero() || delegated.is_zero() {
+            return amount;
+        }
+        return Self::multiply_by_ratio(amount, total_shares, delegated);
+    }
+
+    /// Returns the stake the shares are worth out of delegated, rounded down.
+    ///
+    pub fn stake_for_shares(shares: BalanceOf<T>, total_shares: BalanceOf<T>, delegated: BalanceOf<T>) -> BalanceOf<T> {
+        return Self::multiply_by_ratio(shares, delegated, total_shares);
+    }
+
+    /// Moves the delegation state of a hotkey, including every nominator's shares, onto a
+    /// new hotkey. Used when the owner swaps the hotkey of a neuron.
+    ///
+    pub fn swap_delegate_hotkey(old_hotkey: &T::AccountId, new_hotkey: &T::AccountId) {
+        if !Delegates::<T>::take(old_hotkey) {
+            return;
+        }
+        Delegates::<T>::insert(new_hotkey, true);
+        DelegatedStake::<T>::insert(new_hotkey, DelegatedStake::<T>::take(old_hotkey));
+        TotalNominatorShares::<T>::insert(new_hotkey, TotalNominatorShares::<T>::take(old_hotkey));
+        NominatorCount::<T>::insert(new_hotkey, NominatorCount::<T>::take(old_hotkey));
+        let nominators: Vec<(T::AccountId, BalanceOf<T>)> = NominatorShares::<T>::drain_prefix(old_hotkey).collect();
+        for (nominator, shares) in nominators {
+            NominatorShares::<T>::insert(new_hotkey, nominator, shares);
+        }
+    }
+
+    /// Pays every nominator of the hotkey the stake its shares are worth out of the delegation
+    /// pool and clears the hotkey's delegation state. Used when the hotkey is pruned from the graph.
+    /// The rounding dust left in the pool is moved onto the owner's coldkey. A payout below the
+    /// existential deposit to an account that does not exist is burned and taken out of the issuance,
+    /// as the balances pallet would burn it. Returns the total paid out or burned, the caller releases
+    /// the rest of the stake to the owner and decreases the total stake.
+    ///
+    pub fn return_delegated_stake(hotkey: &T::AccountId, owner: &T::AccountId) -> BalanceOf<T> {
+        let total_shares: BalanceOf<T> = TotalNominatorShares::<T>::take(hotkey);
+        let delegated: BalanceOf<T> = DelegatedStake::<T>::take(hotkey);
+        Delegates::<T>::remove(hotkey);
+        NominatorCount::<T>::remove(hotkey);
+        let pool: T::AccountId = Self::get_delegation_pool_account();
+        let mut payouts: Vec<(T::AccountId, BalanceOf<T>)> = Vec::new();
+        let mut paid: BalanceOf<T> = Zero::zero();
+        for (nominator, shares) in NominatorShares::<T>::drain_prefix(hotkey) {
+            let stake: BalanceOf<T> = Self::stake_for_shares(shares, total_shares, delegated);
+            paid = paid.saturating_add(stake);
+            payouts.push((nominator, stake));
+        }
+        let dust: BalanceOf<T> = delegated.saturating_sub(paid);
+
+        // ---- Burns and the dust go first. Once the pool falls below the existential deposit it is reaped
+        // with whatever it still holds, so the payouts moved out last must leave it empty or above the deposit.
+        let (payouts, burns): (Vec<(T::AccountId, BalanceOf<T>)>, Vec<(T::AccountId, BalanceOf<T>)>) =
+            payouts.into_iter().partition(|(nominator, stake)| Self::can_receive_reserved_stake(nominator, *stake));
+        for (_, stake) in burns {
+            Self::burn_reserved_stake(&pool, stake);
+        }
+        if Self::can_receive_reserved_stake(owner, dust) {
+            Self::move_reserved_stake(&pool, owner, dust);
+        } else {
+            Self::burn_reserved_stake(&pool, dust);
+            paid = delegated;
+        }
+        for (nominator, stake) in payouts {
+            Self::unreserve_stake_on_coldkey(&nominator, Self::move_reserved_stake(&pool, &nominator, stake));
+        }
+        return paid;
+    }
+}
@@ -0,0 +1,133 @@
+use super::*;
+use frame_support::IterableStorageMap;
+use sp_std::collections::btree_set::BTreeSet;
+
+impl<T: Config> Pallet<T> {
+    /***********************************************************
+     * check_invariants() - verifies the stake and issuance accounting
+     ***********************************************************/
+
+    /// Checks the invariants the rest of the pallet relies on and returns every one that is
+    /// broken. Unlike the debug_asserts in staking this also runs in release builds, from the
+    /// try-runtime hooks and through the runtime api. It reads every neuron, so it is never
+    /// called from a block.
+    ///
+    /// It checks that
+    /// - TotalStake is the sum of the stake of every neuron
+    /// - No delegate holds more delegated stake than stake
+    /// - Hotkeys and Keys map every hotkey and uid onto each other and hold as many entries
+    /// - Coldkeys and ColdkeyHotkeys record the coldkey of every neuron in Keys and nothing else
+    /// - The uids in use are exactly 0 to N - 1
+    /// - TotalIssuance covers TotalStake, as every unit of stake has been issued
+    /// - TotalIssuance is the issuance of the balances pallet
+    /// - The stake and the unbonding stake are exactly what the coldkeys and the delegation
+    ///   pool hold under STAKE_RESERVE_ID
+    ///
+    pub fn check_invariants() -> Vec<InvariantViolation<BalanceOf<T>>> {
+        let mut violations: Vec<InvariantViolation<BalanceOf<T>>> = vec![];
+
+        // ---- Stake totals.
+        let summed: BalanceOf<T> = Stake::<T>::iter_values().fold(Zero::zero(), |total: BalanceOf<T>, stake| total.saturating_add(stake));
+        let recorded: BalanceOf<T> = TotalStake::<T>::get();
+        if summed != recorded {
+            violations.push(InvariantViolation::TotalStake { recorded, summed });
+        }
+
+        // ---- Hotkeys, Coldkeys and ColdkeyHotkeys against Keys.
+        let mut neurons: u32 = 0;
+        let mut max_uid: Option<u32> = None;
+        for (uid, hotkey) in <Keys<T> as IterableStorageMap<u32, T::AccountId>>::iter() {
+            neurons += 1;
+            max_uid = max_uid.max(Some(uid));
+            if !Hotkeys::<T>::contains_key(&hotkey) || Hotkeys::<T>::get(&hotkey) != uid {
+                violations.push(InvariantViolation::HotkeyIndex { uid });
+            }
+            let owned: bool = match Coldkeys::<T>::get(uid) {
+                Some(coldkey) => ColdkeyHotkeys::<T>::get(&coldkey).contains(&hotkey),
+                None => false,
+            };
+            if !owned {
+                violations.push(InvariantViolation::ColdkeyIndex { uid });
+            }
+            if DelegatedStake::<T>::get(&hotkey) > Stake::<T>::get(uid) {
+                violations.push(InvariantViolation::DelegatedStake { uid });
+            }
+        }
+        let hotkeys: u32 = Hotkeys::<T>::iter_keys().count() as u32;
+        if hotkeys != neurons {
+            violations.push(InvariantViolation::HotkeyCount { hotkeys, neurons });
+        }
+
+        let coldkeys: u32 = Coldkeys::<T>::iter_keys().count() as u32;
+        let owned_hotkeys: u32 = ColdkeyHotkeys::<T>::iter_values().map(|hotkeys| hotkeys.len() as u32).sum();
+        if coldkeys != neurons || owned_hotkeys != neurons {
+            violations.push(InvariantViolation::ColdkeyCount { coldkeys, owned_hotkeys, neurons });
+        }
+
+        // ---- N against the uids in use.
+        let n: u32 = N::<T>::get();
+        let next_uid: u32 = max_uid.map_or(0, |uid| uid.saturating_add(1));
+        if next_uid != n || neurons != n {
+            violations.push(InvariantViolation::NeuronCount { n, neurons, next_uid });
+        }
+
+        // ---- Issuance against the stake it has issued.
+        let issuance: BalanceOf<T> = TotalIssuance::<T>::get();
+        if issuance < recorded {
+            violations.push(InvariantViolation::IssuanceBelowStake { issuance, stake: recorded });
+        }
+        let issued: BalanceOf<T> = T::Currency::total_issuance();
+        if issuance != issued {
+            violations.push(InvariantViolation::Issuance { recorded: issuance, issued });
+        }
+
+        // ---- Stake against the reserves backing it. Owned and unbonding stake is held on the
+        // coldkeys, delegated stake in the delegation pool.
+        let unbonding: BalanceOf<T> = Unbonding::<T>::iter_values().flatten().fold(Zero::zero(), |total: BalanceOf<T>, chunk| total.saturating_add(chunk.amount));
+        let mut holders: BTreeSet<T::AccountId> = Coldkeys::<T>::iter_values().chain(Unbonding::<T>::iter_keys()).collect();
+        holders.insert(Self::get_delegation_pool_account());
+        let reserved: BalanceOf<T> = holders.iter().fold(Zero::zero(), |total: BalanceOf<T>, holder| total.saturating_add(Self::get_reserved_stake(holder)));
+        let stake: BalanceOf<T> = summed.saturating_add(unbonding);
+        if stake != reserved {
+            violations.push(InvariantViolation::ReservedStake { stake, reserved });
+        }
+        violations
+    }
+
+    /// Returns an error naming the first broken invariant and logs all of them.
+    /// Used by the try-runtime hooks.
+    ///
+    pub fn ensure_invariants() -> Result<(), &'static str> {
+        let violations: Vec<InvariantViolation<BalanceOf<T>>> = Self::check_invariants();
+        for violation in violations.iter() {
+            log::error!(target: LOG_TARGET, "invariant violated: {:?}", violation);
+        }
+        match violations.first() {
+            Some(InvariantViolation::TotalStake { .. }) => Err("TotalStake is not the sum of the stake"),
+            Some(InvariantViolation::DelegatedStake { .. }) => Err("a delegate holds more delegated stake than stake"),
+            Some(InvariantViolation::HotkeyIndex { .. }) => Err("Hotkeys and Keys disagree"),
+            Some(InvariantViolation::HotkeyCount { .. }) => Err("Hotkeys does not hold a uid per neuron"),
+            Some(InvariantViolation::ColdkeyIndex { .. }) => Err("Coldkeys and ColdkeyHotkeys disagree with Keys"),
+            Some(InvariantViolation::ColdkeyCount { .. }) => Err("Coldkeys or ColdkeyHotkeys does not hold an entry per neuron"),
+            Some(InvariantViolation::NeuronCount { .. }) => Err("N does not match the uids in use"),
+            Some(InvariantViolation::IssuanceBelowStake { .. }) => Err("TotalIssuance is below TotalStake"),
+            Some(InvariantViolation::Issuance { .. }) => Err("TotalIssuance is not the issuance of the balances pallet"),
+            Some(InvariantViolation::ReservedStake { .. }) => Err("the stake is not what is held under STAKE_RESERVE_ID"),
+            None => Ok(()),
+        }
+    }
+
+    /// Recomputes the aggregate totals from the values they summarize. TotalStake becomes the
+    /// sum of the stake of every neuron and TotalIssuance the issuance of the balances pallet,
+    /// which also picks up what was minted or burned outside this pallet without passing
+    /// through it.
+    /// Returns the repaired total stake and total issuance.
+    ///
+    pub fn repair_totals() -> (BalanceOf<T>, BalanceOf<T>) {
+        let total_stake: BalanceOf<T> = Stake::<T>::iter_values().fold(Zero::zero(), |total: BalanceOf<T>, stake| total.saturating_add(stake));
+        let total_issuance: BalanceOf<T> = T::Currency::total_issuance();
+        TotalStake::<T>::put(total_stake);
+        TotalIssuance::<T>::put(total_issuance);
+        (total_stake, total_issuance)
+    }
+}
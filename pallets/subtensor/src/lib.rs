@@ -10,7 +10,10 @@ use codec::{Decode, Encode};
 use frame_support::{dispatch, ensure, traits::{
 		Currency, 
 		ExistenceRequirement,
+		Imbalance,
 		IsSubType, 
+		NamedReservableCurrency,
+		ReservableCurrency,
 		tokens::{
 			WithdrawReasons
 		}
@@ -27,10 +30,15 @@ use frame_system::{
 use substrate_fixed::types::U64F64;
 use sp_runtime::{
 	traits::{
+		AccountIdConversion,
+		Bounded,
 		Dispatchable, 
 		DispatchInfoOf, 
+		Saturating,
 		SignedExtension, 
+		Zero,
 	},
+	SaturatedConversion,
 	transaction_validity::{
         TransactionValidityError, 
 		TransactionValidity
@@ -44,6 +52,14 @@ use sp_std::marker::PhantomData;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+// The benchmark test suite runs against the same mock runtime as the integration tests,
+// which names this crate as `pallet_subtensor`.
+#[cfg(all(test, feature = "runtime-benchmarks"))]
+extern crate self as pallet_subtensor;
+#[cfg(all(test, feature = "runtime-benchmarks"))]
+#[path = "../tests/mock.rs"]
+mod mock;
+
 /// ************************************************************
 ///	-Subtensor-Imports
 /// ************************************************************
@@ -52,15 +68,28 @@ mod serving;
 mod step;
 mod registration;
 mod staking;
+mod delegation;
+mod unbonding;
+mod swap;
+mod invariants;
+mod rpc_info;
+pub mod migrations;
+pub mod weight_info;
+
+pub use weight_info::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use sp_core::{U256};
 	use frame_support::IterableStorageMap;
-	use frame_support::{pallet_prelude::*, Printable, traits::{Currency}};
+	use frame_support::{pallet_prelude::*, Printable, PalletId, traits::{Currency, NamedReservableCurrency}};
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::{traits::{Saturating, Zero}, SaturatedConversion};
+	use crate::weight_info::WeightInfo;
 	use sp_std::vec::Vec;
 	use sp_std::vec;
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
@@ -68,8 +97,9 @@ pub mod pallet {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-		/// --- Currency type that will be used to place deposits on neurons
-		type Currency: Currency<Self::AccountId> + Send + Sync;
+		/// --- Currency type that will be used to place deposits on neurons. Stake is held
+		/// in it as a reserve named STAKE_RESERVE_ID.
+		type Currency: NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]> + Send + Sync;
 		
 		/// --- The transaction fee in RAO per byte
 		type TransactionByteFee: Get<BalanceOf<Self>>;
@@ -162,13 +192,29 @@ pub mod pallet {
 		#[pallet::constant]
 		type InitialDifficulty: Get<u64>;
 
-		/// Minimum registration difficulty
+		/// Initial minimum registration difficulty.
+		#[pallet::constant]
+		type InitialMinDifficulty: Get<u64>;
+
+		/// Initial maximum registration difficulty.
+		#[pallet::constant]
+		type InitialMaxDifficulty: Get<u64>;
+
+		/// Initial amount burned from the coldkey to register without proof of work.
+		#[pallet::constant]
+		type InitialBurn: Get<BalanceOf<Self>>;
+
+		/// Initial minimum registration burn.
+		#[pallet::constant]
+		type InitialMinBurn: Get<BalanceOf<Self>>;
+
+		/// Initial maximum registration burn.
 		#[pallet::constant]
-		type MinimumDifficulty: Get<u64>;
+		type InitialMaxBurn: Get<BalanceOf<Self>>;
 
-		/// Maximum registration difficulty
+		/// Initial block from which registration seals not bound to the hotkey are refused.
 		#[pallet::constant]
-		type MaximumDifficulty: Get<u64>;
+		type InitialLegacySealCutoff: Get<u64>;
 
 		/// Initial adjustment interval.
 		#[pallet::constant]
@@ -194,14 +240,44 @@ pub mod pallet {
 		/// Initial validator exclude quantile.
 		#[pallet::constant]
 		type InitialValidatorExcludeQuantile: Get<u8>;
+
+		/// Initial number of blocks a used seal is remembered before it is pruned.
+		#[pallet::constant]
+		type InitialUsedWorkRetention: Get<u64>;
+
+		/// Initial number of blocks removed stake stays locked before it can be withdrawn.
+		#[pallet::constant]
+		type InitialUnbondingPeriod: Get<u64>;
+
+		/// Initial amount burned from the coldkey when it swaps the hotkey of a neuron.
+		#[pallet::constant]
+		type InitialHotkeySwapCost: Get<BalanceOf<Self>>;
+
+		/// Initial number of blocks between a coldkey swap announcement and its execution.
+		#[pallet::constant]
+		type InitialColdkeySwapDelay: Get<u64>;
+
+		/// Initial highest take a hotkey can set, as a fraction of u16::MAX.
+		#[pallet::constant]
+		type InitialMaxTake: Get<u16>;
+
+		/// Initial number of blocks a hotkey waits between changes to its take.
+		#[pallet::constant]
+		type InitialTakeRateLimit: Get<u64>;
+
+		/// Weight information for the extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
-	pub type NeuronMetadataOf<T> = NeuronMetadata<AccountIdOf<T>>;
+	pub type NeuronMetadataOf<T> = NeuronMetadata<AccountIdOf<T>, BalanceOf<T>>;
 	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
-	#[derive(Encode, Decode, Default, TypeInfo)]
-    pub struct NeuronMetadata<AccountId> {
+	/// ---- A full view of a neuron, assembled from the per uid storage maps.
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct NeuronMetadata<AccountId, Balance> {
 
 		/// ---- The endpoint's code version.
         pub version: u32,
@@ -235,7 +311,7 @@ pub mod pallet {
 
         /// ---- The associated coldkey account.
         /// Staking and unstaking transactions must be made by this account.
-        /// The hotkey account (in the Neurons map) has permission to call
+        /// The hotkey account (in the Hotkeys map) has permission to call
         /// subscribe and unsubscribe.
         pub coldkey: AccountId,
 
@@ -249,7 +325,7 @@ pub mod pallet {
 		pub priority: u64,
 
 		/// ---- The associated stake in this account.
-		pub stake: u64,
+		pub stake: Balance,
 
 		/// ---- The associated rank in this account.
 		pub rank: u64,
@@ -276,9 +352,267 @@ pub mod pallet {
 		pub weights: Vec<(u32,u32)>,
     }
 
+	/// ---- The endpoint a neuron serves, stored apart from its scores so serving
+	/// does not touch the rest of the neuron.
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct AxonInfo {
+		/// ---- The endpoint's code version.
+		pub version: u32,
+
+		/// ---- The endpoint's u128 encoded ip address of type v6 or v4.
+		pub ip: u128,
+
+		/// ---- The endpoint's u16 encoded port.
+		pub port: u16,
+
+		/// ---- The endpoint's ip type, 4 for ipv4 and 6 for ipv6.
+		pub ip_type: u8,
+
+		/// ---- The neuron modality, see NeuronMetadata.modality.
+		pub modality: u8,
+	}
+
+	/// ---- Layout version of SubtensorHyperparams. Bump this whenever a field is
+	/// added, removed or reordered so clients can tell which layout they decoded.
+	pub const HYPERPARAMS_VERSION: u32 = 11;
+
+	/// ---- A snapshot of every sudo settable hyperparameter, read in a single call.
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct SubtensorHyperparams<Balance> {
+		/// ---- Layout version, always HYPERPARAMS_VERSION when built by the runtime.
+		pub version: u32,
+		pub rho: u64,
+		pub kappa: u64,
+		pub incentive_pruning_denominator: u64,
+		pub stake_pruning_denominator: u64,
+		pub stake_pruning_min: u64,
+		pub validator_epoch_len: u64,
+		pub validator_epochs_per_reset: u64,
+		pub validator_batch_size: u64,
+		pub validator_sequence_length: u64,
+		pub validator_exclude_quantile: u8,
+		pub max_allowed_uids: u64,
+		pub min_allowed_weights: u64,
+		pub max_allowed_max_min_ratio: u64,
+		pub max_weight_limit: u32,
+		pub immunity_period: u64,
+		pub blocks_per_step: u64,
+		pub bonds_moving_average: u64,
+		pub activity_cutoff: u64,
+		pub difficulty: u64,
+		pub adjustment_interval: u64,
+		pub target_registrations_per_interval: u64,
+		pub max_registrations_per_block: u64,
+		pub foundation_distribution: u64,
+		pub scaling_law_power: u8,
+		pub synergy_scaling_law_power: u8,
+		pub used_work_retention: u64,
+		pub unbonding_period: u64,
+		pub hotkey_swap_cost: Balance,
+		pub coldkey_swap_delay: u64,
+		pub max_take: u16,
+		pub take_rate_limit: u64,
+		pub burn: Balance,
+		/// ---- Seal format expected by register, always SEAL_VERSION when built by the runtime.
+		pub seal_version: u32,
+		pub legacy_seal_cutoff: u64,
+		pub min_difficulty: u64,
+		pub max_difficulty: u64,
+		pub min_burn: Balance,
+		pub max_burn: Balance,
+	}
+
+	/// ---- A hotkey owned by a coldkey along with the values a wallet needs to display it.
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct OwnedHotkeyInfo<AccountId, Balance> {
+		/// ---- The owned hotkey.
+		pub hotkey: AccountId,
+
+		/// ---- The uid the hotkey is registered under.
+		pub uid: u32,
+
+		/// ---- The stake held by the hotkey.
+		pub stake: Balance,
+
+		/// ---- The emission this hotkey received at the last mechanism step.
+		pub emission: u64,
+	}
+
+	/// ---- A hotkey that accepts stake from any coldkey, with the stake its nominators hold in it.
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct DelegateInfo<AccountId, Balance> {
+		/// ---- The delegate hotkey.
+		pub hotkey: AccountId,
+
+		/// ---- The uid the hotkey is registered under.
+		pub uid: u32,
+
+		/// ---- The coldkey which registered the hotkey.
+		pub owner: AccountId,
+
+		/// ---- The total stake held by the hotkey, owned and delegated.
+		pub stake: Balance,
+
+		/// ---- The part of the stake owned by nominators.
+		pub delegated_stake: Balance,
+
+		/// ---- The operator's cut of the hotkey's emission, as a fraction of u16::MAX.
+		pub take: u16,
+
+		/// ---- Each nominator coldkey with the stake its shares are currently worth.
+		pub nominators: Vec<(AccountId, Balance)>,
+	}
+
+	/// ---- Stake removed from a hotkey and waiting out the unbonding period before it can be withdrawn.
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct UnlockChunk<Balance> {
+		/// ---- The amount of stake held in the chunk.
+		pub amount: Balance,
+
+		/// ---- The block from which the chunk can be withdrawn.
+		pub unlock_block: u64,
+	}
+
+	/// ---- Where the emission a neuron's owner earns is paid. Nominators always compound.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum EmissionDestination {
+		/// ---- Added to the neuron's stake, held on the coldkey.
+		Stake,
+
+		/// ---- Minted into the free balance of the coldkey.
+		Coldkey,
+	}
+
+	impl Default for EmissionDestination {
+		fn default() -> Self {
+			EmissionDestination::Stake
+		}
+	}
+
+	/// ---- A broken stake or issuance accounting invariant, as reported by check_invariants.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum InvariantViolation<Balance> {
+		/// ---- TotalStake differs from the sum of the stake of every neuron.
+		TotalStake { recorded: Balance, summed: Balance },
+
+		/// ---- The delegate under this uid holds more delegated stake than stake.
+		DelegatedStake { uid: u32 },
+
+		/// ---- Keys and Hotkeys do not map this uid and its hotkey onto each other.
+		HotkeyIndex { uid: u32 },
+
+		/// ---- Hotkeys holds a different number of entries than there are neurons.
+		HotkeyCount { hotkeys: u32, neurons: u32 },
+
+		/// ---- N differs from the number of neurons or the uids in use are not 0 to N - 1.
+		NeuronCount { n: u32, neurons: u32, next_uid: u32 },
+
+		/// ---- TotalIssuance is below TotalStake, so stake exists that was never issued.
+		IssuanceBelowStake { issuance: Balance, stake: Balance },
+
+		/// ---- TotalIssuance differs from the issuance of the balances pallet.
+		Issuance { recorded: Balance, issued: Balance },
+
+		/// ---- The stake and unbonding stake differ from what is held under STAKE_RESERVE_ID.
+		ReservedStake { stake: Balance, reserved: Balance },
+
+		/// ---- The uid has no entry in Coldkeys or its coldkey's entry in ColdkeyHotkeys lacks its hotkey.
+		ColdkeyIndex { uid: u32 },
+
+		/// ---- Coldkeys or ColdkeyHotkeys holds a different number of entries than there are neurons.
+		ColdkeyCount { coldkeys: u32, owned_hotkeys: u32, neurons: u32 },
+	}
+
+	/// ---- The reason register would refuse a proof of work, as reported by check_registration_work.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum RegistrationWorkError {
+		/// ---- The block already holds max_registrations_per_block registrations.
+		BlockFull,
+
+		/// ---- The hotkey is already registered.
+		AlreadyRegistered,
+
+		/// ---- The work was done on a block after the current one.
+		FutureBlock,
+
+		/// ---- The work was done SEAL_VALIDITY_BLOCKS or more blocks ago.
+		TooOld,
+
+		/// ---- The work has already been used to register.
+		WorkRepeated,
+
+		/// ---- The work does not meet the current difficulty.
+		UnderDifficulty,
+
+		/// ---- The work is not the seal of the block number, nonce and hotkey.
+		SealMismatch,
+
+		/// ---- The work is a legacy seal and the legacy seal cutoff block has been reached.
+		LegacySealExpired,
+	}
+
+	/// ---- A difficulty adjustment, as recorded in DifficultyHistory.
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct DifficultyAdjustment {
+		/// ---- The block the adjustment was made at.
+		pub block: u64,
+
+		/// ---- The difficulty set by the adjustment.
+		pub difficulty: u64,
+
+		/// ---- The registrations made over the adjustment interval it closed.
+		pub registrations: u64,
+	}
+
+	/// ---- Number of blocks after the block it was computed for during which a registration seal is accepted.
+	pub const SEAL_VALIDITY_BLOCKS: u64 = 3;
+
+	/// ---- Version of the registration seal format. Version 1 hashes the nonce and the block hash,
+	/// version 2 also hashes the encoded hotkey so a seal cannot be replayed for another hotkey.
+	/// Version 1 seals are still accepted before the LegacySealCutoff block.
+	pub const SEAL_VERSION: u32 = 2;
+
+	/// ---- Largest factor the difficulty and burn are multiplied or divided by in a single adjustment.
+	pub const MAX_DIFFICULTY_ADJUSTMENT_FACTOR: u64 = 2;
+
+	/// ---- Number of difficulty adjustments kept in DifficultyHistory.
+	pub const DIFFICULTY_HISTORY_LENGTH: usize = 32;
+
+	/// ---- Most coldkeys that can nominate a single delegate. Bounds the payouts made when a delegate is pruned.
+	pub const MAX_NOMINATORS_PER_DELEGATE: u32 = 64;
+
+	/// ---- Most unbonding chunks a coldkey can have queued. Removals in the same block share a chunk.
+	pub const MAX_UNBONDING_CHUNKS: u32 = 32;
+
+	/// ---- Most coldkey swaps that can execute at the same block. Bounds the work done in on_initialize.
+	pub const MAX_COLDKEY_SWAPS_PER_BLOCK: u32 = 16;
+
+	/// ---- Most hotkeys the coldkey swaps executing at the same block can move between them.
+	/// Counted when a swap is announced, a coldkey with a pending swap cannot register more neurons.
+	pub const MAX_COLDKEY_SWAP_HOTKEYS_PER_BLOCK: u32 = 256;
+
+	/// ---- Name of the reserve stake is held under, on coldkeys and on the delegation pool.
+	pub const STAKE_RESERVE_ID: [u8; 8] = *b"substake";
+
+	/// ---- Id of the account holding delegated stake. Nominators share it until they withdraw.
+	pub const DELEGATION_POOL_ID: PalletId = PalletId(*b"subt/dlg");
+
+	/// ---- The in-code storage version. Bump this alongside every new step in `migrations`.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// ************************************************************
@@ -296,7 +630,7 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type TotalStake<T> = StorageValue<
 		_, 
-		u64, 
+		BalanceOf<T>, 
 		ValueQuery
 	>;
 
@@ -456,11 +790,11 @@ pub mod pallet {
 	>;
 
 	#[pallet::type_value] 
-	pub fn DefaultTotalIssuance<T: Config>() -> u64 { T::InitialIssuance::get() }
+	pub fn DefaultTotalIssuance<T: Config>() -> BalanceOf<T> { T::InitialIssuance::get().saturated_into() }
 	#[pallet::storage]
 	pub type TotalIssuance<T> = StorageValue<
 		_, 
-		u64, 
+		BalanceOf<T>, 
 		ValueQuery,
 		DefaultTotalIssuance<T>
 	>;
@@ -505,6 +839,71 @@ pub mod pallet {
 		DefaultDifficulty<T>
 	>;
 
+	#[pallet::type_value] 
+	pub fn DefaultMinDifficulty<T: Config>() -> u64 { T::InitialMinDifficulty::get() }
+	/// ---- Lowest difficulty the adjustment sets.
+	#[pallet::storage]
+	pub type MinDifficulty<T> = StorageValue<
+		_, 
+		u64, 
+		ValueQuery,
+		DefaultMinDifficulty<T>
+	>;
+
+	#[pallet::type_value] 
+	pub fn DefaultMaxDifficulty<T: Config>() -> u64 { T::InitialMaxDifficulty::get() }
+	/// ---- Highest difficulty the adjustment sets.
+	#[pallet::storage]
+	pub type MaxDifficulty<T> = StorageValue<
+		_, 
+		u64, 
+		ValueQuery,
+		DefaultMaxDifficulty<T>
+	>;
+
+	#[pallet::type_value] 
+	pub fn DefaultMinBurn<T: Config>() -> BalanceOf<T> { T::InitialMinBurn::get() }
+	/// ---- Lowest burn the adjustment sets.
+	#[pallet::storage]
+	pub type MinBurn<T> = StorageValue<
+		_, 
+		BalanceOf<T>, 
+		ValueQuery,
+		DefaultMinBurn<T>
+	>;
+
+	#[pallet::type_value] 
+	pub fn DefaultMaxBurn<T: Config>() -> BalanceOf<T> { T::InitialMaxBurn::get() }
+	/// ---- Highest burn the adjustment sets.
+	#[pallet::storage]
+	pub type MaxBurn<T> = StorageValue<
+		_, 
+		BalanceOf<T>, 
+		ValueQuery,
+		DefaultMaxBurn<T>
+	>;
+
+	#[pallet::type_value] 
+	pub fn DefaultBurn<T: Config>() -> BalanceOf<T> { T::InitialBurn::get() }
+	#[pallet::storage]
+	pub type Burn<T> = StorageValue<
+		_, 
+		BalanceOf<T>, 
+		ValueQuery,
+		DefaultBurn<T>
+	>;
+
+	#[pallet::type_value] 
+	pub fn DefaultLegacySealCutoff<T: Config>() -> u64 { T::InitialLegacySealCutoff::get() }
+	/// ---- Block from which register refuses seals in the legacy format, not bound to the hotkey.
+	#[pallet::storage]
+	pub type LegacySealCutoff<T> = StorageValue<
+		_, 
+		u64, 
+		ValueQuery,
+		DefaultLegacySealCutoff<T>
+	>;
+
 	#[pallet::type_value] 
 	pub fn DefaultActivityCutoff<T: Config>() -> u64 { T::InitialActivityCutoff::get() }
 	#[pallet::storage]
@@ -601,6 +1000,14 @@ pub mod pallet {
 		ValueQuery
 	>;
 
+	/// ---- The last DIFFICULTY_HISTORY_LENGTH difficulty adjustments, oldest first.
+	#[pallet::storage]
+	pub type DifficultyHistory<T> = StorageValue<
+		_, 
+		Vec<DifficultyAdjustment>, 
+		ValueQuery
+	>;
+
 	#[pallet::storage]
 	pub type LastMechansimStepBlock<T> = StorageValue<
 		_, 
@@ -634,94 +1041,618 @@ pub mod pallet {
 		ValueQuery
 	>;
 
+	/// ---- Maps from coldkey to the hotkeys it has registered.
 	#[pallet::storage]
-	#[pallet::getter(fn usedwork)]
-    pub(super) type UsedWork<T:Config> = StorageMap<
+	#[pallet::getter(fn coldkey_hotkeys)]
+    pub(super) type ColdkeyHotkeys<T:Config> = StorageMap<
 		_, 
-		Identity, 
-		Vec<u8>, 
-		u64,
+		Blake2_128Concat, 
+		T::AccountId, 
+		Vec<T::AccountId>, 
 		ValueQuery
 	>;
 
-	/// ---- Maps from uid to neuron.
+	/// ---- Maps from hotkey to whether it accepts stake from coldkeys other than its owner.
 	#[pallet::storage]
-    #[pallet::getter(fn uid)]
-    pub(super) type Neurons<T:Config> = StorageMap<
+    pub(super) type Delegates<T:Config> = StorageMap<
 		_, 
-		Identity, 
-		u32, 
-		NeuronMetadataOf<T>, 
-		OptionQuery
+		Blake2_128Concat, 
+		T::AccountId, 
+		bool, 
+		ValueQuery
 	>;
 
-	/// ---- Maps from uid to uid as a set which we use to record uids to prune at next epoch.
+	/// ---- Maps from delegate hotkey to the part of its stake owned by nominators.
 	#[pallet::storage]
-	#[pallet::getter(fn uid_to_prune)]
-    pub(super) type NeuronsToPruneAtNextEpoch<T:Config> = StorageMap<
+    pub(super) type DelegatedStake<T:Config> = StorageMap<
 		_, 
-		Identity, 
-		u32, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		BalanceOf<T>, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from delegate hotkey to the nominator shares issued against its DelegatedStake.
+	#[pallet::storage]
+    pub(super) type TotalNominatorShares<T:Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		BalanceOf<T>, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from delegate hotkey to the number of coldkeys holding shares in it.
+	#[pallet::storage]
+    pub(super) type NominatorCount<T:Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
 		u32, 
-		ValueQuery,
+		ValueQuery
+	>;
+
+	/// ---- Maps from (delegate hotkey, nominator coldkey) to the shares the nominator holds.
+	#[pallet::storage]
+    pub(super) type NominatorShares<T:Config> = StorageDoubleMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		BalanceOf<T>, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from hotkey to where the emission its owner earns is paid. Compounds into stake by default.
+	#[pallet::storage]
+	#[pallet::getter(fn emission_destination)]
+    pub(super) type EmissionDestinations<T:Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		EmissionDestination, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from coldkey to the stake it has removed and not yet withdrawn, oldest chunk first.
+	#[pallet::storage]
+    pub(super) type Unbonding<T:Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		Vec<UnlockChunk<BalanceOf<T>>>, 
+		ValueQuery
 	>;
 
 	#[pallet::type_value] 
-	pub fn DefaultBlockAtRegistration<T: Config>() -> u64 { 0 }
+	pub fn DefaultUnbondingPeriod<T: Config>() -> u64 { T::InitialUnbondingPeriod::get() }
 	#[pallet::storage]
-	#[pallet::getter(fn block_at_registration)]
-    pub(super) type BlockAtRegistration<T:Config> = StorageMap<
+	pub type UnbondingPeriod<T> = StorageValue<
 		_, 
-		Identity, 
-		u32, 
 		u64, 
 		ValueQuery,
-		DefaultBlockAtRegistration<T>
+		DefaultUnbondingPeriod<T>
 	>;
 
-	/// ************************************************************
-	///	-Genesis-Configuration
-	/// ************************************************************
-	/// ---- Genesis Configuration (Mostly used for testing.)
-    #[pallet::genesis_config]
-    pub struct GenesisConfig {
-        pub stake: Vec<(u64, u64)>,
-    }
+	#[pallet::type_value] 
+	pub fn DefaultHotkeySwapCost<T: Config>() -> BalanceOf<T> { T::InitialHotkeySwapCost::get() }
+	#[pallet::storage]
+	pub type HotkeySwapCost<T> = StorageValue<
+		_, 
+		BalanceOf<T>, 
+		ValueQuery,
+		DefaultHotkeySwapCost<T>
+	>;
 
-	#[cfg(feature = "std")]
-	impl Default for GenesisConfig {
-		fn default() -> Self {
-			Self {
-				stake: Default::default(),
-			}
-		}
-	}
-    
-    #[pallet::genesis_build]
-    impl<T:Config> GenesisBuild<T> for GenesisConfig {
-        fn build(&self) {		
-		}
-	}
+	/// ---- Maps from coldkey to its announced new coldkey and the block the swap executes at.
+	#[pallet::storage]
+    pub(super) type ColdkeySwaps<T:Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		(T::AccountId, u64), 
+		OptionQuery
+	>;
 
+	/// ---- Maps from coldkey to the number of pending swaps moving neurons onto it.
+	#[pallet::storage]
+    pub(super) type IncomingColdkeySwaps<T:Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		u32, 
+		ValueQuery
+	>;
 
-	#[cfg(feature = "std")]
-	impl GenesisConfig {
-		/// Direct implementation of `GenesisBuild::build_storage`.
-		///
-		/// Kept in order not to break dependency.
-		pub fn build_storage<T: Config>(&self) -> Result<sp_runtime::Storage, String> {
-			<Self as GenesisBuild<T>>::build_storage(self)
-		}
+	/// ---- Maps from block to the coldkeys whose announced swap executes at that block.
+	#[pallet::storage]
+    pub(super) type ColdkeySwapsDue<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u64, 
+		Vec<T::AccountId>, 
+		ValueQuery
+	>;
 
-		/// Direct implementation of `GenesisBuild::assimilate_storage`.
-		///
-		/// Kept in order not to break dependency.
-		pub fn assimilate_storage<T: Config>(
-			&self,
-			storage: &mut sp_runtime::Storage
-		) -> Result<(), String> {
-			<Self as GenesisBuild<T>>::assimilate_storage(self, storage)
-		}
+	#[pallet::type_value] 
+	pub fn DefaultColdkeySwapDelay<T: Config>() -> u64 { T::InitialColdkeySwapDelay::get() }
+	#[pallet::storage]
+	pub type ColdkeySwapDelay<T> = StorageValue<
+		_, 
+		u64, 
+		ValueQuery,
+		DefaultColdkeySwapDelay<T>
+	>;
+
+	/// ---- Maps from hotkey to the operator's cut of its emission, as a fraction of u16::MAX.
+	#[pallet::storage]
+	#[pallet::getter(fn take)]
+    pub(super) type Takes<T:Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		u16, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from hotkey to the block its take was last changed at.
+	#[pallet::storage]
+    pub(super) type LastTakeChange<T:Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		u64, 
+		OptionQuery
+	>;
+
+	#[pallet::type_value] 
+	pub fn DefaultMaxTake<T: Config>() -> u16 { T::InitialMaxTake::get() }
+	#[pallet::storage]
+	pub type MaxTake<T> = StorageValue<
+		_, 
+		u16, 
+		ValueQuery,
+		DefaultMaxTake<T>
+	>;
+
+	#[pallet::type_value] 
+	pub fn DefaultTakeRateLimit<T: Config>() -> u64 { T::InitialTakeRateLimit::get() }
+	#[pallet::storage]
+	pub type TakeRateLimit<T> = StorageValue<
+		_, 
+		u64, 
+		ValueQuery,
+		DefaultTakeRateLimit<T>
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn usedwork)]
+    pub(super) type UsedWork<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		Vec<u8>, 
+		u64,
+		ValueQuery
+	>;
+
+	/// ---- Seals used at each block, so UsedWork can be pruned once they leave the retention window.
+	#[pallet::storage]
+	pub type UsedWorkByBlock<T> = StorageMap<
+		_, 
+		Identity, 
+		u64, 
+		Vec<Vec<u8>>,
+		ValueQuery
+	>;
+
+	/// ---- The oldest block whose seals are still indexed in UsedWorkByBlock.
+	#[pallet::storage]
+	pub type UsedWorkPruneCursor<T> = StorageValue<
+		_, 
+		u64, 
+		ValueQuery
+	>;
+
+	/// ---- Raw UsedWork key after which seals used before UsedWorkByBlock existed are still
+	/// to be pruned. None once that backlog has been cleared.
+	#[pallet::storage]
+	pub type UsedWorkBacklogCursor<T> = StorageValue<
+		_, 
+		Vec<u8>, 
+		OptionQuery
+	>;
+
+	#[pallet::type_value] 
+	pub fn DefaultUsedWorkRetention<T: Config>() -> u64 { T::InitialUsedWorkRetention::get() }
+	#[pallet::storage]
+	pub type UsedWorkRetention<T> = StorageValue<
+		_, 
+		u64, 
+		ValueQuery,
+		DefaultUsedWorkRetention<T>
+	>;
+
+	/// ---- Maps from uid to hotkey. A uid is in use iff it has an entry here.
+	#[pallet::storage]
+	#[pallet::getter(fn key)]
+    pub(super) type Keys<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		T::AccountId, 
+		OptionQuery
+	>;
+
+	/// ---- Maps from uid to the coldkey which registered it.
+	#[pallet::storage]
+    pub(super) type Coldkeys<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		T::AccountId, 
+		OptionQuery
+	>;
+
+	/// ---- Maps from uid to the stake held by its hotkey.
+	#[pallet::storage]
+    pub(super) type Stake<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		BalanceOf<T>, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from uid to the axon endpoint it serves.
+	#[pallet::storage]
+    pub(super) type Axons<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		AxonInfo, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from uid to its sparse weights row.
+	#[pallet::storage]
+    pub(super) type Weights<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		Vec<(u32,u32)>, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from (uid_i, uid_j) to the bonds held by uid_i in uid_j. Zero bonds are not stored.
+	#[pallet::storage]
+    pub(super) type Bonds<T:Config> = StorageDoubleMap<
+		_, 
+		Identity, 
+		u32, 
+		Identity, 
+		u32, 
+		u64, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from uid to whether it was active at the last mechanism step.
+	#[pallet::storage]
+    pub(super) type Active<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		u32, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from uid to the block of its last chain update.
+	#[pallet::storage]
+    pub(super) type LastUpdate<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		u64, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from uid to its set weights transaction priority.
+	#[pallet::storage]
+    pub(super) type Priority<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		u64, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from uid to its rank at the last mechanism step.
+	#[pallet::storage]
+    pub(super) type Rank<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		u64, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from uid to its trust at the last mechanism step.
+	#[pallet::storage]
+    pub(super) type Trust<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		u64, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from uid to its consensus at the last mechanism step.
+	#[pallet::storage]
+    pub(super) type Consensus<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		u64, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from uid to its incentive at the last mechanism step.
+	#[pallet::storage]
+    pub(super) type Incentive<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		u64, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from uid to its dividends at the last mechanism step.
+	#[pallet::storage]
+    pub(super) type Dividends<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		u64, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from uid to its emission at the last mechanism step.
+	#[pallet::storage]
+    pub(super) type Emission<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		u64, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from uid to the operator's cut of its emission at the last mechanism step, taken before the stake is paid.
+	#[pallet::storage]
+	#[pallet::getter(fn operator_emission)]
+    pub(super) type OperatorEmission<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		u64, 
+		ValueQuery
+	>;
+
+	/// ---- Maps from uid to the part of its emission at the last mechanism step paid to its stake, pro-rata.
+	#[pallet::storage]
+	#[pallet::getter(fn stake_emission)]
+    pub(super) type StakeEmission<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		u64, 
+		ValueQuery
+	>;
+
+	#[pallet::type_value] 
+	pub fn DefaultBlockAtRegistration<T: Config>() -> u64 { 0 }
+	#[pallet::storage]
+	#[pallet::getter(fn block_at_registration)]
+    pub(super) type BlockAtRegistration<T:Config> = StorageMap<
+		_, 
+		Identity, 
+		u32, 
+		u64, 
+		ValueQuery,
+		DefaultBlockAtRegistration<T>
+	>;
+
+	/// ************************************************************
+	///	-Genesis-Configuration
+	/// ************************************************************
+	/// ---- A neuron registered at genesis. Neurons take uids in the order they are listed.
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct GenesisNeuron<AccountId, Balance> {
+		/// ---- The hotkey the neuron is registered under.
+		pub hotkey: AccountId,
+
+		/// ---- The coldkey owning the hotkey.
+		pub coldkey: AccountId,
+
+		/// ---- Stake held by the hotkey, added to TotalStake.
+		pub stake: Balance,
+
+		/// ---- The axon the neuron serves.
+		pub axon: AxonInfo,
+
+		/// ---- Sparse (uid, weight) pairs. Left empty the neuron starts with a self weight.
+		pub weights: Vec<(u32, u32)>,
+	}
+
+	/// ---- Hyperparameter overrides for a network. Unset fields keep the Config initial value.
+	#[derive(Clone, Default, PartialEq, Eq, RuntimeDebug)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(default, deny_unknown_fields))]
+	pub struct GenesisHyperparams<Balance> {
+		pub rho: Option<u64>,
+		pub kappa: Option<u64>,
+		pub incentive_pruning_denominator: Option<u64>,
+		pub stake_pruning_denominator: Option<u64>,
+		pub stake_pruning_min: Option<u64>,
+		pub validator_epoch_len: Option<u64>,
+		pub validator_epochs_per_reset: Option<u64>,
+		pub validator_batch_size: Option<u64>,
+		pub validator_sequence_length: Option<u64>,
+		pub validator_exclude_quantile: Option<u8>,
+		pub max_allowed_uids: Option<u64>,
+		pub min_allowed_weights: Option<u64>,
+		pub max_allowed_max_min_ratio: Option<u64>,
+		pub max_weight_limit: Option<u32>,
+		pub immunity_period: Option<u64>,
+		pub blocks_per_step: Option<u64>,
+		pub bonds_moving_average: Option<u64>,
+		pub activity_cutoff: Option<u64>,
+		pub difficulty: Option<u64>,
+		pub adjustment_interval: Option<u64>,
+		pub target_registrations_per_interval: Option<u64>,
+		pub max_registrations_per_block: Option<u64>,
+		pub foundation_distribution: Option<u64>,
+		pub scaling_law_power: Option<u8>,
+		pub synergy_scaling_law_power: Option<u8>,
+		pub used_work_retention: Option<u64>,
+		pub unbonding_period: Option<u64>,
+		pub hotkey_swap_cost: Option<Balance>,
+		pub coldkey_swap_delay: Option<u64>,
+		pub max_take: Option<u16>,
+		pub take_rate_limit: Option<u64>,
+		pub burn: Option<Balance>,
+		pub legacy_seal_cutoff: Option<u64>,
+		pub min_difficulty: Option<u64>,
+		pub max_difficulty: Option<u64>,
+		pub min_burn: Option<Balance>,
+		pub max_burn: Option<Balance>,
+	}
+
+	/// ---- Genesis Configuration. Seeds a network with neurons and hyperparameters,
+	/// used for local networks and for re-genesis from an exported metagraph.
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+		/// ---- Extra (uid, stake) added to the neurons below.
+        pub stake: Vec<(u64, BalanceOf<T>)>,
+
+		/// ---- Neurons registered at block 0, taking uids 0..neurons.len().
+		#[cfg_attr(feature = "std", serde(default))]
+		pub neurons: Vec<GenesisNeuron<T::AccountId, BalanceOf<T>>>,
+
+		/// ---- Hyperparameters overriding the Config initial values.
+		#[cfg_attr(feature = "std", serde(default))]
+		pub hyperparameters: GenesisHyperparams<BalanceOf<T>>,
+    }
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self {
+				stake: Default::default(),
+				neurons: Default::default(),
+				hyperparameters: Default::default(),
+			}
+		}
+	}
+    
+    #[pallet::genesis_build]
+    impl<T:Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+			// Hyperparameters go first so the neuron checks below see max_allowed_uids.
+			let params = &self.hyperparameters;
+			if let Some( rho ) = params.rho { Pallet::<T>::set_rho( rho ); }
+			if let Some( kappa ) = params.kappa { Pallet::<T>::set_kappa( kappa ); }
+			if let Some( denominator ) = params.incentive_pruning_denominator { Pallet::<T>::set_incentive_pruning_denominator( denominator ); }
+			if let Some( denominator ) = params.stake_pruning_denominator { Pallet::<T>::set_stake_pruning_denominator( denominator ); }
+			if let Some( min ) = params.stake_pruning_min { Pallet::<T>::set_stake_pruning_min( min ); }
+			if let Some( len ) = params.validator_epoch_len { Pallet::<T>::set_validator_epoch_len( len ); }
+			if let Some( epochs ) = params.validator_epochs_per_reset { Pallet::<T>::set_validator_epochs_per_reset( epochs ); }
+			if let Some( size ) = params.validator_batch_size { Pallet::<T>::set_validator_batch_size( size ); }
+			if let Some( len ) = params.validator_sequence_length { Pallet::<T>::set_validator_sequence_length( len ); }
+			if let Some( quantile ) = params.validator_exclude_quantile { Pallet::<T>::set_validator_exclude_quantile( quantile ); }
+			if let Some( max ) = params.max_allowed_uids { Pallet::<T>::set_max_allowed_uids( max ); }
+			if let Some( min ) = params.min_allowed_weights { Pallet::<T>::set_min_allowed_weights( min ); }
+			if let Some( ratio ) = params.max_allowed_max_min_ratio { Pallet::<T>::set_max_allowed_max_min_ratio( ratio ); }
+			if let Some( limit ) = params.max_weight_limit { Pallet::<T>::set_max_weight_limit( limit ); }
+			if let Some( period ) = params.immunity_period { Pallet::<T>::set_immunity_period( period ); }
+			if let Some( blocks ) = params.blocks_per_step { Pallet::<T>::set_blocks_per_step( blocks ); }
+			if let Some( average ) = params.bonds_moving_average { Pallet::<T>::set_bonds_moving_average( average ); }
+			if let Some( cutoff ) = params.activity_cutoff { Pallet::<T>::set_activity_cutoff( cutoff ); }
+			if let Some( difficulty ) = params.difficulty { Pallet::<T>::set_difficulty_from_u64( difficulty ); }
+			if let Some( interval ) = params.adjustment_interval { Pallet::<T>::set_adjustment_interval( interval ); }
+			if let Some( target ) = params.target_registrations_per_interval { Pallet::<T>::set_target_registrations_per_interval( target ); }
+			if let Some( max ) = params.max_registrations_per_block { Pallet::<T>::set_max_registratations_per_block( max ); }
+			if let Some( distribution ) = params.foundation_distribution { FoundationDistribution::<T>::put( distribution ); }
+			if let Some( power ) = params.scaling_law_power { Pallet::<T>::set_scaling_law_power( power ); }
+			if let Some( power ) = params.synergy_scaling_law_power { Pallet::<T>::set_synergy_scaling_law_power( power ); }
+			if let Some( retention ) = params.used_work_retention { Pallet::<T>::set_used_work_retention( retention ); }
+			if let Some( period ) = params.unbonding_period { Pallet::<T>::set_unbonding_period( period ); }
+			if let Some( cost ) = params.hotkey_swap_cost { Pallet::<T>::set_hotkey_swap_cost( cost ); }
+			if let Some( delay ) = params.coldkey_swap_delay { Pallet::<T>::set_coldkey_swap_delay( delay ); }
+			if let Some( take ) = params.max_take { Pallet::<T>::set_max_take( take ); }
+			if let Some( limit ) = params.take_rate_limit { Pallet::<T>::set_take_rate_limit( limit ); }
+			if let Some( burn ) = params.burn { Pallet::<T>::set_burn( burn ); }
+			if let Some( cutoff ) = params.legacy_seal_cutoff { Pallet::<T>::set_legacy_seal_cutoff( cutoff ); }
+			if let Some( difficulty ) = params.min_difficulty { Pallet::<T>::set_min_difficulty( difficulty ); }
+			if let Some( difficulty ) = params.max_difficulty { Pallet::<T>::set_max_difficulty( difficulty ); }
+			assert!( Pallet::<T>::get_min_difficulty() <= Pallet::<T>::get_max_difficulty(), "genesis min_difficulty above max_difficulty" );
+			if let Some( burn ) = params.min_burn { Pallet::<T>::set_min_burn( burn ); }
+			if let Some( burn ) = params.max_burn { Pallet::<T>::set_max_burn( burn ); }
+			assert!( Pallet::<T>::get_min_burn() <= Pallet::<T>::get_max_burn(), "genesis min_burn above max_burn" );
+
+			// Neurons are written the way do_registration writes them, at block 0 and without pruning.
+			let n: usize = self.neurons.len();
+			assert!( n as u64 <= Pallet::<T>::get_max_allowed_uids(), "genesis neurons exceed max_allowed_uids" );
+			for neuron in self.neurons.iter() {
+				assert!( !Hotkeys::<T>::contains_key( &neuron.hotkey ), "duplicate genesis hotkey" );
+				assert!( neuron.weights.iter().all( |( uid_j, _ )| ( *uid_j as usize ) < n ), "genesis weight to an unknown uid" );
+				let uid: u32 = Pallet::<T>::get_next_uid();
+				BlockAtRegistration::<T>::insert( uid, 0 );
+				Pallet::<T>::init_neuron( uid, &neuron.hotkey, &neuron.coldkey, 0 );
+				Hotkeys::<T>::insert( &neuron.hotkey, uid );
+				ColdkeyHotkeys::<T>::append( &neuron.coldkey, &neuron.hotkey );
+				Axons::<T>::insert( uid, neuron.axon.clone() );
+				if !neuron.weights.is_empty() {
+					Weights::<T>::insert( uid, neuron.weights.clone() );
+				}
+				assert!( Pallet::<T>::mint_reserved_stake( &neuron.coldkey, neuron.stake ) == neuron.stake, "genesis stake below the existential deposit" );
+				Stake::<T>::insert( uid, neuron.stake );
+				Pallet::<T>::increase_total_stake( neuron.stake );
+			}
+
+			for ( uid, stake ) in self.stake.iter() {
+				let uid: u32 = *uid as u32;
+				assert!( Pallet::<T>::is_uid_active( uid ), "genesis stake for an unknown uid" );
+				let coldkey: T::AccountId = Pallet::<T>::get_coldkey_for_uid( uid );
+				assert!( Pallet::<T>::mint_reserved_stake( &coldkey, *stake ) == *stake, "genesis stake below the existential deposit" );
+				Stake::<T>::mutate( uid, |stake_i| *stake_i = stake_i.saturating_add( *stake ) );
+				Pallet::<T>::increase_total_stake( *stake );
+			}
+
+			// Genesis stake is minted like emission, so our issuance starts out as the balances pallet's.
+			TotalIssuance::<T>::put( T::Currency::total_issuance() );
+		}
+	}
+
+
+	#[cfg(feature = "std")]
+	impl<T: Config> GenesisConfig<T> {
+		/// Direct implementation of `GenesisBuild::build_storage`.
+		///
+		/// Kept in order not to break dependency.
+		pub fn build_storage(&self) -> Result<sp_runtime::Storage, String> {
+			<Self as GenesisBuild<T>>::build_storage(self)
+		}
+
+		/// Direct implementation of `GenesisBuild::assimilate_storage`.
+		///
+		/// Kept in order not to break dependency.
+		pub fn assimilate_storage(
+			&self,
+			storage: &mut sp_runtime::Storage
+		) -> Result<(), String> {
+			<Self as GenesisBuild<T>>::assimilate_storage(self, storage)
+		}
 	}
 
 	
@@ -747,11 +1678,54 @@ pub mod pallet {
 
 		/// --- Event created during when stake has been transfered from 
 		/// the coldkey onto the hotkey staking account.
-		StakeAdded(T::AccountId, u64),
+		StakeAdded(T::AccountId, BalanceOf<T>),
 
 		/// --- Event created when stake has been removed from 
 		/// the staking account into the coldkey account.
-		StakeRemoved(T::AccountId, u64),
+		StakeRemoved(T::AccountId, BalanceOf<T>),
+
+		/// --- Event created when a coldkey has moved stake from one of its hotkeys to another.
+		StakeMoved(T::AccountId, T::AccountId, T::AccountId, BalanceOf<T>),
+
+		/// --- Event created when a hotkey opts in to accepting stake from any coldkey.
+		DelegateAdded(T::AccountId),
+
+		/// --- Event created when a nominator coldkey has delegated stake to a hotkey.
+		StakeDelegated(T::AccountId, T::AccountId, BalanceOf<T>),
+
+		/// --- Event created when a nominator coldkey has withdrawn delegated stake from a hotkey.
+		StakeUndelegated(T::AccountId, T::AccountId, BalanceOf<T>),
+
+		/// --- Event created when removed stake has been queued on a coldkey, 
+		/// with the block from which it can be withdrawn.
+		StakeUnbonding(T::AccountId, BalanceOf<T>, u64),
+
+		/// --- Event created when unlocked stake has been withdrawn into the coldkey account.
+		UnbondedWithdrawn(T::AccountId, BalanceOf<T>),
+
+		/// --- Event created when a coldkey has set the take of one of its hotkeys.
+		TakeSet(T::AccountId, u16),
+
+		/// --- Event created when the step has split the emission of a hotkey with a take,
+		/// into the operator's cut and the part paid to its stake.
+		EmissionSplit(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+
+		/// --- Event created when a coldkey has set where the emission of one of its hotkeys is paid.
+		EmissionDestinationSet(T::AccountId, EmissionDestination),
+
+		/// --- Event created when a coldkey has moved one of its neurons from the old hotkey to the new one.
+		HotkeySwapped(T::AccountId, T::AccountId, T::AccountId),
+
+		/// --- Event created when a coldkey has announced a swap to a new coldkey, 
+		/// with the block the swap executes at.
+		ColdkeySwapAnnounced(T::AccountId, T::AccountId, u64),
+
+		/// --- Event created when an announced coldkey swap is cancelled, by the coldkey or when it is due
+		/// because the new coldkey does not exist and the stake it moves is below the existential deposit.
+		ColdkeySwapCancelled(T::AccountId),
+
+		/// --- Event created when the neurons of a coldkey have moved to its announced new coldkey.
+		ColdkeySwapped(T::AccountId, T::AccountId),
 
 		/// --- Event created when the difficulty has been set.
 		DifficultySet(u64),
@@ -828,8 +1802,47 @@ pub mod pallet {
 		/// --- Event created when the immunity period has been set.
 		ImmunityPeriodSet(u64),
 
+		/// --- Event created when the used work retention has been set.
+		UsedWorkRetentionSet(u64),
+
+		/// --- Event created when the unbonding period has been set.
+		UnbondingPeriodSet(u64),
+
+		/// --- Event created when the hotkey swap cost has been set.
+		HotkeySwapCostSet(BalanceOf<T>),
+
+		/// --- Event created when the coldkey swap delay has been set.
+		ColdkeySwapDelaySet(u64),
+
+		/// --- Event created when the max take has been set.
+		MaxTakeSet(u16),
+
+		/// --- Event created when the take rate limit has been set.
+		TakeRateLimitSet(u64),
+
+		/// --- Event created when the registration burn has been set.
+		BurnSet(BalanceOf<T>),
+
+		/// --- Event created when the legacy seal cutoff block has been set.
+		LegacySealCutoffSet(u64),
+
+		/// --- Event created when the minimum difficulty has been set.
+		MinDifficultySet(u64),
+
+		/// --- Event created when the maximum difficulty has been set.
+		MaxDifficultySet(u64),
+
+		/// --- Event created when the minimum burn has been set.
+		MinBurnSet(BalanceOf<T>),
+
+		/// --- Event created when the maximum burn has been set.
+		MaxBurnSet(BalanceOf<T>),
+
 		/// --- Event thrown when bonds have been reset.
-		ResetBonds()
+		ResetBonds(),
+
+		/// --- Event created when root has recomputed the total stake and total issuance.
+		TotalsRepaired(BalanceOf<T>, BalanceOf<T>)
 	}
 
 	// Errors inform users that something went wrong.
@@ -900,7 +1913,8 @@ pub mod pallet {
 		BalanceWithdrawalError,
 
 		/// ---- Thrown when the dispatch attempts to convert between a u64 and T::balance 
-		/// but the call fails.
+		/// but the call fails. No longer thrown now that stake is held as a Balance, kept so the
+		/// errors after it keep their index.
 		CouldNotConvertToBalance,
 
 		/// ---- Thrown when the dispatch attempts to set weights on chain with fewer elements 
@@ -920,6 +1934,66 @@ pub mod pallet {
 
 		/// ---- Thrown when the caller attempts to set a storage value outside of its allowed range.
 		StorageValueOutOfRange,
+
+		/// ---- Thrown when a coldkey delegates stake to a hotkey which has not opted in as a delegate.
+		NotDelegate,
+
+		/// ---- Thrown when the owner of a hotkey opts it in as a delegate twice.
+		AlreadyDelegate,
+
+		/// ---- Thrown when the delegated amount is too small to buy a share in the delegate's stake.
+		DelegatedStakeTooSmall,
+
+		/// ---- Thrown when stake moved onto an account that does not exist is below the existential deposit,
+		/// which would burn it.
+		StakeBelowExistentialDeposit,
+
+		/// ---- Thrown when a new coldkey nominates a delegate which already has MAX_NOMINATORS_PER_DELEGATE nominators.
+		TooManyNominators,
+
+		/// ---- Thrown when a coldkey removes stake while it already has MAX_UNBONDING_CHUNKS chunks queued.
+		TooManyUnbondingChunks,
+
+		/// ---- Thrown when a coldkey withdraws unbonded stake but none of its chunks has unlocked yet.
+		NoUnlockedStake,
+
+		/// ---- Thrown when a coldkey swaps a hotkey without the balance to pay the hotkey swap cost.
+		NotEnoughBalanceToSwapHotkey,
+
+		/// ---- Thrown when a coldkey announces a swap to itself.
+		ColdkeySwapToSelf,
+
+		/// ---- Thrown when a coldkey announces a swap while it already has one pending.
+		ColdkeySwapAlreadyAnnounced,
+
+		/// ---- Thrown when a coldkey with no registered neurons announces a swap.
+		NoOwnedHotkeys,
+
+		/// ---- Thrown when MAX_COLDKEY_SWAPS_PER_BLOCK swaps already execute at the block a new swap would.
+		TooManyColdkeySwapsThisBlock,
+
+		/// ---- Thrown when the swaps already executing at the block a new swap would, together with the
+		/// new swap, move more than MAX_COLDKEY_SWAP_HOTKEYS_PER_BLOCK hotkeys.
+		TooManyColdkeySwapHotkeysThisBlock,
+
+		/// ---- Thrown when a neuron is registered to a coldkey with a pending swap, or a swap is announced
+		/// to a coldkey with a pending swap or by a coldkey that pending swaps move neurons onto.
+		ColdkeySwapPending,
+
+		/// ---- Thrown when a coldkey cancels a swap but has none pending.
+		NoColdkeySwapAnnounced,
+
+		/// ---- Thrown when a coldkey sets a take above the max take.
+		TakeTooHigh,
+
+		/// ---- Thrown when a coldkey changes a take again before the take rate limit has passed.
+		TakeRateLimitExceeded,
+
+		/// ---- Thrown when a coldkey registers through a burn without the balance to pay it.
+		NotEnoughBalanceToRegister,
+
+		/// ---- Thrown when a seal in the legacy format, not bound to the hotkey, is submitted from the legacy seal cutoff block on.
+		LegacySealExpired,
 	}
 
 	impl<T: Config> Printable for Error<T> {
@@ -947,7 +2021,8 @@ pub mod pallet {
 		/// 	* 'n': (T::BlockNumber):
 		/// 		- The number of the block we are initializing.
 		fn on_initialize( _n: BlockNumberFor<T> ) -> Weight {
-			
+			let mut weight: Weight = T::WeightInfo::on_initialize();
+
 			// Only run the block step every `blocks_per_step`.
 			// Initially `blocks_since_last_step + 1` is 0 but increments until it reaches `blocks_per_step`.
 			// We use the >= here in the event that we lower get_blocks per step and these qualities never meet.
@@ -959,7 +2034,7 @@ pub mod pallet {
 				let emission_this_step:u64 = ( Self::get_blocks_since_last_step() + 1 ) * Self::get_block_emission();
 
 				// Apply emission step based on mechanism and updates values.
-				Self::mechanism_step( emission_this_step );
+				weight = weight.saturating_add( Self::mechanism_step( emission_this_step ) );
 
 				// Reset counter down to 0, this ensures that if `blocks_per_step=1` we will do an emission on every block.
 				// If `blocks_per_step=2` we will skip the next block, since (0+1) !>= 2, add one to the counter, and then apply the next
@@ -972,9 +2047,30 @@ pub mod pallet {
 			}
 
 			// Make a difficulty update.
-			Self::update_difficulty();
-			
-			return 0;
+			weight = weight.saturating_add( Self::update_difficulty() );
+
+			// Move the neurons of coldkeys whose announced swap is due.
+			weight = weight.saturating_add( Self::execute_due_coldkey_swaps() );
+
+			// Forget seals that can no longer be replayed.
+			weight.saturating_add( Self::prune_used_work() )
+		}
+
+		/// ---- Called when the runtime is upgraded, before any block is initialized.
+		/// Runs every migration step the on-chain storage version has not seen yet.
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate_to_current_version::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			migrations::pre_upgrade::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			migrations::post_upgrade::<T>()?;
+			Self::ensure_invariants()
 		}
 	}
     
@@ -998,115 +2094,462 @@ pub mod pallet {
 		/// will be corrected for this deviation. 
 		/// 
 		/// # Args:
-		/// 	* `origin`: (<T as frame_system::Config>Origin):
-		/// 		- The caller, a hotkey who wishes to set their weights.
-		/// 
-		/// 	* `uids` (Vec<u32>):
-		/// 		- The edge endpoint for the weight, i.e. j for w_ij.
+		/// 	* `origin`: (<T as frame_system::Config>Origin):
+		/// 		- The caller, a hotkey who wishes to set their weights.
+		/// 
+		/// 	* `uids` (Vec<u32>):
+		/// 		- The edge endpoint for the weight, i.e. j for w_ij.
+		///
+		/// 	* 'weights' (Vec<u32>):
+		/// 		- The u32 integer encoded weights. Interpreted as rational
+		/// 		values in the range [0,1]. They must sum to in32::MAX.
+		///
+		/// # Event:
+		/// 	* WeightsSet;
+		/// 		- On successfully setting the weights on chain.
+		///
+		/// # Raises:
+		/// 	* 'WeightVecNotEqualSize':
+		/// 		- If the passed weights and uids have unequal size.
+		///
+		/// 	* 'WeightSumToLarge':
+		/// 		- When the calling coldkey is not associated with the hotkey account.
+		///
+		/// 	* 'InsufficientBalance':
+		/// 		- When the amount to stake exceeds the amount of balance in the
+		/// 		associated colkey account.
+		///
+        #[pallet::weight((T::WeightInfo::set_weights(dests.len() as u32), DispatchClass::Normal, Pays::No))]
+		pub fn set_weights(
+			origin:OriginFor<T>, 
+			dests: Vec<u32>, 
+			weights: Vec<u32>
+		) -> DispatchResult {
+			Self::do_set_weights(origin, dests, weights)
+		}
+		
+		/// --- Adds stake to a neuron account. The call is made from the
+		/// coldkey account linked in the neurons's NeuronMetadata.
+		/// Only the associated coldkey is allowed to make staking and
+		/// unstaking requests. This protects the neuron against
+		/// attacks on its hotkey running in production code.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, a coldkey signature associated with the hotkey account.
+		///
+		/// 	* 'hotkey' (T::AccountId):
+		/// 		- The hotkey account to add stake to.
+		///
+		/// 	* 'ammount_staked' (BalanceOf<T>):
+		/// 		- The ammount to transfer from the balances account of the cold key
+		/// 		into the staking account of the hotkey.
+		///
+		/// # Event:
+		/// 	* 'StakeAdded':
+		/// 		- On the successful staking of funds.
+		///
+		/// # Raises:
+		/// 	* 'NotRegistered':
+		/// 		- If the hotkey account is not active (has not subscribed)
+		///
+		/// 	* 'NonAssociatedColdKey':
+		/// 		- When the calling coldkey is not associated with the hotkey account.
+		///
+		/// 	* 'InsufficientBalance':
+		/// 		- When the amount to stake exceeds the amount of balance in the
+		/// 		associated colkey account.
+		///
+		#[pallet::weight((T::WeightInfo::add_stake(), DispatchClass::Normal, Pays::No))]
+		pub fn add_stake(
+			origin:OriginFor<T>, 
+			hotkey: T::AccountId, 
+			ammount_staked: BalanceOf<T>
+		) -> DispatchResult {
+			Self::do_add_stake(origin, hotkey, ammount_staked)
+		}
+
+		/// ---- Remove stake from the staking account. The call must be made
+		/// from the coldkey account attached to the neuron metadata. Only this key
+		/// has permission to make staking and unstaking requests.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, a coldkey signature associated with the hotkey account.
+		///
+		/// 	* 'hotkey' (T::AccountId):
+		/// 		- The hotkey account to withdraw stake from.
+		///
+		/// 	* 'ammount_unstaked' (BalanceOf<T>):
+		/// 		- The ammount to move from the staking account into the coldkey's
+		/// 		unbonding queue. It can be withdrawn once the unbonding period has passed.
+		///
+		/// # Event:
+		/// 	* 'StakeRemoved':
+		/// 		- On successful withdrawl.
+		///
+		/// 	* 'StakeUnbonding':
+		/// 		- On the amount being queued for release.
+		///
+		/// # Raises:
+		/// 	* 'NonAssociatedColdKey':
+		/// 		- When the calling coldkey is not associated with the hotkey account.
+		///
+		/// 	* 'NotEnoughStaketoWithdraw':
+		/// 		- When the amount to unstake exceeds the quantity staked in the
+		/// 		associated hotkey staking account.
+		///
+		/// 	* 'TooManyUnbondingChunks':
+		/// 		- When the coldkey already has MAX_UNBONDING_CHUNKS chunks queued.
+		///
+		#[pallet::weight((T::WeightInfo::remove_stake(), DispatchClass::Normal, Pays::No))]
+		pub fn remove_stake(
+			origin:OriginFor<T>, 
+			hotkey: T::AccountId, 
+			ammount_unstaked: BalanceOf<T>
+		) -> DispatchResult {
+			Self::do_remove_stake(origin, hotkey, ammount_unstaked)
+		}
+
+		/// ---- Moves stake between two hotkeys registered by the same coldkey without it
+		/// passing through the coldkey's balance. The total stake is unchanged.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, the coldkey associated with both hotkey accounts.
+		///
+		/// 	* 'from_hotkey' (T::AccountId):
+		/// 		- The hotkey account to take the stake from.
+		///
+		/// 	* 'to_hotkey' (T::AccountId):
+		/// 		- The hotkey account to add the stake to.
+		///
+		/// 	* 'ammount_moved' (BalanceOf<T>):
+		/// 		- The ammount to move between the staking accounts.
+		///
+		/// # Event:
+		/// 	* 'StakeMoved':
+		/// 		- On the successful move of the stake.
+		///
+		/// # Raises:
+		/// 	* 'NonAssociatedColdKey':
+		/// 		- When the calling coldkey is not associated with one of the hotkey accounts.
+		///
+		/// 	* 'NotEnoughStaketoWithdraw':
+		/// 		- When the amount exceeds the stake the coldkey owns in from_hotkey.
+		///
+		#[pallet::weight((T::WeightInfo::move_stake(), DispatchClass::Normal, Pays::Yes))]
+		pub fn move_stake(
+			origin:OriginFor<T>, 
+			from_hotkey: T::AccountId, 
+			to_hotkey: T::AccountId, 
+			ammount_moved: BalanceOf<T>
+		) -> DispatchResult {
+			Self::do_move_stake(origin, from_hotkey, to_hotkey, ammount_moved)
+		}
+
+		/// ---- Opts a hotkey in to accepting stake from any coldkey. The call must be made
+		/// from the coldkey which registered the hotkey. 
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, the coldkey associated with the hotkey account.
+		///
+		/// 	* 'hotkey' (T::AccountId):
+		/// 		- The hotkey to open to nominators.
+		///
+		/// # Event:
+		/// 	* 'DelegateAdded':
+		/// 		- On the hotkey becoming a delegate.
+		///
+		/// # Raises:
+		/// 	* 'NonAssociatedColdKey':
+		/// 		- When the calling coldkey is not associated with the hotkey account.
+		///
+		/// 	* 'AlreadyDelegate':
+		/// 		- When the hotkey is already a delegate.
+		///
+		#[pallet::weight((T::WeightInfo::become_delegate(), DispatchClass::Normal, Pays::Yes))]
+		pub fn become_delegate(
+			origin:OriginFor<T>, 
+			hotkey: T::AccountId
+		) -> DispatchResult {
+			Self::do_become_delegate(origin, hotkey)
+		}
+
+		/// ---- Delegates stake from any coldkey to a delegate hotkey. The caller receives
+		/// shares in the hotkey's delegated stake and earns the hotkey's emission pro-rata.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, the nominating coldkey.
+		///
+		/// 	* 'hotkey' (T::AccountId):
+		/// 		- The delegate hotkey to stake into.
+		///
+		/// 	* 'ammount_delegated' (BalanceOf<T>):
+		/// 		- The ammount to transfer from the balances account of the cold key
+		/// 		into the staking account of the hotkey.
+		///
+		/// # Event:
+		/// 	* 'StakeDelegated':
+		/// 		- On the successful delegation of funds.
+		///
+		/// # Raises:
+		/// 	* 'NotDelegate':
+		/// 		- When the hotkey has not opted in as a delegate.
+		///
+		/// 	* 'NotEnoughBalanceToStake':
+		/// 		- When the amount to delegate exceeds the balance of the coldkey.
+		///
+		/// 	* 'StakeBelowExistentialDeposit':
+		/// 		- When the delegation pool is empty and the amount is below the existential deposit.
+		///
+		#[pallet::weight((T::WeightInfo::delegate_stake(), DispatchClass::Normal, Pays::Yes))]
+		pub fn delegate_stake(
+			origin:OriginFor<T>, 
+			hotkey: T::AccountId, 
+			ammount_delegated: BalanceOf<T>
+		) -> DispatchResult {
+			Self::do_delegate_stake(origin, hotkey, ammount_delegated)
+		}
+
+		/// ---- Withdraws delegated stake from a hotkey back into the nominating coldkey.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, the nominating coldkey.
+		///
+		/// 	* 'hotkey' (T::AccountId):
+		/// 		- The delegate hotkey to withdraw from.
+		///
+		/// 	* 'ammount_undelegated' (BalanceOf<T>):
+		/// 		- The ammount to move into the coldkey's unbonding queue.
+		///
+		/// # Event:
+		/// 	* 'StakeUndelegated':
+		/// 		- On successful withdrawl.
+		///
+		/// 	* 'StakeUnbonding':
+		/// 		- On the amount being queued for release.
+		///
+		/// # Raises:
+		/// 	* 'NotEnoughStaketoWithdraw':
+		/// 		- When the amount exceeds the stake the caller's shares are worth.
+		///
+		/// 	* 'TooManyUnbondingChunks':
+		/// 		- When the coldkey already has MAX_UNBONDING_CHUNKS chunks queued.
+		///
+		/// 	* 'StakeBelowExistentialDeposit':
+		/// 		- When the coldkey holds no funds and the amount is below the existential deposit.
+		///
+		#[pallet::weight((T::WeightInfo::undelegate_stake(), DispatchClass::Normal, Pays::Yes))]
+		pub fn undelegate_stake(
+			origin:OriginFor<T>, 
+			hotkey: T::AccountId, 
+			ammount_undelegated: BalanceOf<T>
+		) -> DispatchResult {
+			Self::do_undelegate_stake(origin, hotkey, ammount_undelegated)
+		}
+
+		/// ---- Withdraws the caller's unbonding stake whose unbonding period has passed
+		/// into the balance of the coldkey. Chunks still locked stay queued.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, the coldkey which removed the stake.
 		///
-		/// 	* 'weights' (Vec<u32>):
-		/// 		- The u32 integer encoded weights. Interpreted as rational
-		/// 		values in the range [0,1]. They must sum to in32::MAX.
+		/// # Event:
+		/// 	* 'UnbondedWithdrawn':
+		/// 		- On the unlocked stake being paid out.
+		///
+		/// # Raises:
+		/// 	* 'NoUnlockedStake':
+		/// 		- When none of the caller's chunks has unlocked yet.
+		///
+		#[pallet::weight((T::WeightInfo::withdraw_unbonded(), DispatchClass::Normal, Pays::Yes))]
+		pub fn withdraw_unbonded(
+			origin:OriginFor<T>
+		) -> DispatchResult {
+			Self::do_withdraw_unbonded(origin)
+		}
+
+		/// ---- Sets the take of a hotkey registered by the calling coldkey: the operator's cut of
+		/// the hotkey's emission, taken before the rest is paid to its stake pro-rata. The take
+		/// can change once per take rate limit blocks and the max take caps it.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, the coldkey which registered the hotkey.
+		///
+		/// 	* 'hotkey' (T::AccountId):
+		/// 		- The registered hotkey to set the take for.
+		///
+		/// 	* 'take' (u16):
+		/// 		- The operator's cut as a fraction of u16::MAX.
 		///
 		/// # Event:
-		/// 	* WeightsSet;
-		/// 		- On successfully setting the weights on chain.
+		/// 	* 'TakeSet':
+		/// 		- On the take being set.
 		///
 		/// # Raises:
-		/// 	* 'WeightVecNotEqualSize':
-		/// 		- If the passed weights and uids have unequal size.
+		/// 	* 'NotRegistered':
+		/// 		- When the hotkey is not registered.
 		///
-		/// 	* 'WeightSumToLarge':
-		/// 		- When the calling coldkey is not associated with the hotkey account.
+		/// 	* 'NonAssociatedColdKey':
+		/// 		- When the calling coldkey did not register the hotkey.
 		///
-		/// 	* 'InsufficientBalance':
-		/// 		- When the amount to stake exceeds the amount of balance in the
-		/// 		associated colkey account.
+		/// 	* 'TakeTooHigh':
+		/// 		- When the take is above the max take.
 		///
-        #[pallet::weight((0, DispatchClass::Normal, Pays::No))]
-		pub fn set_weights(
+		/// 	* 'TakeRateLimitExceeded':
+		/// 		- When the take changed less than take rate limit blocks ago.
+		///
+		#[pallet::weight((T::WeightInfo::set_take(), DispatchClass::Normal, Pays::Yes))]
+		pub fn set_take(
 			origin:OriginFor<T>, 
-			dests: Vec<u32>, 
-			weights: Vec<u32>
+			hotkey: T::AccountId,
+			take: u16
 		) -> DispatchResult {
-			Self::do_set_weights(origin, dests, weights)
+			Self::do_set_take(origin, hotkey, take)
 		}
-		
-		/// --- Adds stake to a neuron account. The call is made from the
-		/// coldkey account linked in the neurons's NeuronMetadata.
-		/// Only the associated coldkey is allowed to make staking and
-		/// unstaking requests. This protects the neuron against
-		/// attacks on its hotkey running in production code.
+
+		/// ---- Sets where the emission the calling coldkey earns on one of its hotkeys is paid:
+		/// compounded into the hotkey's stake, the default, or minted into the coldkey's free balance.
+		/// The emission earned by nominators of a delegate always compounds.
 		///
 		/// # Args:
 		/// 	* 'origin': (<T as frame_system::Config>Origin):
-		/// 		- The caller, a coldkey signature associated with the hotkey account.
+		/// 		- The caller, the coldkey which registered the hotkey.
 		///
 		/// 	* 'hotkey' (T::AccountId):
-		/// 		- The hotkey account to add stake to.
+		/// 		- The registered hotkey to set the destination for.
 		///
-		/// 	* 'ammount_staked' (u64):
-		/// 		- The ammount to transfer from the balances account of the cold key
-		/// 		into the staking account of the hotkey.
+		/// 	* 'destination' (EmissionDestination):
+		/// 		- Stake to compound or Coldkey to pay out.
 		///
 		/// # Event:
-		/// 	* 'StakeAdded':
-		/// 		- On the successful staking of funds.
+		/// 	* 'EmissionDestinationSet':
+		/// 		- On the destination being set.
 		///
 		/// # Raises:
 		/// 	* 'NotRegistered':
-		/// 		- If the hotkey account is not active (has not subscribed)
+		/// 		- When the hotkey is not registered.
 		///
 		/// 	* 'NonAssociatedColdKey':
-		/// 		- When the calling coldkey is not associated with the hotkey account.
-		///
-		/// 	* 'InsufficientBalance':
-		/// 		- When the amount to stake exceeds the amount of balance in the
-		/// 		associated colkey account.
+		/// 		- When the calling coldkey did not register the hotkey.
 		///
-		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
-		pub fn add_stake(
+		#[pallet::weight((T::WeightInfo::set_emission_destination(), DispatchClass::Normal, Pays::Yes))]
+		pub fn set_emission_destination(
 			origin:OriginFor<T>, 
-			hotkey: T::AccountId, 
-			ammount_staked: u64
+			hotkey: T::AccountId,
+			destination: EmissionDestination
 		) -> DispatchResult {
-			Self::do_add_stake(origin, hotkey, ammount_staked)
+			Self::do_set_emission_destination(origin, hotkey, destination)
 		}
 
-		/// ---- Remove stake from the staking account. The call must be made
-		/// from the coldkey account attached to the neuron metadata. Only this key
-		/// has permission to make staking and unstaking requests.
+		/// ---- Replaces the hotkey of a neuron registered by the calling coldkey. The neuron
+		/// keeps its uid, stake, bonds, weights, axon and immunity. The hotkey swap cost is
+		/// burned from the coldkey.
 		///
 		/// # Args:
 		/// 	* 'origin': (<T as frame_system::Config>Origin):
-		/// 		- The caller, a coldkey signature associated with the hotkey account.
+		/// 		- The caller, the coldkey which registered the old hotkey.
 		///
-		/// 	* 'hotkey' (T::AccountId):
-		/// 		- The hotkey account to withdraw stake from.
+		/// 	* 'old_hotkey' (T::AccountId):
+		/// 		- The registered hotkey to retire.
 		///
-		/// 	* 'ammount_unstaked' (u64):
-		/// 		- The ammount to transfer from the staking account into the balance
-		/// 		of the coldkey.
+		/// 	* 'new_hotkey' (T::AccountId):
+		/// 		- The unregistered hotkey to take its place.
 		///
 		/// # Event:
-		/// 	* 'StakeRemoved':
-		/// 		- On successful withdrawl.
+		/// 	* 'HotkeySwapped':
+		/// 		- On the neuron moving to the new hotkey.
 		///
 		/// # Raises:
 		/// 	* 'NonAssociatedColdKey':
-		/// 		- When the calling coldkey is not associated with the hotkey account.
+		/// 		- When the calling coldkey did not register the old hotkey.
 		///
-		/// 	* 'NotEnoughStaketoWithdraw':
-		/// 		- When the amount to unstake exceeds the quantity staked in the
-		/// 		associated hotkey staking account.
+		/// 	* 'AlreadyRegistered':
+		/// 		- When the new hotkey is already registered.
 		///
-		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
-		pub fn remove_stake(
+		/// 	* 'NotEnoughBalanceToSwapHotkey':
+		/// 		- When the coldkey cannot pay the hotkey swap cost.
+		///
+		#[pallet::weight((T::WeightInfo::swap_hotkey(), DispatchClass::Normal, Pays::Yes))]
+		pub fn swap_hotkey(
 			origin:OriginFor<T>, 
-			hotkey: T::AccountId, 
-			ammount_unstaked: u64
+			old_hotkey: T::AccountId, 
+			new_hotkey: T::AccountId
 		) -> DispatchResult {
-			Self::do_remove_stake(origin, hotkey, ammount_unstaked)
+			Self::do_swap_hotkey(origin, old_hotkey, new_hotkey)
+		}
+
+		/// ---- Announces that every neuron registered by the calling coldkey moves to a new
+		/// coldkey after the coldkey swap delay. The swap executes at the start of that block
+		/// unless the calling coldkey cancels it first. A swap which would move stake below the
+		/// existential deposit onto a new coldkey that does not exist is cancelled when due.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, the current coldkey.
+		///
+		/// 	* 'new_coldkey' (T::AccountId):
+		/// 		- The coldkey to move the neurons to.
+		///
+		/// # Event:
+		/// 	* 'ColdkeySwapAnnounced':
+		/// 		- On the swap being scheduled.
+		///
+		/// 	* 'ColdkeySwapped':
+		/// 		- When the swap executes.
+		///
+		/// 	* 'ColdkeySwapCancelled':
+		/// 		- When the swap is due and would burn the stake it moves.
+		///
+		/// # Raises:
+		/// 	* 'ColdkeySwapAlreadyAnnounced':
+		/// 		- When the coldkey already has a pending swap.
+		///
+		/// 	* 'NoOwnedHotkeys':
+		/// 		- When the coldkey has no registered neurons.
+		///
+		/// 	* 'ColdkeySwapPending':
+		/// 		- When the new coldkey has a pending swap, or pending swaps move neurons onto the coldkey.
+		///
+		/// 	* 'TooManyColdkeySwapsThisBlock':
+		/// 		- When the block the swap would execute at is full.
+		///
+		/// 	* 'TooManyColdkeySwapHotkeysThisBlock':
+		/// 		- When the swaps at that block would move too many hotkeys.
+		///
+		#[pallet::weight((T::WeightInfo::announce_coldkey_swap(), DispatchClass::Normal, Pays::Yes))]
+		pub fn announce_coldkey_swap(
+			origin:OriginFor<T>, 
+			new_coldkey: T::AccountId
+		) -> DispatchResult {
+			Self::do_announce_coldkey_swap(origin, new_coldkey)
+		}
+
+		/// ---- Cancels the pending coldkey swap of the calling coldkey.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, the coldkey which announced the swap.
+		///
+		/// # Event:
+		/// 	* 'ColdkeySwapCancelled':
+		/// 		- On the swap being cancelled.
+		///
+		/// # Raises:
+		/// 	* 'NoColdkeySwapAnnounced':
+		/// 		- When the coldkey has no pending swap.
+		///
+		#[pallet::weight((T::WeightInfo::cancel_coldkey_swap(), DispatchClass::Normal, Pays::Yes))]
+		pub fn cancel_coldkey_swap(
+			origin:OriginFor<T>
+		) -> DispatchResult {
+			Self::do_cancel_coldkey_swap(origin)
 		}
 
 		/// ---- Serves or updates axon information for the neuron associated with the caller. If the caller
@@ -1132,7 +2575,7 @@ pub mod pallet {
 		/// 	* 'AxonServed':
 		/// 		- On subscription of a new neuron to the active set.
 		///
-		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
+		#[pallet::weight((T::WeightInfo::serve_axon(), DispatchClass::Normal, Pays::No))]
 		pub fn serve_axon (
 			origin:OriginFor<T>, 
 			version: u32, 
@@ -1157,7 +2600,8 @@ pub mod pallet {
 		/// 		- Hashing nonce as a u64.
 		///
 		/// 	* 'work' (Vec<u8>):
-		/// 		- Work hash as list of bytes.
+		/// 		- Work hash as list of bytes, a seal of version SEAL_VERSION over the hotkey.
+		/// 		  Seals of version 1 are accepted before the LegacySealCutoff block.
 		/// 
 		/// 	* 'hotkey' (T::AccountId,):
 		/// 		- Hotkey to register.
@@ -1169,7 +2613,17 @@ pub mod pallet {
 		/// 	* 'NeuronRegistered':
 		/// 		- On subscription of a new neuron to the active set.
 		///
-		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
+		/// # Raises:
+		/// 	* 'InvalidSeal':
+		/// 		- When the work is not the seal of the block number, nonce and hotkey.
+		///
+		/// 	* 'LegacySealExpired':
+		/// 		- When the work is a version 1 seal and the legacy seal cutoff block has been reached.
+		///
+		/// 	* 'ColdkeySwapPending':
+		/// 		- When the coldkey has a pending coldkey swap.
+		///
+		#[pallet::weight((T::WeightInfo::register(Pallet::<T>::get_neuron_count()), DispatchClass::Normal, Pays::No))]
 		pub fn register( 
 				origin:OriginFor<T>, 
 				block_number: u64, 
@@ -1180,6 +2634,41 @@ pub mod pallet {
 		) -> DispatchResult {
 			Self::do_registration(origin, block_number, nonce, work, hotkey, coldkey)
 		}
+
+		/// ---- Registers a new neuron to the graph without proof of work. The current burn is
+		/// taken from the calling coldkey and destroyed.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, the coldkey which pays the burn and owns the neuron.
+		/// 
+		/// 	* 'hotkey' (T::AccountId,):
+		/// 		- Hotkey to register.
+		///
+		/// # Event:
+		/// 	* 'NeuronRegistered':
+		/// 		- On subscription of a new neuron to the active set.
+		///
+		/// # Raises:
+		/// 	* 'ToManyRegistrationsThisBlock':
+		/// 		- When the block already holds max registrations per block, PoW and burned alike.
+		///
+		/// 	* 'AlreadyRegistered':
+		/// 		- When the hotkey is already registered.
+		///
+		/// 	* 'NotEnoughBalanceToRegister':
+		/// 		- When the coldkey cannot pay the burn.
+		///
+		/// 	* 'ColdkeySwapPending':
+		/// 		- When the coldkey has a pending coldkey swap.
+		///
+		#[pallet::weight((T::WeightInfo::burned_register(Pallet::<T>::get_neuron_count()), DispatchClass::Normal, Pays::Yes))]
+		pub fn burned_register( 
+				origin:OriginFor<T>, 
+				hotkey: T::AccountId
+		) -> DispatchResult {
+			Self::do_burned_registration(origin, hotkey)
+		}
 		/// ---- SUDO ONLY FUNCTIONS
 		///
 		/// # Args:
@@ -1198,7 +2687,7 @@ pub mod pallet {
 		///		* 'TargetRegistrationsPerIntervalSet'
 		///
 		/// 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_blocks_per_step(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_blocks_per_step ( 
 			origin:OriginFor<T>, 
 			blocks_per_step: u64 
@@ -1209,7 +2698,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_bonds_moving_average(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_bonds_moving_average ( 
 			origin:OriginFor<T>, 
 			bonds_moving_average: u64 
@@ -1220,18 +2709,19 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_difficulty(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_difficulty ( 
 			origin:OriginFor<T>, 
 			difficulty: u64 
 		) -> DispatchResult {
 			ensure_root( origin )?;
+			ensure!( difficulty >= Self::get_min_difficulty() && difficulty <= Self::get_max_difficulty(), Error::<T>::StorageValueOutOfRange ); // Within the difficulty bounds.
 			Difficulty::<T>::set( difficulty );
 			Self::deposit_event( Event::DifficultySet( difficulty ) );
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_adjustment_interval(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_adjustment_interval ( 
 			origin:OriginFor<T>, 
 			adjustment_interval: u64 
@@ -1242,7 +2732,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_activity_cutoff(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_activity_cutoff ( 
 			origin:OriginFor<T>, 
 			activity_cutoff: u64 
@@ -1253,7 +2743,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_target_registrations_per_interval(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_target_registrations_per_interval ( 
 			origin:OriginFor<T>, 
 			target_registrations_per_interval: u64 
@@ -1264,7 +2754,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_rho(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_rho ( 
 			origin:OriginFor<T>, 
 			rho: u64 
@@ -1275,7 +2765,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_kappa(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_kappa ( 
 			origin:OriginFor<T>, 
 			kappa: u64 
@@ -1286,7 +2776,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_max_allowed_uids(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_max_allowed_uids ( 
 			origin:OriginFor<T>, 
 			max_allowed_uids: u64 
@@ -1297,7 +2787,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_min_allowed_weights(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_min_allowed_weights ( 
 			origin:OriginFor<T>, 
 			min_allowed_weights: u64 
@@ -1308,7 +2798,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_max_allowed_max_min_ratio(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_max_allowed_max_min_ratio ( 
 			origin:OriginFor<T>, 
 			max_allowed_max_min_ratio: u64 
@@ -1319,7 +2809,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_max_weight_limit(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_max_weight_limit ( 
 			origin:OriginFor<T>, 
 			max_weight_limit: u32 
@@ -1330,7 +2820,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_validator_batch_size(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_validator_batch_size ( 
 			origin:OriginFor<T>, 
 			validator_batch_size: u64 
@@ -1341,7 +2831,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_validator_sequence_length(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_validator_sequence_length ( 
 			origin:OriginFor<T>, 
 			validator_sequence_length: u64 
@@ -1352,7 +2842,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_validator_epoch_len(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_validator_epoch_len ( 
 			origin:OriginFor<T>, 
 			validator_epoch_len : u64 
@@ -1363,7 +2853,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_validator_epochs_per_reset(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_validator_epochs_per_reset ( 
 			origin:OriginFor<T>, 
 			validator_epochs_per_reset : u64 
@@ -1374,7 +2864,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_incentive_pruning_denominator(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_incentive_pruning_denominator( 
 			origin:OriginFor<T>, 
 			incentive_pruning_denominator: u64 
@@ -1385,7 +2875,7 @@ pub mod pallet {
 			Ok(())
 		}
 		
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_stake_pruning_denominator(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_stake_pruning_denominator( 
 			origin:OriginFor<T>, 
 			stake_pruning_denominator: u64 
@@ -1396,7 +2886,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_stake_pruning_min(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_stake_pruning_min( 
 			origin:OriginFor<T>, 
 			stake_pruning_min: u64 
@@ -1407,7 +2897,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_immunity_period(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_immunity_period ( 
 			origin:OriginFor<T>, 
 			immunity_period: u64 
@@ -1418,7 +2908,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_reset_bonds(Pallet::<T>::get_neuron_count()), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_reset_bonds ( 
 			origin:OriginFor<T>
 		) -> DispatchResult {
@@ -1428,7 +2918,17 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_repair_totals(Pallet::<T>::get_neuron_count()), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_repair_totals ( 
+			origin:OriginFor<T>
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			let ( total_stake, total_issuance ) = Self::repair_totals();
+			Self::deposit_event( Event::TotalsRepaired( total_stake, total_issuance ) );
+			Ok(())
+		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_scaling_law_power(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_scaling_law_power( 
 			origin:OriginFor<T>, 
 			scaling_law_power: u8 
@@ -1440,7 +2940,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_synergy_scaling_law_power(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_synergy_scaling_law_power( 
 			origin:OriginFor<T>, 
 			synergy_scaling_law_power: u8 
@@ -1452,7 +2952,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_validator_exclude_quantile(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_validator_exclude_quantile( 
 			origin:OriginFor<T>, 
 			validator_exclude_quantile: u8 
@@ -1463,6 +2963,145 @@ pub mod pallet {
 			Self::deposit_event( Event::ValidatorExcludeQuantileSet( validator_exclude_quantile ));
 			Ok(())
 		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_used_work_retention(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_used_work_retention( 
+			origin:OriginFor<T>, 
+			used_work_retention: u64 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			ensure!( used_work_retention >= SEAL_VALIDITY_BLOCKS, Error::<T>::StorageValueOutOfRange ); // Seals must be remembered for as long as they are valid.
+		    UsedWorkRetention::<T>::set( used_work_retention );
+			Self::deposit_event( Event::UsedWorkRetentionSet( used_work_retention ));
+			Ok(())
+		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_unbonding_period(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_unbonding_period( 
+			origin:OriginFor<T>, 
+			unbonding_period: u64 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+		    UnbondingPeriod::<T>::set( unbonding_period );
+			Self::deposit_event( Event::UnbondingPeriodSet( unbonding_period ));
+			Ok(())
+		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_hotkey_swap_cost(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_hotkey_swap_cost( 
+			origin:OriginFor<T>, 
+			hotkey_swap_cost: BalanceOf<T> 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+		    HotkeySwapCost::<T>::set( hotkey_swap_cost );
+			Self::deposit_event( Event::HotkeySwapCostSet( hotkey_swap_cost ));
+			Ok(())
+		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_coldkey_swap_delay(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_coldkey_swap_delay( 
+			origin:OriginFor<T>, 
+			coldkey_swap_delay: u64 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			ensure!( coldkey_swap_delay > 0, Error::<T>::StorageValueOutOfRange ); // The owner needs at least a block to cancel in.
+		    ColdkeySwapDelay::<T>::set( coldkey_swap_delay );
+			Self::deposit_event( Event::ColdkeySwapDelaySet( coldkey_swap_delay ));
+			Ok(())
+		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_max_take(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_max_take( 
+			origin:OriginFor<T>, 
+			max_take: u16 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+		    MaxTake::<T>::set( max_take );
+			Self::deposit_event( Event::MaxTakeSet( max_take ));
+			Ok(())
+		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_take_rate_limit(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_take_rate_limit( 
+			origin:OriginFor<T>, 
+			take_rate_limit: u64 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+		    TakeRateLimit::<T>::set( take_rate_limit );
+			Self::deposit_event( Event::TakeRateLimitSet( take_rate_limit ));
+			Ok(())
+		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_burn(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_burn( 
+			origin:OriginFor<T>, 
+			burn: BalanceOf<T> 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			ensure!( burn >= Self::get_min_burn() && burn <= Self::get_max_burn(), Error::<T>::StorageValueOutOfRange ); // Within the burn bounds.
+		    Burn::<T>::set( burn );
+			Self::deposit_event( Event::BurnSet( burn ));
+			Ok(())
+		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_legacy_seal_cutoff(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_legacy_seal_cutoff( 
+			origin:OriginFor<T>, 
+			legacy_seal_cutoff: u64 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+		    LegacySealCutoff::<T>::set( legacy_seal_cutoff );
+			Self::deposit_event( Event::LegacySealCutoffSet( legacy_seal_cutoff ));
+			Ok(())
+		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_min_difficulty(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_min_difficulty( 
+			origin:OriginFor<T>, 
+			min_difficulty: u64 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			ensure!( min_difficulty <= Self::get_max_difficulty(), Error::<T>::StorageValueOutOfRange ); // The bounds must not cross.
+		    MinDifficulty::<T>::set( min_difficulty );
+			Self::deposit_event( Event::MinDifficultySet( min_difficulty ));
+			Ok(())
+		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_max_difficulty(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_max_difficulty( 
+			origin:OriginFor<T>, 
+			max_difficulty: u64 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			ensure!( max_difficulty >= Self::get_min_difficulty(), Error::<T>::StorageValueOutOfRange ); // The bounds must not cross.
+		    MaxDifficulty::<T>::set( max_difficulty );
+			Self::deposit_event( Event::MaxDifficultySet( max_difficulty ));
+			Ok(())
+		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_min_burn(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_min_burn( 
+			origin:OriginFor<T>, 
+			min_burn: BalanceOf<T> 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			ensure!( min_burn <= Self::get_max_burn(), Error::<T>::StorageValueOutOfRange ); // The bounds must not cross.
+		    MinBurn::<T>::set( min_burn );
+			Self::deposit_event( Event::MinBurnSet( min_burn ));
+			Ok(())
+		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_max_burn(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_max_burn( 
+			origin:OriginFor<T>, 
+			max_burn: BalanceOf<T> 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			ensure!( max_burn >= Self::get_min_burn(), Error::<T>::StorageValueOutOfRange ); // The bounds must not cross.
+		    MaxBurn::<T>::set( max_burn );
+			Self::deposit_event( Event::MaxBurnSet( max_burn ));
+			Ok(())
+		}
 	}
 
 	// ---- Subtensor helper functions.
@@ -1531,12 +3170,50 @@ pub mod pallet {
 			MaxRegistrationsPerBlock::<T>::put( max_registrations );
 		}
 		// -- Minimum difficulty
-		pub fn get_minimum_difficulty( ) -> u64 {
-			return T::MinimumDifficulty::get();
+		pub fn get_min_difficulty( ) -> u64 {
+			return MinDifficulty::<T>::get();
+		}
+		pub fn set_min_difficulty( min_difficulty: u64 ) {
+			MinDifficulty::<T>::put( min_difficulty );
 		}
 		// -- Maximum difficulty
-		pub fn get_maximum_difficulty( ) -> u64 {
-			return T::MaximumDifficulty::get();
+		pub fn get_max_difficulty( ) -> u64 {
+			return MaxDifficulty::<T>::get();
+		}
+		pub fn set_max_difficulty( max_difficulty: u64 ) {
+			MaxDifficulty::<T>::put( max_difficulty );
+		}
+		// -- Difficulty adjustment history.
+		pub fn get_difficulty_history( ) -> Vec<DifficultyAdjustment> {
+			return DifficultyHistory::<T>::get();
+		}
+		// -- Registration burn.
+		pub fn get_burn( ) -> BalanceOf<T> {
+			return Burn::<T>::get();
+		}
+		pub fn set_burn( burn: BalanceOf<T> ) {
+			Burn::<T>::put( burn );
+		}
+		// -- Minimum burn
+		pub fn get_min_burn( ) -> BalanceOf<T> {
+			return MinBurn::<T>::get();
+		}
+		pub fn set_min_burn( min_burn: BalanceOf<T> ) {
+			MinBurn::<T>::put( min_burn );
+		}
+		// -- Maximum burn
+		pub fn get_max_burn( ) -> BalanceOf<T> {
+			return MaxBurn::<T>::get();
+		}
+		pub fn set_max_burn( max_burn: BalanceOf<T> ) {
+			MaxBurn::<T>::put( max_burn );
+		}
+		// -- Legacy seal cutoff block.
+		pub fn get_legacy_seal_cutoff( ) -> u64 {
+			return LegacySealCutoff::<T>::get();
+		}
+		pub fn set_legacy_seal_cutoff( legacy_seal_cutoff: u64 ) {
+			LegacySealCutoff::<T>::put( legacy_seal_cutoff );
 		}
 		// -- Get Block emission.
 		pub fn get_block_emission( ) -> u64 {
@@ -1613,6 +3290,42 @@ pub mod pallet {
 		pub fn set_validator_exclude_quantile( validator_exclude_quantile: u8 ) {
 			ValidatorExcludeQuantile::<T>::put( validator_exclude_quantile );
 		}
+		pub fn get_used_work_retention( ) -> u64 {
+			return UsedWorkRetention::<T>::get();
+		}
+		pub fn set_used_work_retention( used_work_retention: u64 ) {
+			UsedWorkRetention::<T>::put( used_work_retention );
+		}
+		pub fn get_unbonding_period( ) -> u64 {
+			return UnbondingPeriod::<T>::get();
+		}
+		pub fn set_unbonding_period( unbonding_period: u64 ) {
+			UnbondingPeriod::<T>::put( unbonding_period );
+		}
+		pub fn get_hotkey_swap_cost( ) -> BalanceOf<T> {
+			return HotkeySwapCost::<T>::get();
+		}
+		pub fn set_hotkey_swap_cost( hotkey_swap_cost: BalanceOf<T> ) {
+			HotkeySwapCost::<T>::put( hotkey_swap_cost );
+		}
+		pub fn get_coldkey_swap_delay( ) -> u64 {
+			return ColdkeySwapDelay::<T>::get();
+		}
+		pub fn set_coldkey_swap_delay( coldkey_swap_delay: u64 ) {
+			ColdkeySwapDelay::<T>::put( coldkey_swap_delay );
+		}
+		pub fn get_max_take( ) -> u16 {
+			return MaxTake::<T>::get();
+		}
+		pub fn set_max_take( max_take: u16 ) {
+			MaxTake::<T>::put( max_take );
+		}
+		pub fn get_take_rate_limit( ) -> u64 {
+			return TakeRateLimit::<T>::get();
+		}
+		pub fn set_take_rate_limit( take_rate_limit: u64 ) {
+			TakeRateLimit::<T>::put( take_rate_limit );
+		}
 
 		// -- Get step consensus shift (1/kappa)
 		pub fn get_kappa( ) -> u64 {
@@ -1666,75 +3379,75 @@ pub mod pallet {
 		pub fn get_registrations_this_block( ) -> u64 {
 			RegistrationsThisBlock::<T>::get()
 		}
-		pub fn get_total_stake( ) -> u64 {
+		pub fn get_total_stake( ) -> BalanceOf<T> {
 			return TotalStake::<T>::get();
 		}
-		pub fn get_total_issuance( ) -> u64 {
+		pub fn get_total_issuance( ) -> BalanceOf<T> {
 			return TotalIssuance::<T>::get();
 		}
-		pub fn get_initial_total_issuance( ) -> u64 {
-			return T::InitialIssuance::get();
+		pub fn get_initial_total_issuance( ) -> BalanceOf<T> {
+			return T::InitialIssuance::get().saturated_into();
 		}
 		pub fn get_lastupdate( ) -> Vec<u64> {
 			let mut result: Vec<u64> = vec![ 0; Self::get_neuron_count() as usize ];
-			for ( uid_i, neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
-				result[ uid_i as usize ] = neuron_i.last_update;
+			for ( uid_i, last_update_i ) in <LastUpdate<T> as IterableStorageMap<u32, u64>>::iter() {
+				result[ uid_i as usize ] = last_update_i;
 			}
 			return result
 		}
-		pub fn get_stake( ) -> Vec<u64> {
-			let mut result: Vec<u64> = vec![ 0; Self::get_neuron_count() as usize ];
-			for ( uid_i, neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
-				result[ uid_i as usize ] = neuron_i.stake;
+		pub fn get_stake( ) -> Vec<BalanceOf<T>> {
+			let mut result: Vec<BalanceOf<T>> = vec![ Zero::zero(); Self::get_neuron_count() as usize ];
+			for ( uid_i, stake_i ) in <Stake<T> as IterableStorageMap<u32, BalanceOf<T>>>::iter() {
+				result[ uid_i as usize ] = stake_i;
 			}
 			return result
 		}
 		pub fn get_ranks( ) -> Vec<u64> {
 			let mut result: Vec<u64> = vec![ 0; Self::get_neuron_count() as usize ];
-			for ( uid_i, neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
-				result[ uid_i as usize ] = neuron_i.rank;
+			for ( uid_i, rank_i ) in <Rank<T> as IterableStorageMap<u32, u64>>::iter() {
+				result[ uid_i as usize ] = rank_i;
 			}
 			return result
 		}
 		pub fn get_trust( ) -> Vec<u64> {
 			let mut result: Vec<u64> = vec![ 0; Self::get_neuron_count() as usize ];
-			for ( uid_i, neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
-				result[ uid_i as usize ] = neuron_i.trust;
+			for ( uid_i, trust_i ) in <Trust<T> as IterableStorageMap<u32, u64>>::iter() {
+				result[ uid_i as usize ] = trust_i;
 			}
 			return result
 		}
 		pub fn get_consensus( ) -> Vec<u64> {
 			let mut result: Vec<u64> = vec![ 0; Self::get_neuron_count() as usize ];
-			for ( uid_i, neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
-				result[ uid_i as usize ] = neuron_i.consensus;
+			for ( uid_i, consensus_i ) in <Consensus<T> as IterableStorageMap<u32, u64>>::iter() {
+				result[ uid_i as usize ] = consensus_i;
 			}
 			return result
 		}
 		pub fn get_incentive( ) -> Vec<u64> {
 			let mut result: Vec<u64> = vec![ 0; Self::get_neuron_count() as usize ];
-			for ( uid_i, neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
-				result[ uid_i as usize ] = neuron_i.incentive;
+			for ( uid_i, incentive_i ) in <Incentive<T> as IterableStorageMap<u32, u64>>::iter() {
+				result[ uid_i as usize ] = incentive_i;
 			}
 			return result
 		}
 		pub fn get_dividends( ) -> Vec<u64> {
 			let mut result: Vec<u64> = vec![ 0; Self::get_neuron_count() as usize ];
-			for ( uid_i, neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
-				result[ uid_i as usize] = neuron_i.dividends;
+			for ( uid_i, dividends_i ) in <Dividends<T> as IterableStorageMap<u32, u64>>::iter() {
+				result[ uid_i as usize] = dividends_i;
 			}
 			return result
 		}
 		pub fn get_emission( ) -> Vec<u64> {
 			let mut result: Vec<u64> = vec![ 0; Self::get_neuron_count() as usize ];
-			for ( uid_i, neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
-				result[ uid_i as usize ] = neuron_i.emission;
+			for ( uid_i, emission_i ) in <Emission<T> as IterableStorageMap<u32, u64>>::iter() {
+				result[ uid_i as usize ] = emission_i;
 			}
 			return result
 		}
 		pub fn get_active( ) -> Vec<u32> {
 			let mut result: Vec<u32> = vec![ 0; Self::get_neuron_count() as usize ];
-			for ( uid_i, neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
-				result[ uid_i as usize] = neuron_i.active;
+			for ( uid_i, active_i ) in <Active<T> as IterableStorageMap<u32, u32>>::iter() {
+				result[ uid_i as usize] = active_i;
 			}
 			return result
 		}
@@ -1746,12 +3459,35 @@ pub mod pallet {
 			return bonds
 		}
 		pub fn get_bonds( ) -> Vec<Vec<u64>>  {
-			let mut bonds: Vec<Vec<u64>> = vec![ vec![]; Self::get_neuron_count() as usize ];
-			for ( uid_i, neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
-				bonds[ uid_i as usize ] = Self::get_bonds_for_neuron( &neuron_i );
+			let n: usize = Self::get_neuron_count() as usize;
+			let mut bonds: Vec<Vec<u64>> = vec![ vec![]; n ];
+			for uid_i in Keys::<T>::iter_keys() {
+				let mut bonds_i: Vec<u64> = vec![ 0; n ];
+				for (uid_j, bonds_ij) in Bonds::<T>::iter_prefix( uid_i ) {
+					bonds_i[ uid_j as usize ] = bonds_ij;
+				}
+				bonds[ uid_i as usize ] = bonds_i;
 			}
 			return bonds
 		}
+		// --- Returns the bonds held by uid_i in uid_j.
+		pub fn get_bond( uid_i: u32, uid_j: u32 ) -> u64 {
+			return Bonds::<T>::get( uid_i, uid_j );
+		}
+		// --- Returns the non zero bonds held by uid ordered by the uid they are held in.
+		pub fn get_sparse_bonds_for_uid( uid: u32 ) -> Vec<(u32, u64)> {
+			let mut bonds: Vec<(u32, u64)> = Bonds::<T>::iter_prefix( uid ).collect();
+			bonds.sort_by_key( |(uid_j, _)| *uid_j );
+			return bonds
+		}
+		// --- Removes every bond held by or held in uid. Called when uid is
+		// handed to a new neuron so no bonds carry over to it.
+		pub fn clear_bonds_for_uid( uid: u32 ) {
+			Bonds::<T>::remove_prefix( uid, None );
+			for uid_i in Keys::<T>::iter_keys() {
+				Bonds::<T>::remove( uid_i, uid );
+			}
+		}
 		pub fn get_weights_for_neuron( neuron: &NeuronMetadataOf<T> ) -> Vec<u32>  {
 			let mut weights: Vec<u32> = vec![ 0; Self::get_neuron_count() as usize ];
 			for (uid_j, weights_ij) in neuron.weights.iter(){
@@ -1760,29 +3496,30 @@ pub mod pallet {
 			return weights
 		}
 		pub fn get_weights( ) -> Vec<Vec<u32>>  {
-			let mut weights: Vec<Vec<u32>> = vec![ vec![]; Self::get_neuron_count() as usize ];
-			for ( uid_i, neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
-				weights[ uid_i as usize ] = Self::get_weights_for_neuron( &neuron_i );
+			let n: usize = Self::get_neuron_count() as usize;
+			let mut weights: Vec<Vec<u32>> = vec![ vec![]; n ];
+			for uid_i in Keys::<T>::iter_keys() {
+				let mut weights_i: Vec<u32> = vec![ 0; n ];
+				for (uid_j, weights_ij) in Weights::<T>::get( uid_i ).iter() {
+					weights_i[ *uid_j as usize ] = *weights_ij;
+				}
+				weights[ uid_i as usize ] = weights_i;
 			}
 			return weights
 		}		
 
 		// Setters
-		pub fn set_stake_from_vector( stake: Vec<u64> ) {
-			let mut total_stake: u64 = 0;
+		pub fn set_stake_from_vector( stake: Vec<BalanceOf<T>> ) {
+			let mut total_stake: BalanceOf<T> = Zero::zero();
 			for uid_i in 0..Self::get_neuron_count() {
-				let mut neuron = Neurons::<T>::get(uid_i).unwrap();
-				neuron.stake = stake[ uid_i as usize ];
-				Neurons::<T>::insert( uid_i, neuron );
-				total_stake += stake[ uid_i as usize ];
+				Stake::<T>::insert( uid_i, stake[ uid_i as usize ] );
+				total_stake = total_stake.saturating_add( stake[ uid_i as usize ] );
 			}
 			TotalStake::<T>::set( total_stake );
 		}
 		pub fn set_last_update_from_vector( last_update: Vec<u64> ) {
 			for uid_i in 0..Self::get_neuron_count() {
-				let mut neuron = Neurons::<T>::get(uid_i).unwrap();
-				neuron.last_update = last_update[ uid_i as usize ];
-				Neurons::<T>::insert( uid_i, neuron );
+				LastUpdate::<T>::insert( uid_i, last_update[ uid_i as usize ] );
 			}
 		}
 		pub fn set_weights_from_matrix( weights: Vec<Vec<u32>> ) {
@@ -1794,33 +3531,24 @@ pub mod pallet {
 						sparse_weights.push( (uid_j, weight_ij) );
 					}
 				}
-				let mut neuron = Neurons::<T>::get(uid_i).unwrap();
-				neuron.weights = sparse_weights;
-				Neurons::<T>::insert( uid_i, neuron );
+				Weights::<T>::insert( uid_i, sparse_weights );
 			}
 		}
 
 		pub fn set_bonds_from_matrix( bonds: Vec<Vec<u64>> ) {
 			for uid_i in 0..Self::get_neuron_count() {
-				let mut sparse_bonds: Vec<(u32, u64)> = vec![];
 				for uid_j in 0..Self::get_neuron_count() {
 					let bond_ij: u64 = bonds[uid_i as usize][uid_j as usize];
-					sparse_bonds.push( (uid_j, bond_ij) );
+					if bond_ij != 0 {
+						Bonds::<T>::insert( uid_i, uid_j, bond_ij );
+					} else {
+						Bonds::<T>::remove( uid_i, uid_j );
+					}
 				}
-				let mut neuron = Neurons::<T>::get(uid_i).unwrap();
-				neuron.bonds = sparse_bonds;
-				Neurons::<T>::insert( uid_i, neuron );
 			}
 		}
 	
 		// Helpers.
-		// --- Returns Option if the u64 converts to a balance
-		// use .unwarp if the result returns .some().
-		pub fn u64_to_balance(input: u64) -> Option<<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance>
-		{
-			input.try_into().ok()
-		}
-
 		// --- Returns true if the account-id has an active
 		// account on chain.
 		pub fn add_hotkey_to_active_set(hotkey_id: &T::AccountId, uid: u32) {
@@ -1839,16 +3567,11 @@ pub mod pallet {
 			return !Self::is_hotkey_active(hotkey_id);
 		}
 
-		// --- Returns true if the uid is to be prunned at the next epoch.
-		pub fn will_be_prunned ( uid:u32 ) -> bool {
-			return NeuronsToPruneAtNextEpoch::<T>::contains_key( uid );
-		}
-
 		// --- Returns true if the uid is active, i.e. there
 		// is a staking, last_update, and neuron account associated
 		// with this uid.
 		pub fn is_uid_active(uid: u32) -> bool {
-			return Neurons::<T>::contains_key(uid);
+			return Keys::<T>::contains_key(uid);
 		}
 
 		// --- Returns hotkey associated with the hotkey account.
@@ -1857,8 +3580,41 @@ pub mod pallet {
 		pub fn get_uid_for_hotkey(hotkey_id: &T::AccountId) -> u32{
 			return Hotkeys::<T>::get(&hotkey_id);
 		}
+
+		// --- Returns the coldkey which registered the uid.
+		// This should be called in conjunction with is_uid_active
+		// to ensure this function does not throw an error.
+		pub fn get_coldkey_for_uid( uid: u32 ) -> T::AccountId {
+			return Coldkeys::<T>::get( uid ).unwrap();
+		}
+
+		// --- Assembles the full neuron view for the uid from the per uid maps.
+		// This should be called in conjunction with is_uid_active
+		// to ensure this function does not throw an error.
 		pub fn get_neuron_for_uid ( uid: u32 ) -> NeuronMetadataOf<T> {
-			return Neurons::<T>::get( uid ).unwrap();
+			let axon: AxonInfo = Axons::<T>::get( uid );
+			NeuronMetadataOf::<T> {
+				version: axon.version,
+				ip: axon.ip,
+				port: axon.port,
+				ip_type: axon.ip_type,
+				uid,
+				modality: axon.modality,
+				hotkey: Keys::<T>::get( uid ).unwrap(),
+				coldkey: Coldkeys::<T>::get( uid ).unwrap(),
+				active: Active::<T>::get( uid ),
+				last_update: LastUpdate::<T>::get( uid ),
+				priority: Priority::<T>::get( uid ),
+				stake: Stake::<T>::get( uid ),
+				rank: Rank::<T>::get( uid ),
+				trust: Trust::<T>::get( uid ),
+				consensus: Consensus::<T>::get( uid ),
+				incentive: Incentive::<T>::get( uid ),
+				dividends: Dividends::<T>::get( uid ),
+				emission: Emission::<T>::get( uid ),
+				bonds: Self::get_sparse_bonds_for_uid( uid ),
+				weights: Weights::<T>::get( uid ),
+			}
 		}
 
 		// --- Returns the neuron associated with the passed hotkey.
@@ -1893,7 +3649,6 @@ pub mod pallet {
 		pub fn get_priority_set_weights( hotkey: &T::AccountId, len: u64 ) -> u64 {
 			if Hotkeys::<T>::contains_key( hotkey ) {
 				let uid = Hotkeys::<T>::get( hotkey );
-				let neuron = Neurons::<T>::get( uid ).unwrap();
 				// Multiply here by 1_000_000 since len may divide all log values to zero.
 				// a peer with 1 tao will have priority 29 000 000 000 after 1 epoch.
 				// with 10 tao 33 000 000 000
@@ -1901,7 +3656,7 @@ pub mod pallet {
 				// with 1000 tao 39 000 000 000
 				// with 10000 tao 43 000 000 000
 				// division by len will always return a non zero value with which to differentiate. 
-				return neuron.priority * 1_000_000 / len;
+				return Priority::<T>::get( uid ) * 1_000_000 / len;
 			} else{
 				return 0;
 			}
@@ -2040,7 +3795,7 @@ impl<T: Config + Send + Sync> SignedExtension for SubtensorSignedExtension<T>
 				let transaction_fee = 0;
                 Ok((CallType::SetWeights, transaction_fee, who.clone())) 
             }
-			Some(Call::register{..}) => {
+			Some(Call::register{..}) | Some(Call::burned_register{..}) => {
                 let transaction_fee = 0;
                 Ok((CallType::Register, transaction_fee, who.clone()))
             }
@@ -0,0 +1,301 @@
+use super::*;
+use frame_support::{Blake2_128Concat, Identity, IterableStorageMap, StorageHasher, StoragePrefixedMap, traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion}, weights::Weight};
+use frame_support::storage::migration::{get_storage_value, put_storage_value, storage_key_iter, remove_storage_prefix};
+
+const LOG_TARGET: &'static str = "runtime::subtensor::migrations";
+
+/// Brings storage up to STORAGE_VERSION. Each step is tagged with the version it produces and only
+/// runs on chains whose on-chain version is below it, in the order listed here. New steps go at the
+/// end together with a bump of STORAGE_VERSION.
+pub fn migrate_to_current_version<T: Config>() -> Weight {
+    let onchain: StorageVersion = Pallet::<T>::on_chain_storage_version();
+    let steps: [(u16, fn() -> Weight); 7] = [
+        // v1: NeuronMetadata split into per uid maps.
+        ( 1, migrate_to_split_neuron_storage::<T> ),
+        // v2: Bonds keyed by (uid_i, uid_j) and the prune set removed.
+        ( 2, migrate_bonds_to_double_map::<T> ),
+        ( 2, migrate_remove_neurons_to_prune::<T> ),
+        // v3: ColdkeyHotkeys index.
+        ( 3, migrate_coldkey_hotkeys_index::<T> ),
+        // v4: UsedWork indexed by block and pruned.
+        ( 4, migrate_used_work_backlog::<T> ),
+        // v5: Stake held as a reserve in the balances pallet.
+        ( 5, migrate_stake_to_reserves::<T> ),
+        // v6: Stake amounts held as a Balance instead of a u64.
+        ( 6, migrate_stake_to_balance::<T> ),
+    ];
+    let mut weight: Weight = T::DbWeight::get().reads(1);
+    for ( version, step ) in steps {
+        if onchain < version {
+            weight = weight.saturating_add( step() );
+        }
+    }
+    if onchain < STORAGE_VERSION {
+        log::info!( target: LOG_TARGET, "migrated storage from {:?} to {:?}", onchain, STORAGE_VERSION );
+        STORAGE_VERSION.put::<Pallet<T>>();
+        weight = weight.saturating_add( T::DbWeight::get().writes(1) );
+    }
+    weight
+}
+
+/// Records the values the migrations must leave untouched.
+#[cfg(feature = "try-runtime")]
+pub fn pre_upgrade<T: Config>() -> Result<(), &'static str> {
+    use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+    Pallet::<T>::set_temp_storage( N::<T>::get(), "neuron_count" );
+    let total_stake: BalanceOf<T> = if Pallet::<T>::on_chain_storage_version() < 6 {
+        let pallet_name = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+        get_storage_value::<u64>( pallet_name, b"TotalStake", &[] ).unwrap_or( 0 ).saturated_into()
+    } else {
+        TotalStake::<T>::get()
+    };
+    Pallet::<T>::set_temp_storage( total_stake, "total_stake" );
+    Ok(())
+}
+
+/// Checks the upgraded storage is at the current version, holds no data in an old layout,
+/// and still describes the same set of neurons.
+#[cfg(feature = "try-runtime")]
+pub fn post_upgrade<T: Config>() -> Result<(), &'static str> {
+    use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+    ensure!( Pallet::<T>::on_chain_storage_version() == STORAGE_VERSION, "storage version was not bumped" );
+
+    let n: u32 = N::<T>::get();
+    ensure!( Pallet::<T>::get_temp_storage::<u32>( "neuron_count" ) == Some( n ), "neuron count changed" );
+    ensure!( Pallet::<T>::get_temp_storage::<BalanceOf<T>>( "total_stake" ) == Some( TotalStake::<T>::get() ), "total stake changed" );
+    ensure!( Keys::<T>::iter_keys().count() as u32 == n, "Keys does not hold a hotkey per neuron" );
+    ensure!( Coldkeys::<T>::iter_keys().count() as u32 == n, "Coldkeys does not hold a coldkey per neuron" );
+    ensure!( Hotkeys::<T>::iter_keys().count() as u32 == n, "Hotkeys does not hold a uid per neuron" );
+    let indexed: usize = ColdkeyHotkeys::<T>::iter_values().map( |hotkeys| hotkeys.len() ).sum();
+    ensure!( indexed as u32 == n, "ColdkeyHotkeys does not index every hotkey" );
+
+    let pallet_name = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+    ensure!( storage_key_iter::<u32, NeuronMetadataV1<T::AccountId>, Identity>( pallet_name, b"Neurons" ).next().is_none(), "Neurons was not drained" );
+    ensure!( old_uids_to_prune::<T>().is_empty(), "NeuronsToPruneAtNextEpoch was not removed" );
+    ensure!( !bonds_in_row_layout::<T>(), "Bonds still holds rows" );
+    Ok(())
+}
+
+/// The neuron layout stored under `Neurons` before it was split into per uid maps.
+/// Frozen here so the migration keeps decoding the old bytes even as NeuronMetadata changes.
+#[derive(Encode, Decode)]
+struct NeuronMetadataV1<AccountId> {
+    version: u32,
+    ip: u128,
+    port: u16,
+    ip_type: u8,
+    uid: u32,
+    modality: u8,
+    hotkey: AccountId,
+    coldkey: AccountId,
+    active: u32,
+    last_update: u64,
+    priority: u64,
+    stake: u64,
+    rank: u64,
+    trust: u64,
+    consensus: u64,
+    incentive: u64,
+    dividends: u64,
+    emission: u64,
+    bonds: Vec<(u32,u64)>,
+    weights: Vec<(u32,u32)>,
+}
+
+/// The unbonding chunk layout up to storage version 5, while stake was a u64.
+#[derive(Encode, Decode)]
+struct UnlockChunkV5 {
+    amount: u64,
+    unlock_block: u64,
+}
+
+/// Moves every neuron out of the old `Neurons` map into the per uid maps.
+/// The old map is drained as we go, so this is a no-op once it has run.
+/// Stake is written as the u64 it was at this version, migrate_stake_to_balance converts it.
+pub fn migrate_to_split_neuron_storage<T: Config>() -> Weight {
+    let pallet_name = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+    let pruned: Vec<u32> = old_uids_to_prune::<T>();
+    let mut migrated: u64 = 0;
+    for ( uid, neuron ) in storage_key_iter::<u32, NeuronMetadataV1<T::AccountId>, Identity>( pallet_name, b"Neurons" ).drain() {
+        Keys::<T>::insert( uid, neuron.hotkey );
+        Coldkeys::<T>::insert( uid, neuron.coldkey );
+        put_storage_value( pallet_name, b"Stake", &uid.encode(), neuron.stake );
+        Axons::<T>::insert( uid, AxonInfo {
+            version: neuron.version,
+            ip: neuron.ip,
+            port: neuron.port,
+            ip_type: neuron.ip_type,
+            modality: neuron.modality,
+        });
+        Weights::<T>::insert( uid, neuron.weights );
+        for ( uid_j, bonds_ij ) in neuron.bonds {
+            if bonds_ij != 0 && !pruned.contains( &uid_j ) {
+                Bonds::<T>::insert( uid, uid_j, bonds_ij );
+            }
+        }
+        Active::<T>::insert( uid, neuron.active );
+        LastUpdate::<T>::insert( uid, neuron.last_update );
+        Priority::<T>::insert( uid, neuron.priority );
+        Rank::<T>::insert( uid, neuron.rank );
+        Trust::<T>::insert( uid, neuron.trust );
+        Consensus::<T>::insert( uid, neuron.consensus );
+        Incentive::<T>::insert( uid, neuron.incentive );
+        Dividends::<T>::insert( uid, neuron.dividends );
+        Emission::<T>::insert( uid, neuron.emission );
+        debug_assert!( neuron.uid == uid );
+        migrated += 1;
+    }
+    log::info!( target: LOG_TARGET, "split {:?} neurons into per uid storage", migrated );
+    // One read and removal of the old entry plus the column and bond writes per neuron.
+    T::DbWeight::get().reads_writes( migrated + pruned.len() as u64 + 1, migrated * ( 15 + migrated ) )
+}
+
+/// Returns the uids recorded in the old `NeuronsToPruneAtNextEpoch` set. Bonds held in these
+/// uids belonged to pruned neurons and would have been dropped at the next mechanism step.
+fn old_uids_to_prune<T: Config>() -> Vec<u32> {
+    let pallet_name = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+    storage_key_iter::<u32, u32, Identity>( pallet_name, b"NeuronsToPruneAtNextEpoch" ).map( |( uid, _ )| uid ).collect()
+}
+
+/// Moves bonds from one sparse row per uid into the (uid_i, uid_j) double map.
+/// Both layouts live under the `Bonds` prefix, so we tell them apart by key length:
+/// a row is keyed by a single u32 while a double map entry is keyed by two.
+pub fn migrate_bonds_to_double_map<T: Config>() -> Weight {
+    if !bonds_in_row_layout::<T>() {
+        return T::DbWeight::get().reads(1);
+    }
+
+    // Collect before writing, the new entries share the prefix we are draining.
+    let pallet_name = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+    let pruned: Vec<u32> = old_uids_to_prune::<T>();
+    let rows: Vec<(u32, Vec<(u32,u64)>)> = storage_key_iter::<u32, Vec<(u32,u64)>, Identity>( pallet_name, b"Bonds" ).drain().collect();
+    let reads: u64 = rows.len() as u64 + pruned.len() as u64 + 1;
+    let mut writes: u64 = rows.len() as u64;
+    for ( uid_i, row_i ) in rows {
+        for ( uid_j, bonds_ij ) in row_i {
+            if bonds_ij != 0 && !pruned.contains( &uid_j ) {
+                Bonds::<T>::insert( uid_i, uid_j, bonds_ij );
+                writes += 1;
+            }
+        }
+    }
+    log::info!( target: LOG_TARGET, "moved {:?} bonds into the bonds double map", writes );
+    T::DbWeight::get().reads_writes( reads, writes )
+}
+
+/// Returns true if the first key under the `Bonds` prefix is keyed by a single u32.
+fn bonds_in_row_layout<T: Config>() -> bool {
+    let prefix = <Bonds<T> as StoragePrefixedMap<u64>>::final_prefix();
+    match sp_io::storage::next_key( &prefix ) {
+        Some( key ) => key.starts_with( &prefix ) && key.len() == prefix.len() + 4,
+        None => false,
+    }
+}
+
+/// Removes the `NeuronsToPruneAtNextEpoch` set. Pruning now clears bonds at registration,
+/// and the migrations above have already dropped any bonds held in the recorded uids.
+pub fn migrate_remove_neurons_to_prune<T: Config>() -> Weight {
+    let pallet_name = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+    remove_storage_prefix( pallet_name, b"NeuronsToPruneAtNextEpoch", &[] );
+    T::DbWeight::get().writes(1)
+}
+
+/// Builds the ColdkeyHotkeys index from the neurons already registered on chain.
+/// Chains that registered neurons before the index existed have an empty index,
+/// so we only run when the index is empty and there are neurons to index.
+pub fn migrate_coldkey_hotkeys_index<T: Config>() -> Weight {
+    let mut weight: Weight = T::DbWeight::get().reads(1);
+    if ColdkeyHotkeys::<T>::iter().next().is_some() {
+        return weight;
+    }
+    let mut indexed: u64 = 0;
+    for ( uid_i, coldkey_i ) in <Coldkeys<T> as IterableStorageMap<u32, T::AccountId>>::iter() {
+        if let Some( hotkey_i ) = Keys::<T>::get( uid_i ) {
+            ColdkeyHotkeys::<T>::append( &coldkey_i, &hotkey_i );
+            indexed += 1;
+        }
+    }
+    weight = weight.saturating_add( T::DbWeight::get().reads_writes( 2 * indexed, indexed ) );
+    log::info!( target: LOG_TARGET, "indexed {:?} hotkeys by coldkey", indexed );
+    weight
+}
+
+/// Starts pruning the seals recorded in UsedWork before UsedWorkByBlock existed. Those seals
+/// are not indexed by block, so instead of removing them here in one go we point the backlog
+/// cursor at the start of UsedWork and let on_initialize work through it a chunk at a time.
+/// The block cursor starts at the current block, as no earlier block has indexed seals.
+pub fn migrate_used_work_backlog<T: Config>() -> Weight {
+    UsedWorkPruneCursor::<T>::put( Pallet::<T>::get_current_block_as_u64() );
+    if UsedWork::<T>::iter_keys().next().is_some() {
+        let prefix = <UsedWork<T> as StoragePrefixedMap<u64>>::final_prefix();
+        UsedWorkBacklogCursor::<T>::put( prefix.to_vec() );
+        log::info!( target: LOG_TARGET, "scheduled pruning of the used work backlog" );
+    }
+    T::DbWeight::get().reads_writes( 2, 2 )
+}
+
+/// Backs the stake recorded before stake was held as a reserve. Stake used to be withdrawn
+/// from the coldkey and so no longer existed in the balances pallet. We mint the owned stake
+/// of every neuron onto its coldkey, its delegated stake into the delegation pool and every
+/// unbonding chunk onto its coldkey, all held under STAKE_RESERVE_ID. TotalIssuance is then
+/// taken from the balances pallet, which from here on moves in step with it.
+/// Stake is read and TotalIssuance written as the u64 they were at this version.
+pub fn migrate_stake_to_reserves<T: Config>() -> Weight {
+    let pallet_name = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+    let pool: T::AccountId = Pallet::<T>::get_delegation_pool_account();
+    let mint = | account: &T::AccountId, amount: u64 | -> u64 { Pallet::<T>::mint_reserved_stake( account, amount.saturated_into() ).saturated_into() };
+    let mut neurons: u64 = 0;
+    let mut unbonding_coldkeys: u64 = 0;
+    let mut unbacked: u64 = 0;
+    for ( uid_i, hotkey_i ) in <Keys<T> as IterableStorageMap<u32, T::AccountId>>::iter() {
+        if let Some( coldkey_i ) = Coldkeys::<T>::get( uid_i ) {
+            let delegated: u64 = get_storage_value::<u64>( pallet_name, b"DelegatedStake", &Blake2_128Concat::hash( &hotkey_i.encode() ) ).unwrap_or( 0 );
+            let stake: u64 = get_storage_value::<u64>( pallet_name, b"Stake", &uid_i.encode() ).unwrap_or( 0 );
+            let owned: u64 = stake.saturating_sub( delegated );
+            unbacked += owned - mint( &coldkey_i, owned );
+            unbacked += delegated - mint( &pool, delegated );
+            neurons += 1;
+        }
+    }
+    for ( coldkey_i, chunks_i ) in storage_key_iter::<T::AccountId, Vec<UnlockChunkV5>, Blake2_128Concat>( pallet_name, b"Unbonding" ) {
+        let unbonding: u64 = chunks_i.iter().fold( 0u64, |total, chunk| total.saturating_add( chunk.amount ) );
+        unbacked += unbonding - mint( &coldkey_i, unbonding );
+        unbonding_coldkeys += 1;
+    }
+    let issuance: u64 = T::Currency::total_issuance().saturated_into();
+    put_storage_value( pallet_name, b"TotalIssuance", &[], issuance );
+    log::info!( target: LOG_TARGET, "held the stake of {:?} neurons and {:?} unbonding coldkeys as reserves, {:?} could not be minted", neurons, unbonding_coldkeys, unbacked );
+    // Per neuron the stake entries plus the coldkey and pool accounts and reserves, per unbonding coldkey its account and reserves.
+    T::DbWeight::get().reads_writes( 8 * neurons + 3 * unbonding_coldkeys + 2, 4 * neurons + 2 * unbonding_coldkeys + 2 )
+}
+
+/// Re-encodes every stake amount recorded as a u64 as a Balance: the stake of every neuron, the
+/// delegated stake and nominator shares of every delegate, every unbonding chunk, TotalStake,
+/// TotalIssuance, the registration Burn and the HotkeySwapCost. Amounts keep their value, only
+/// the encoding widens.
+pub fn migrate_stake_to_balance<T: Config>() -> Weight {
+    let widen = | amount: u64 | -> BalanceOf<T> { amount.saturated_into() };
+    let mut translated: u64 = 0;
+    Stake::<T>::translate::<u64, _>( |_, stake| { translated += 1; Some( widen( stake ) ) } );
+    DelegatedStake::<T>::translate::<u64, _>( |_, delegated| { translated += 1; Some( widen( delegated ) ) } );
+    TotalNominatorShares::<T>::translate::<u64, _>( |_, shares| { translated += 1; Some( widen( shares ) ) } );
+    NominatorShares::<T>::translate::<u64, _>( |_, _, shares| { translated += 1; Some( widen( shares ) ) } );
+    Unbonding::<T>::translate::<Vec<UnlockChunkV5>, _>( |_, chunks| {
+        translated += 1;
+        Some( chunks.into_iter().map( |chunk| UnlockChunk { amount: widen( chunk.amount ), unlock_block: chunk.unlock_block } ).collect() )
+    });
+    if TotalStake::<T>::translate::<u64, _>( |stake| stake.map( widen ) ).is_err() {
+        log::error!( target: LOG_TARGET, "TotalStake could not be decoded as a u64" );
+    }
+    if TotalIssuance::<T>::translate::<u64, _>( |issuance| issuance.map( widen ) ).is_err() {
+        log::error!( target: LOG_TARGET, "TotalIssuance could not be decoded as a u64" );
+    }
+    if Burn::<T>::translate::<u64, _>( |burn| burn.map( widen ) ).is_err() {
+        log::error!( target: LOG_TARGET, "Burn could not be decoded as a u64" );
+    }
+    if HotkeySwapCost::<T>::translate::<u64, _>( |cost| cost.map( widen ) ).is_err() {
+        log::error!( target: LOG_TARGET, "HotkeySwapCost could not be decoded as a u64" );
+    }
+    log::info!( target: LOG_TARGET, "re-encoded {:?} stake entries as balances", translated );
+    T::DbWeight::get().reads_writes( translated + 4, translated + 4 )
+}
@@ -1,14 +1,34 @@
 use super::*;
 use substrate_fixed::types::I65F63;
-use frame_support::{IterableStorageMap};
 use sp_std::convert::TryInto;
 use sp_core::{H256, U256};
 use sp_io::hashing::sha2_256;
 use sp_io::hashing::keccak_256;
 use frame_system::{ensure_signed};
+use frame_support::{traits::Get, weights::Weight};
 
 const LOG_TARGET: &'static str = "runtime::subtensor::registration";
 
+/// Blocks of UsedWorkByBlock pruned at most per call to prune_used_work.
+const USED_WORK_BLOCKS_PRUNED_PER_CALL: u64 = 8;
+
+/// Seals visited at most per call to prune_used_work_backlog.
+const USED_WORK_BACKLOG_CHUNK: usize = 512;
+
+impl<T: Config> From<RegistrationWorkError> for Error<T> {
+    fn from( error: RegistrationWorkError ) -> Self {
+        match error {
+            RegistrationWorkError::BlockFull => Error::<T>::ToManyRegistrationsThisBlock,
+            RegistrationWorkError::AlreadyRegistered => Error::<T>::AlreadyRegistered,
+            RegistrationWorkError::FutureBlock | RegistrationWorkError::TooOld => Error::<T>::InvalidWorkBlock,
+            RegistrationWorkError::WorkRepeated => Error::<T>::WorkRepeated,
+            RegistrationWorkError::UnderDifficulty => Error::<T>::InvalidDifficulty,
+            RegistrationWorkError::SealMismatch => Error::<T>::InvalidSeal,
+            RegistrationWorkError::LegacySealExpired => Error::<T>::LegacySealExpired,
+        }
+    }
+}
+
 impl<T: Config> Pallet<T> {
 
     pub fn do_registration ( 
@@ -23,36 +43,117 @@ impl<T: Config> Pallet<T> {
         // --- Check the callers hotkey signature.
         ensure_signed(origin)?;
 
+        // --- Check the registration limits, the work block, the difficulty and the seal.
+        Self::check_registration_work( block_number, nonce, &work, &hotkey ).map_err( Error::<T>::from )?;
+
+        // --- Check the coldkey is not being swapped, announced swaps count the hotkeys they move.
+        ensure!( !ColdkeySwaps::<T>::contains_key( &coldkey ), Error::<T>::ColdkeySwapPending );
+        
+        // Check that the hotkey has not already been registered.
+        ensure!( !Hotkeys::<T>::contains_key(&hotkey), Error::<T>::AlreadyRegistered );
+        
+        // Above this line all relevant checks that the registration is legitimate have been met. 
+        // --- registration does not exceed limit.
+        // --- registration meets difficulty.
+        // --- registration is not a duplicate.
+        // --- Insert the neuron, pruning the lowest scoring peer if the graph is full.
+        Self::register_neuron( &hotkey, &coldkey );
+
+        // --- Record the work so it cannot be used again.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        UsedWork::<T>::insert( &work.clone(), current_block ); // Add the work to current + block. So we can prune at a later date.
+        UsedWorkByBlock::<T>::append( current_block, &work ); // Index the work by block so prune_used_work can find it.
+
+        Ok(())
+    }
+
+    /// Runs every check do_registration makes on a proof of work without writing to storage,
+    /// so clients can find out why register would refuse it before submitting. Runtime api calls
+    /// at a block run after the next block has been initialized on top of it, so the block number,
+    /// registrations and difficulty checked against are those a register included next would see.
+    ///
+    /// It returns the following errors if the work would be refused
+    /// - BlockFull : The block already holds max_registrations_per_block registrations
+    /// - AlreadyRegistered : The hotkey is already registered
+    /// - FutureBlock : The work was done on a block after the current one
+    /// - TooOld : The work was done SEAL_VALIDITY_BLOCKS or more blocks ago
+    /// - WorkRepeated : The work has already been used
+    /// - UnderDifficulty : The work does not meet the current difficulty
+    /// - SealMismatch : The work is not the seal of the block number, nonce and hotkey
+    /// - LegacySealExpired : The work is a legacy seal and the cutoff block has been reached
+    ///
+    pub fn check_registration_work( block_number: u64, nonce: u64, work: &[u8], hotkey: &T::AccountId ) -> Result<(), RegistrationWorkError> {
+
         // --- Check that registrations per block and hotkey.
         let registrations_this_block: u64 = Self::get_registrations_this_block();
-        ensure! ( registrations_this_block < Self::get_max_registratations_per_block(), Error::<T>::ToManyRegistrationsThisBlock ); // Number of registrations this block exceeded.
-        ensure!( !Hotkeys::<T>::contains_key(&hotkey), Error::<T>::AlreadyRegistered );  // Hotkey has already registered.
+        ensure! ( registrations_this_block < Self::get_max_registratations_per_block(), RegistrationWorkError::BlockFull ); // Number of registrations this block exceeded.
+        ensure!( !Hotkeys::<T>::contains_key( hotkey ), RegistrationWorkError::AlreadyRegistered );  // Hotkey has already registered.
 
         // --- Check block number validity.
         let current_block_number: u64 = Self::get_current_block_as_u64_here();
-        ensure! ( block_number <= current_block_number, Error::<T>::InvalidWorkBlock ); // Can't work on future block.
-        ensure! ( current_block_number - block_number < 3, Error::<T>::InvalidWorkBlock ); // Work must have been done within 3 blocks (stops long range attacks).
+        ensure! ( block_number <= current_block_number, RegistrationWorkError::FutureBlock ); // Can't work on future block.
+        ensure! ( current_block_number - block_number < SEAL_VALIDITY_BLOCKS, RegistrationWorkError::TooOld ); // Work must have been done within 3 blocks (stops long range attacks).
 
         // --- Check for repeat work,
-        ensure!( !UsedWork::<T>::contains_key( &work.clone() ), Error::<T>::WorkRepeated );  // Work has not been used before.
+        ensure!( !UsedWork::<T>::contains_key( work ), RegistrationWorkError::WorkRepeated );  // Work has not been used before.
 
         // --- Check difficulty.
+        ensure! ( work.len() == 32, RegistrationWorkError::SealMismatch ); // A seal is a 32 byte hash.
         let difficulty: U256 = Self::get_difficulty();
-        let work_hash: H256 = Self::vec_to_hash( work.clone() );
-        ensure! ( Self::hash_meets_difficulty( &work_hash, difficulty ), Error::<T>::InvalidDifficulty ); // Check that the work meets difficulty.
+        let work_hash: H256 = Self::vec_to_hash( work.to_vec() );
+        ensure! ( Self::hash_meets_difficulty( &work_hash, difficulty ), RegistrationWorkError::UnderDifficulty ); // Check that the work meets difficulty.
+
+        // --- Check work. A seal bound to the hotkey is always accepted, a legacy seal only before the cutoff.
+        let seal: H256 = Self::create_seal_hash_for_hotkey( block_number, nonce, hotkey );
+        if seal != work_hash {
+            let legacy_seal: H256 = Self::create_seal_hash( block_number, nonce );
+            ensure! ( legacy_seal == work_hash, RegistrationWorkError::SealMismatch ); // Check that this work matches hash and nonce.
+            ensure! ( current_block_number < Self::get_legacy_seal_cutoff(), RegistrationWorkError::LegacySealExpired ); // Legacy seals can be stolen from the mempool.
+        }
 
-        // --- Check work.
-        let seal: H256 = Self::create_seal_hash( block_number, nonce );
-        ensure! ( seal == work_hash, Error::<T>::InvalidSeal ); // Check that this work matches hash and nonce.
-        
-        // Check that the hotkey has not already been registered.
-        ensure!( !Hotkeys::<T>::contains_key(&hotkey), Error::<T>::AlreadyRegistered );
-        
-        // Above this line all relevant checks that the registration is legitimate have been met. 
-        // --- registration does not exceed limit.
-        // --- registration meets difficulty.
-        // --- registration is not a duplicate.
-        // Next we will check to see if the uid limit has been reached.
+        Ok(())
+    }
+
+    /// Registers the hotkey under the calling coldkey without proof of work. The current
+    /// burn is withdrawn from the coldkey and taken out of the issuance. Burned registrations
+    /// share max_registrations_per_block and the registrations counted towards the burn and
+    /// difficulty adjustment with PoW registrations.
+    ///
+    /// It throws the following errors if there is something wrong
+    /// - ToManyRegistrationsThisBlock : The block already holds max_registrations_per_block registrations
+    /// - AlreadyRegistered : The hotkey is already registered
+    /// - NotEnoughBalanceToRegister : The coldkey cannot pay the burn
+    /// - ColdkeySwapPending : The coldkey has a pending coldkey swap
+    ///
+    pub fn do_burned_registration( origin: T::Origin, hotkey: T::AccountId ) -> dispatch::DispatchResult {
+
+        // --- Check the callers coldkey signature.
+        let coldkey = ensure_signed(origin)?;
+
+        // --- Check that registrations per block and hotkey.
+        let registrations_this_block: u64 = Self::get_registrations_this_block();
+        ensure! ( registrations_this_block < Self::get_max_registratations_per_block(), Error::<T>::ToManyRegistrationsThisBlock ); // Number of registrations this block exceeded.
+        ensure!( !Hotkeys::<T>::contains_key(&hotkey), Error::<T>::AlreadyRegistered );  // Hotkey has already registered.
+        ensure!( !ColdkeySwaps::<T>::contains_key( &coldkey ), Error::<T>::ColdkeySwapPending ); // Announced swaps count the hotkeys they move.
+
+        // --- We burn the registration cost from the coldkey and take it out of the issuance, as the balances pallet does.
+        let burn: BalanceOf<T> = Self::get_burn();
+        ensure!( Self::can_remove_balance_from_coldkey_account( &coldkey, burn ), Error::<T>::NotEnoughBalanceToRegister );
+        Self::burn_from_coldkey( &coldkey, burn )?;
+
+        // --- Insert the neuron, pruning the lowest scoring peer if the graph is full.
+        Self::register_neuron( &hotkey, &coldkey );
+
+        Ok(())
+    }
+
+    /// Inserts the hotkey as a neuron owned by the coldkey and returns its uid. When the graph
+    /// is full the peer with the lowest pruning score is replaced and its stake returned.
+    /// Counts the registration towards this block and the adjustment interval and deposits
+    /// NeuronRegistered. Callers check the registration is legitimate beforehand.
+    pub fn register_neuron( hotkey: &T::AccountId, coldkey: &T::AccountId ) -> u32 {
+
+        // We check to see if the uid limit has been reached.
         // If we have reached our limit we need to find a replacement. 
         // The replacement peer is the peer with the lowest replacement score.
         let uid_to_set_in_metagraph: u32; // To be filled, we either are prunning or setting with get_next_uid.
@@ -69,19 +170,20 @@ impl<T: Config> Pallet<T> {
             // Pruning score values have already been computed at the previous mechanism step.
             let mut uid_to_prune: u32 = 0; // To be filled. Default to zero but will certainly be filled.
             let mut min_prunning_score: I65F63 = I65F63::from_num( u64::MAX ); // Start min score as max.
-            for ( uid_i, neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
+            for uid_i in Keys::<T>::iter_keys() {
 
                 // If a neuron has more than stake_pruning_min they are ranked based on stake
                 // otherwise we prune based on incentive.
+                let stake_i: BalanceOf<T> = Stake::<T>::get( uid_i );
                 let mut prunning_score: I65F63;
-                if neuron_i.stake >= Self::get_stake_pruning_min() {
-                    if Self::get_total_stake() > 0 { // in case stake pruning min == 0
-                        prunning_score = I65F63::from_num( neuron_i.stake ) / I65F63::from_num( Self::get_total_stake() );
+                if stake_i >= Self::get_stake_pruning_min().saturated_into::<BalanceOf<T>>() {
+                    if !Self::get_total_stake().is_zero() { // in case stake pruning min == 0
+                        prunning_score = I65F63::saturating_from_num( stake_i.saturated_into::<u128>() ) / I65F63::saturating_from_num( Self::get_total_stake().saturated_into::<u128>() );
                     } else {
                         prunning_score = I65F63::from_num( 0 );
                     }
                 } else {
-                    prunning_score = I65F63::from_num( neuron_i.incentive ) / I65F63::from_num( u64::MAX );
+                    prunning_score = I65F63::from_num( Incentive::<T>::get( uid_i ) ) / I65F63::from_num( u64::MAX );
                 }
                 
                 // Neurons that have registered within an immunity period should not be counted in this pruning
@@ -97,71 +199,134 @@ impl<T: Config> Pallet<T> {
                 // Find the min purnning score. We will remove this peer first. 
                 if prunning_score < min_prunning_score {
                     // Update the min
-                    uid_to_prune = neuron_i.uid;
+                    uid_to_prune = uid_i;
                     min_prunning_score = prunning_score;
                 }
             }
             // Remember which uid is min so we can replace it in the graph.
-            let neuron_to_prune: NeuronMetadataOf<T> = Neurons::<T>::get( uid_to_prune ).unwrap();
-            uid_to_set_in_metagraph = neuron_to_prune.uid;
-            let hotkey_to_prune = neuron_to_prune.hotkey;
-
-            // Next we will add this prunned peer to NeuronsToPruneAtNextEpoch.
-            // We record this set because we need to remove all bonds owned in this uid.
-            // neuron.bonds records all bonds this peer owns which will be removed by default. 
-            // However there are other peers with bonds in this peer, these need to be cleared as well.
-            // NOTE(const): In further iterations it will be beneficial to build bonds as a double
-            // iterable set so that deletions become easier. 
-            NeuronsToPruneAtNextEpoch::<T>::insert( uid_to_set_in_metagraph, uid_to_set_in_metagraph ); // Subtrate does not contain a set storage item.
+            uid_to_set_in_metagraph = uid_to_prune;
+            let hotkey_to_prune: T::AccountId = Keys::<T>::get( uid_to_prune ).unwrap();
+            let coldkey_to_prune: T::AccountId = Coldkeys::<T>::get( uid_to_prune ).unwrap();
+            let stake_to_prune: BalanceOf<T> = Stake::<T>::get( uid_to_prune );
+
+            // Next we clear every bond owned by this peer and every bond other peers hold in it,
+            // so the new neuron does not inherit the pruned peer's bonds.
+            Self::clear_bonds_for_uid( uid_to_set_in_metagraph );
+
             // Finally, we need to unstake all the funds that this peer had staked. 
-            // These funds are deposited back into the coldkey account so that no funds are destroyed. 
-            let stake_to_be_added_on_coldkey = Self::u64_to_balance( neuron_to_prune.stake );
-            Self::add_balance_to_coldkey_account( &neuron_to_prune.coldkey, stake_to_be_added_on_coldkey.unwrap() );
-            Self::decrease_total_stake( neuron_to_prune.stake );
+            // Nominators are paid what their shares are worth and the rest is released from
+            // the coldkey's stake reserve into its free balance so that no funds are destroyed. 
+            let delegated_to_prune: BalanceOf<T> = Self::return_delegated_stake( &hotkey_to_prune, &coldkey_to_prune );
+            Self::unreserve_stake_on_coldkey( &coldkey_to_prune, stake_to_prune.saturating_sub( delegated_to_prune ) );
+            Self::decrease_total_stake( stake_to_prune );
 
             // Remove hotkey from hotkeys set, 
             // and to clean up and prune whatever extra hotkeys there are on top of the existing max_allowed_uids
             if Hotkeys::<T>::contains_key(&hotkey_to_prune) {
-                Hotkeys::<T>::remove( hotkey_to_prune );
+                Hotkeys::<T>::remove( &hotkey_to_prune );
             }
+            Self::remove_hotkey_from_coldkey( &coldkey_to_prune, &hotkey_to_prune );
+            EmissionDestinations::<T>::remove( &hotkey_to_prune );
+            Takes::<T>::remove( &hotkey_to_prune );
+            LastTakeChange::<T>::remove( &hotkey_to_prune );
         }
 
-        // --- Next we create a new entry in the table with the new metadata.
-        let neuron = NeuronMetadataOf::<T> {
-            version: 0,
-            ip: 0,
-            port: 0,
-            ip_type: 0,
-            uid: uid_to_set_in_metagraph,
-            modality: 0,
-            hotkey: hotkey.clone(),
-            coldkey: coldkey.clone(),
-            active: 1,
-            last_update: current_block, 
-            priority: 0,
-            stake: 0,
-            rank: 0,
-            trust: 0,
-            consensus: 0,
-            incentive: 0,
-            emission: 0,
-            dividends: 0,
-            bonds: vec![],
-            weights: vec![(uid_to_set_in_metagraph, u32::MAX)], // self weight set to 1.
-        };
-
         // --- Update avg registrations per 1000 block.
         RegistrationsThisInterval::<T>::mutate( |val| *val += 1 );
         RegistrationsThisBlock::<T>::mutate( |val| *val += 1 );
 
         // --- We deposit the neuron registered event.
         BlockAtRegistration::<T>::insert( uid_to_set_in_metagraph, current_block ); // Set immunity momment.
-        Neurons::<T>::insert( uid_to_set_in_metagraph, neuron ); // Insert neuron info under uid.
-        Hotkeys::<T>::insert( &hotkey, uid_to_set_in_metagraph ); // Add hotkey into hotkey set.
-        UsedWork::<T>::insert( &work.clone(), current_block ); // Add the work to current + block. So we can prune at a later date.
+        Self::init_neuron( uid_to_set_in_metagraph, hotkey, coldkey, current_block ); // Insert neuron info under uid.
+        Hotkeys::<T>::insert( hotkey, uid_to_set_in_metagraph ); // Add hotkey into hotkey set.
+        ColdkeyHotkeys::<T>::append( coldkey, hotkey ); // Add hotkey into the coldkey's owned set.
         Self::deposit_event(Event::NeuronRegistered( uid_to_set_in_metagraph ));
+        uid_to_set_in_metagraph
+    }
 
-        Ok(())
+    /// Writes a freshly registered neuron under uid, overwriting every per uid
+    /// entry left by a pruned neuron. The new neuron starts with a self weight of 1.
+    /// Bonds are not touched here, pruning clears them with clear_bonds_for_uid.
+    pub fn init_neuron( uid: u32, hotkey: &T::AccountId, coldkey: &T::AccountId, current_block: u64 ) {
+        Keys::<T>::insert( uid, hotkey );
+        Coldkeys::<T>::insert( uid, coldkey );
+        Stake::<T>::insert( uid, BalanceOf::<T>::zero() );
+        Axons::<T>::insert( uid, AxonInfo::default() );
+        Weights::<T>::insert( uid, vec![(uid, u32::MAX)] ); // self weight set to 1.
+        Active::<T>::insert( uid, 1 );
+        LastUpdate::<T>::insert( uid, current_block );
+        Priority::<T>::insert( uid, 0 );
+        Rank::<T>::insert( uid, 0 );
+        Trust::<T>::insert( uid, 0 );
+        Consensus::<T>::insert( uid, 0 );
+        Incentive::<T>::insert( uid, 0 );
+        Dividends::<T>::insert( uid, 0 );
+        Emission::<T>::insert( uid, 0 );
+        OperatorEmission::<T>::insert( uid, 0 );
+        StakeEmission::<T>::insert( uid, 0 );
+    }
+
+    /// Removes the seals used more than used_work_retention blocks ago from UsedWork.
+    /// At most USED_WORK_BLOCKS_PRUNED_PER_CALL blocks are pruned per call so a raised
+    /// cursor lag is worked off over several blocks. Also advances the backlog cursor.
+    pub fn prune_used_work() -> Weight {
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let retention: u64 = Self::get_used_work_retention();
+        let mut cursor: u64 = UsedWorkPruneCursor::<T>::get();
+        let mut blocks_pruned: u64 = 0;
+        let mut seals_pruned: u64 = 0;
+        while blocks_pruned < USED_WORK_BLOCKS_PRUNED_PER_CALL && cursor.saturating_add( retention ) < current_block {
+            for work in UsedWorkByBlock::<T>::take( cursor ) {
+                UsedWork::<T>::remove( &work );
+                seals_pruned += 1;
+            }
+            cursor += 1;
+            blocks_pruned += 1;
+        }
+        if blocks_pruned > 0 {
+            UsedWorkPruneCursor::<T>::put( cursor );
+        }
+        let weight: Weight = T::DbWeight::get().reads_writes( 3 + blocks_pruned, blocks_pruned + seals_pruned + 1 );
+        weight.saturating_add( Self::prune_used_work_backlog( current_block, retention ) )
+    }
+
+    /// Prunes up to USED_WORK_BACKLOG_CHUNK seals from UsedWork that were used before
+    /// UsedWorkByBlock existed, continuing from UsedWorkBacklogCursor. Clears the cursor
+    /// once the end of UsedWork is reached.
+    pub fn prune_used_work_backlog( current_block: u64, retention: u64 ) -> Weight {
+        let cursor: Vec<u8> = match UsedWorkBacklogCursor::<T>::get() {
+            Some( cursor ) => cursor,
+            None => return T::DbWeight::get().reads(1),
+        };
+        let chunk: Vec<(Vec<u8>, u64)> = UsedWork::<T>::iter_from( cursor ).take( USED_WORK_BACKLOG_CHUNK ).collect();
+        let mut seals_pruned: u64 = 0;
+        for ( work, block ) in chunk.iter() {
+            if block.saturating_add( retention ) < current_block {
+                UsedWork::<T>::remove( work );
+                seals_pruned += 1;
+            }
+        }
+        match chunk.last() {
+            Some( ( work, _ ) ) if chunk.len() == USED_WORK_BACKLOG_CHUNK => UsedWorkBacklogCursor::<T>::put( UsedWork::<T>::hashed_key_for( work ) ),
+            _ => {
+                log::info!( target: LOG_TARGET, "cleared the used work backlog" );
+                UsedWorkBacklogCursor::<T>::kill();
+            }
+        }
+        T::DbWeight::get().reads_writes( 1 + chunk.len() as u64, seals_pruned + 1 )
+    }
+
+    /// Removes the hotkey from the set of hotkeys owned by this coldkey,
+    /// dropping the coldkey entry altogether once it owns nothing.
+    pub fn remove_hotkey_from_coldkey( coldkey: &T::AccountId, hotkey: &T::AccountId ) {
+        ColdkeyHotkeys::<T>::mutate_exists( coldkey, |maybe_hotkeys| {
+            if let Some( hotkeys ) = maybe_hotkeys {
+                hotkeys.retain( |owned| owned != hotkey );
+                if hotkeys.is_empty() {
+                    *maybe_hotkeys = None;
+                }
+            }
+        });
     }
 
     pub fn get_current_block_as_u64_here( ) -> u64 {
@@ -259,6 +424,35 @@ impl<T: Config> Pallet<T> {
         return seal_hash;
     }
 
+    /// Creates a seal of version SEAL_VERSION for the hash of block_number_u64.
+    pub fn create_seal_hash_for_hotkey( block_number_u64: u64, nonce_u64: u64, hotkey: &T::AccountId ) -> H256 {
+        let block_hash_at_number: H256 = Self::get_block_hash_from_u64( block_number_u64 );
+        Self::create_seal_hash_for_block_hash( &block_hash_at_number, nonce_u64, hotkey )
+    }
+
+    /// Creates a seal of version SEAL_VERSION. The preimage is the legacy one, the nonce as
+    /// 8 little endian bytes followed by the block hash, with the SCALE encoded hotkey appended,
+    /// so work found for one hotkey does not register any other. Reads no storage, so off chain
+    /// solvers call it directly to produce the exact seal register checks.
+    pub fn create_seal_hash_for_block_hash( block_hash: &H256, nonce_u64: u64, hotkey: &T::AccountId ) -> H256 {
+        let mut full_bytes: Vec<u8> = nonce_u64.to_le_bytes().to_vec();
+        full_bytes.extend_from_slice( block_hash.as_bytes() );
+        full_bytes.extend_from_slice( &hotkey.encode() );
+        let sha256_seal_hash_vec: [u8; 32] = sha2_256( &full_bytes );
+        let keccak_256_seal_hash_vec: [u8; 32] = keccak_256( &sha256_seal_hash_vec );
+        let seal_hash: H256 = H256::from_slice( &keccak_256_seal_hash_vec );
+
+		 log::trace!(
+			"\nnonce_u64: {:?}, \nblock_hash: {:?}, \nhotkey: {:?}, \nseal_hash: {:?}",
+			nonce_u64,
+			block_hash,
+			hotkey,
+			seal_hash
+		);
+
+        return seal_hash;
+    }
+
     // Helper function for creating nonce and work.
     pub fn create_work_for_block_number( block_number: u64, start_nonce: u64 ) -> (u64, Vec<u8>) {
         let difficulty: U256 = Self::get_difficulty();
@@ -272,6 +466,19 @@ impl<T: Config> Pallet<T> {
         return (nonce, vec_work)
     }
 
+    // Helper function for creating nonce and work bound to the hotkey.
+    pub fn create_work_for_hotkey( block_number: u64, start_nonce: u64, hotkey: &T::AccountId ) -> (u64, Vec<u8>) {
+        let difficulty: U256 = Self::get_difficulty();
+        let mut nonce: u64 = start_nonce;
+        let mut work: H256 = Self::create_seal_hash_for_hotkey( block_number, nonce, hotkey );
+        while !Self::hash_meets_difficulty(&work, difficulty) {
+            nonce = nonce + 1;
+            work = Self::create_seal_hash_for_hotkey( block_number, nonce, hotkey );
+        }
+        let vec_work: Vec<u8> = Self::hash_to_vec( work );
+        return (nonce, vec_work)
+    }
+
     pub fn print_seal( block_number: u64, nonce_u64: u64, difficulty: u64 ) {
         let block_hash: H256 = Self::get_block_hash_from_u64(block_number);
         let block_hash_bytes: &[u8] = block_hash.as_bytes();
@@ -0,0 +1,147 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /***********************************************************
+     * Read-only queries served through the SubtensorApi runtime api.
+     ***********************************************************/
+
+    // --- Returns the neuron registered under this uid or None if the uid is not in use.
+    pub fn get_neuron( uid: u32 ) -> Option<NeuronMetadataOf<T>> {
+        if !Self::is_uid_active( uid ) {
+            return None;
+        }
+        return Some( Self::get_neuron_for_uid( uid ) );
+    }
+
+    // --- Returns every registered neuron ordered by uid.
+    pub fn get_neurons() -> Vec<NeuronMetadataOf<T>> {
+        let n = Self::get_neuron_count();
+        let mut neurons: Vec<NeuronMetadataOf<T>> = Vec::with_capacity( n as usize );
+        for uid in 0..n {
+            if let Some( neuron ) = Self::get_neuron( uid ) {
+                neurons.push( neuron );
+            }
+        }
+        return neurons;
+    }
+
+    // --- Returns the neuron registered with this hotkey or None if the hotkey is not registered.
+    pub fn get_neuron_by_hotkey( hotkey: &T::AccountId ) -> Option<NeuronMetadataOf<T>> {
+        if !Self::is_hotkey_active( hotkey ) {
+            return None;
+        }
+        return Self::get_neuron( Self::get_uid_for_hotkey( hotkey ) );
+    }
+
+    // --- Returns the hotkeys registered by this coldkey with their uid, stake and last emission.
+    pub fn get_hotkeys_for_coldkey( coldkey: &T::AccountId ) -> Vec<OwnedHotkeyInfo<T::AccountId, BalanceOf<T>>> {
+        let mut owned: Vec<OwnedHotkeyInfo<T::AccountId, BalanceOf<T>>> = vec![];
+        for hotkey in ColdkeyHotkeys::<T>::get( coldkey ) {
+            if Self::is_hotkey_active( &hotkey ) {
+                let uid = Self::get_uid_for_hotkey( &hotkey );
+                owned.push( OwnedHotkeyInfo {
+                    hotkey,
+                    uid,
+                    stake: Stake::<T>::get( uid ),
+                    emission: Emission::<T>::get( uid ),
+                });
+            }
+        }
+        return owned;
+    }
+
+    // --- Returns the delegate registered with this hotkey or None if the hotkey is not a registered delegate.
+    pub fn get_delegate( hotkey: &T::AccountId ) -> Option<DelegateInfo<T::AccountId, BalanceOf<T>>> {
+        if !Self::is_hotkey_active( hotkey ) || !Self::is_delegate( hotkey ) {
+            return None;
+        }
+        let uid = Self::get_uid_for_hotkey( hotkey );
+        let total_shares: BalanceOf<T> = TotalNominatorShares::<T>::get( hotkey );
+        let delegated_stake: BalanceOf<T> = DelegatedStake::<T>::get( hotkey );
+        let nominators: Vec<(T::AccountId, BalanceOf<T>)> = NominatorShares::<T>::iter_prefix( hotkey )
+            .map( |( nominator, shares )| ( nominator, Self::stake_for_shares( shares, total_shares, delegated_stake ) ) )
+            .collect();
+        return Some( DelegateInfo {
+            hotkey: hotkey.clone(),
+            uid,
+            owner: Self::get_coldkey_for_uid( uid ),
+            stake: Stake::<T>::get( uid ),
+            delegated_stake,
+            take: Takes::<T>::get( hotkey ),
+            nominators,
+        });
+    }
+
+    // --- Returns every registered delegate.
+    pub fn get_delegates() -> Vec<DelegateInfo<T::AccountId, BalanceOf<T>>> {
+        return Delegates::<T>::iter_keys().filter_map( |hotkey| Self::get_delegate( &hotkey ) ).collect();
+    }
+
+    // --- Returns each delegate hotkey this coldkey nominates with the stake its shares are currently worth.
+    pub fn get_nominations_for_coldkey( coldkey: &T::AccountId ) -> Vec<(T::AccountId, BalanceOf<T>)> {
+        let mut nominations: Vec<(T::AccountId, BalanceOf<T>)> = vec![];
+        for hotkey in Delegates::<T>::iter_keys() {
+            if NominatorShares::<T>::contains_key( &hotkey, coldkey ) {
+                let stake: BalanceOf<T> = Self::get_nominator_stake( &hotkey, coldkey );
+                nominations.push( ( hotkey, stake ) );
+            }
+        }
+        return nominations;
+    }
+
+    // --- Returns the stake this coldkey has removed and not yet withdrawn, oldest chunk first.
+    pub fn get_pending_unlocks( coldkey: &T::AccountId ) -> Vec<UnlockChunk<BalanceOf<T>>> {
+        return Unbonding::<T>::get( coldkey );
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /***********************************************************
+     * Hyperparameter snapshot served through the SubtensorApi runtime api.
+     ***********************************************************/
+
+    // --- Returns the current value of every sudo settable hyperparameter.
+    pub fn get_hyperparameters() -> SubtensorHyperparams<BalanceOf<T>> {
+        SubtensorHyperparams {
+            version: HYPERPARAMS_VERSION,
+            rho: Self::get_rho(),
+            kappa: Self::get_kappa(),
+            incentive_pruning_denominator: Self::get_incentive_pruning_denominator(),
+            stake_pruning_denominator: Self::get_stake_pruning_denominator(),
+            stake_pruning_min: Self::get_stake_pruning_min(),
+            validator_epoch_len: Self::get_validator_epoch_len(),
+            validator_epochs_per_reset: Self::get_validator_epochs_per_reset(),
+            validator_batch_size: Self::get_validator_batch_size(),
+            validator_sequence_length: Self::get_validator_sequence_length(),
+            validator_exclude_quantile: Self::get_validator_exclude_quantile(),
+            max_allowed_uids: Self::get_max_allowed_uids(),
+            min_allowed_weights: Self::get_min_allowed_weights(),
+            max_allowed_max_min_ratio: Self::get_max_allowed_max_min_ratio(),
+            max_weight_limit: Self::get_max_weight_limit(),
+            immunity_period: Self::get_immunity_period(),
+            blocks_per_step: Self::get_blocks_per_step(),
+            bonds_moving_average: Self::get_bonds_moving_average(),
+            activity_cutoff: Self::get_activity_cutoff(),
+            difficulty: Self::get_difficulty_as_u64(),
+            adjustment_interval: Self::get_adjustment_interval(),
+            target_registrations_per_interval: Self::get_target_registrations_per_interval(),
+            max_registrations_per_block: Self::get_max_registratations_per_block(),
+            foundation_distribution: FoundationDistribution::<T>::get(),
+            scaling_law_power: Self::get_scaling_law_power(),
+            synergy_scaling_law_power: Self::get_synergy_scaling_law_power(),
+            used_work_retention: Self::get_used_work_retention(),
+            unbonding_period: Self::get_unbonding_period(),
+            hotkey_swap_cost: Self::get_hotkey_swap_cost(),
+            coldkey_swap_delay: Self::get_coldkey_swap_delay(),
+            max_take: Self::get_max_take(),
+            take_rate_limit: Self::get_take_rate_limit(),
+            burn: Self::get_burn(),
+            seal_version: SEAL_VERSION,
+            legacy_seal_cutoff: Self::get_legacy_seal_cutoff(),
+            min_difficulty: Self::get_min_difficulty(),
+            max_difficulty: Self::get_max_difficulty(),
+            min_burn: Self::get_min_burn(),
+            max_burn: Self::get_max_burn(),
+        }
+    }
+}
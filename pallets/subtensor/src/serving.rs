@@ -15,17 +15,13 @@ impl<T: Config> Pallet<T> {
         // --- We get the uid associated with this hotkey account.
         let uid = Self::get_uid_for_hotkey(&hotkey_id);
 
-        // --- We get the neuron assoicated with this hotkey.
-        let mut neuron = Self::get_neuron_for_uid(uid);
-        neuron.version = version;
-        neuron.ip = ip;
-        neuron.port = port;
-        neuron.ip_type = ip_type;
-        neuron.active = 1;
-        neuron.last_update = Self::get_current_block_as_u64();
+        // --- We update the axon and activity of the neuron assoicated with this hotkey.
+        let axon = AxonInfo { version, ip, port, ip_type, modality };
+        Axons::<T>::insert(uid, axon);
+        Active::<T>::insert(uid, 1);
+        LastUpdate::<T>::insert(uid, Self::get_current_block_as_u64());
 
         // --- We deposit the neuron updated event
-        Neurons::<T>::insert(uid, neuron);
         Self::deposit_event(Event::AxonServed(uid));
         
         Ok(())
@@ -40,9 +36,9 @@ impl<T: Config> Pallet<T> {
             return true;
         }
 
-        // Hotkey is active, so we are able to find the neuron associated with it
-        let neuron = Self::get_neuron_for_hotkey(hotkey);
-        Self::neuron_belongs_to_coldkey(&neuron, coldkey)
+        // Hotkey is active, so we are able to find the coldkey associated with it
+        let uid = Self::get_uid_for_hotkey(hotkey);
+        Self::get_coldkey_for_uid(uid) == *coldkey
     }
 }
 
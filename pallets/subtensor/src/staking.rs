@@ -1,11 +1,13 @@
 use super::*;
+use sp_core::U256;
+use frame_support::traits::OnUnbalanced;
 
 impl<T: Config> Pallet<T> {
     /***********************************************************
      * do_add_stake() - main function called from parent module
      ***********************************************************/
 
-    pub fn do_add_stake(origin: T::Origin, hotkey: T::AccountId, stake_to_be_added: u64) -> dispatch::DispatchResult
+    pub fn do_add_stake(origin: T::Origin, hotkey: T::AccountId, stake_to_be_added: BalanceOf<T>) -> dispatch::DispatchResult
     {
         // ---- We check the transaction is signed by the caller
         // and retrieve the T::AccountId pubkey information.
@@ -14,23 +16,20 @@ impl<T: Config> Pallet<T> {
 
         // Check if the hotkey is active
         ensure!(Self::is_hotkey_active(&hotkey), Error::<T>::NotRegistered);
-        let neuron = Self::get_neuron_for_hotkey(&hotkey);
+        let uid = Self::get_uid_for_hotkey(&hotkey);
 
         // Check if uid is active
-        ensure!(Self::is_uid_active(neuron.uid), Error::<T>::NotRegistered);
+        ensure!(Self::is_uid_active(uid), Error::<T>::NotRegistered);
 
-        // ---- We check that the NeuronMetadata is linked to the calling
+        // ---- We check that the neuron is linked to the calling
         // cold key, otherwise throw a NonAssociatedColdKey error.
-        ensure!(Self::neuron_belongs_to_coldkey(&neuron, &coldkey), Error::<T>::NonAssociatedColdKey);
+        ensure!(Self::uid_belongs_to_coldkey(uid, &coldkey), Error::<T>::NonAssociatedColdKey);
 
         // ---- We check that the calling coldkey contains enough funds to
-        // create the staking transaction.
-        let stake_as_balance = Self::u64_to_balance(stake_to_be_added);
-        ensure!(stake_as_balance.is_some(), Error::<T>::CouldNotConvertToBalance);
-
-        ensure!(Self::can_remove_balance_from_coldkey_account(&coldkey, stake_as_balance.unwrap()), Error::<T>::NotEnoughBalanceToStake);
-        ensure!(Self::remove_balance_from_coldkey_account(&coldkey, stake_as_balance.unwrap()) == true, Error::<T>::BalanceWithdrawalError);
-        Self::add_stake_to_neuron_hotkey_account(neuron.uid, stake_to_be_added);
+        // create the staking transaction. The funds stay on the coldkey, held as a reserve.
+        ensure!(Self::can_reserve_stake_on_coldkey(&coldkey, stake_to_be_added), Error::<T>::NotEnoughBalanceToStake);
+        ensure!(Self::reserve_stake_on_coldkey(&coldkey, stake_to_be_added) == true, Error::<T>::BalanceWithdrawalError);
+        Self::add_stake_to_neuron_hotkey_account(uid, stake_to_be_added);
 
         // ---- Emit the staking event.
         Self::deposit_event(Event::StakeAdded(hotkey, stake_to_be_added));
@@ -39,7 +38,7 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    /// This function removes stake from a hotkey account and puts into a coldkey account.
+    /// This function removes stake from a hotkey account and queues it for release to the coldkey account.
     /// This function should be called through an extrinsic signed with the coldkeypair's private
     /// key. It takes a hotkey account id and an ammount as parameters.
     ///
@@ -47,16 +46,17 @@ impl<T: Config> Pallet<T> {
     /// 1) A Check is performed to see if the hotkey is active (ie, the node using the key is subscribed)
     /// 2) The neuron metadata associated with the hotkey is retrieved, and is checked if it is subscribed with the supplied cold key
     /// 3) If these checks pass, inflation is emitted to the nodes' peers
-    /// 4) If the account has enough stake, the requested amount it transferred to the coldkey account
-    /// 5) The total amount of stake is reduced after transfer is complete
+    /// 4) If the account has enough stake, the requested amount is queued on the coldkey and can be
+    ///    withdrawn with withdraw_unbonded once the unbonding period has passed
+    /// 5) The total amount of stake is reduced immediately, so the queued amount no longer counts in consensus
     ///
     /// It throws the following errors if there is something wrong
     /// - NotRegistered : The suplied hotkey is not in use. This ususally means a node that uses this key has not subscribed yet, or has unsubscribed
     /// - NonAssociatedColdKey : The supplied hotkey account id is not subscribed using the supplied cold key
-    /// - NotEnoughStaketoWithdraw : The ammount of stake available in the hotkey account is lower than the requested amount
-    /// - CouldNotConvertToBalance : A conversion error occured while converting stake from u64 to Balance
+    /// - NotEnoughStaketoWithdraw : The ammount of stake the coldkey owns in the hotkey account is lower than the requested amount
+    /// - TooManyUnbondingChunks : The coldkey already has MAX_UNBONDING_CHUNKS chunks queued
     ///
-    pub fn do_remove_stake(origin: T::Origin, hotkey: T::AccountId, stake_to_be_removed: u64) -> dispatch::DispatchResult {
+    pub fn do_remove_stake(origin: T::Origin, hotkey: T::AccountId, stake_to_be_removed: BalanceOf<T>) -> dispatch::DispatchResult {
 
         // ---- We check the transaction is signed by the caller
         // and retrieve the T::AccountId pubkey information.
@@ -65,26 +65,24 @@ impl<T: Config> Pallet<T> {
         // ---- We query the Neuron set for the NeuronMetadata stored under
         // the passed hotkey.
         ensure!(Self::is_hotkey_active(&hotkey), Error::<T>::NotRegistered);
-        let neuron = Self::get_neuron_for_hotkey(&hotkey);
+        let uid = Self::get_uid_for_hotkey(&hotkey);
 
         // Check if uid is active
-        ensure!(Self::is_uid_active(neuron.uid), Error::<T>::NotRegistered);
+        ensure!(Self::is_uid_active(uid), Error::<T>::NotRegistered);
 
-        // ---- We check that the NeuronMetadata is linked to the calling
+        // ---- We check that the neuron is linked to the calling
         // cold key, otherwise throw a NonAssociatedColdKey error.
-        ensure!(Self::neuron_belongs_to_coldkey(&neuron, &coldkey), Error::<T>::NonAssociatedColdKey);
+        ensure!(Self::uid_belongs_to_coldkey(uid, &coldkey), Error::<T>::NonAssociatedColdKey);
 
         // ---- We check that the hotkey has enough stake to withdraw
-        // and then withdraw from the account.
-        ensure!(Self::has_enough_stake(&neuron, stake_to_be_removed), Error::<T>::NotEnoughStaketoWithdraw);
-        let stake_to_be_added_as_currency = Self::u64_to_balance(stake_to_be_removed);
-        ensure!(stake_to_be_added_as_currency.is_some(), Error::<T>::CouldNotConvertToBalance);
+        // and then withdraw from the account. Stake delegated by nominators is not the owner's to withdraw.
+        ensure!(Self::get_owned_stake_for_uid(uid, &hotkey) >= stake_to_be_removed, Error::<T>::NotEnoughStaketoWithdraw);
+        ensure!(Self::can_unbond(&coldkey), Error::<T>::TooManyUnbondingChunks);
 
-        // --- We perform the withdrawl by converting the stake to a u64 balance
-        // and deposit the balance into the coldkey account. If the coldkey account
-        // does not exist it is created.
-        Self::add_balance_to_coldkey_account(&coldkey, stake_to_be_added_as_currency.unwrap());
-        Self::remove_stake_from_neuron_hotkey_account(neuron.uid, stake_to_be_removed);
+        // --- We perform the withdrawl by taking the stake out of the hotkey account
+        // and queueing it on the coldkey until the unbonding period has passed.
+        Self::remove_stake_from_neuron_hotkey_account(uid, stake_to_be_removed);
+        Self::unbond_to_coldkey(&coldkey, stake_to_be_removed);
 
         // ---- Emit the unstaking event.
         Self::deposit_event(Event::StakeRemoved(hotkey, stake_to_be_removed));
@@ -93,32 +91,94 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Moves stake between two hotkeys registered by the calling coldkey in a single step.
+    /// The stake never passes through the coldkey's free balance, so the total stake is unchanged.
+    ///
+    /// It throws the following errors if there is something wrong
+    /// - NotRegistered : One of the hotkeys is not registered
+    /// - NonAssociatedColdKey : One of the hotkeys was not registered by the calling coldkey
+    /// - NotEnoughStaketoWithdraw : The stake the coldkey owns in from_hotkey is lower than the requested amount
+    ///
+    pub fn do_move_stake(origin: T::Origin, from_hotkey: T::AccountId, to_hotkey: T::AccountId, stake_to_be_moved: BalanceOf<T>) -> dispatch::DispatchResult {
+
+        // ---- We check the transaction is signed by the caller
+        // and retrieve the T::AccountId pubkey information.
+        let coldkey = ensure_signed(origin)?;
+
+        // ---- We check both hotkeys are registered and linked to the calling coldkey.
+        ensure!(Self::is_hotkey_active(&from_hotkey), Error::<T>::NotRegistered);
+        ensure!(Self::is_hotkey_active(&to_hotkey), Error::<T>::NotRegistered);
+        let from_uid = Self::get_uid_for_hotkey(&from_hotkey);
+        let to_uid = Self::get_uid_for_hotkey(&to_hotkey);
+        ensure!(Self::uid_belongs_to_coldkey(from_uid, &coldkey), Error::<T>::NonAssociatedColdKey);
+        ensure!(Self::uid_belongs_to_coldkey(to_uid, &coldkey), Error::<T>::NonAssociatedColdKey);
+
+        // ---- We check the source hotkey has enough stake owned by the coldkey.
+        // Stake delegated by nominators is not the owner's to move.
+        ensure!(Self::get_owned_stake_for_uid(from_uid, &from_hotkey) >= stake_to_be_moved, Error::<T>::NotEnoughStaketoWithdraw);
+
+        // ---- We move the stake, the total stake goes down and back up by the same amount.
+        Self::remove_stake_from_neuron_hotkey_account(from_uid, stake_to_be_moved);
+        Self::add_stake_to_neuron_hotkey_account(to_uid, stake_to_be_moved);
+
+        // ---- Emit the event and return ok.
+        Self::deposit_event(Event::StakeMoved(coldkey, from_hotkey, to_hotkey, stake_to_be_moved));
+        Ok(())
+    }
+
+    /// Sets where the owner's part of the hotkey's emission is paid from the next step on.
+    /// Stake compounds it into the hotkey's stake, Coldkey mints it into the coldkey's free
+    /// balance so the owner does not have to remove stake to reach it.
+    ///
+    /// It throws the following errors if there is something wrong
+    /// - NotRegistered : The hotkey is not registered
+    /// - NonAssociatedColdKey : The hotkey was not registered by the calling coldkey
+    ///
+    pub fn do_set_emission_destination(origin: T::Origin, hotkey: T::AccountId, destination: EmissionDestination) -> dispatch::DispatchResult {
+
+        // ---- We check the transaction is signed by the owning coldkey.
+        let coldkey = ensure_signed(origin)?;
+        ensure!(Self::is_hotkey_active(&hotkey), Error::<T>::NotRegistered);
+        let uid = Self::get_uid_for_hotkey(&hotkey);
+        ensure!(Self::uid_belongs_to_coldkey(uid, &coldkey), Error::<T>::NonAssociatedColdKey);
+
+        // ---- Only hotkeys paying out are stored, compounding is the default.
+        match destination {
+            EmissionDestination::Stake => EmissionDestinations::<T>::remove(&hotkey),
+            EmissionDestination::Coldkey => EmissionDestinations::<T>::insert(&hotkey, destination),
+        }
+
+        // ---- Emit the event and return ok.
+        Self::deposit_event(Event::EmissionDestinationSet(hotkey, destination));
+        Ok(())
+    }
+
 
     /********************************
     --==[[  Helper functions   ]]==--
     *********************************/
 
-    pub fn get_stake_of_neuron_hotkey_account_by_uid(uid: u32) -> u64 {
-        return Self::get_neuron_for_uid(uid).stake
+    pub fn get_stake_of_neuron_hotkey_account_by_uid(uid: u32) -> BalanceOf<T> {
+        return Stake::<T>::get(uid)
     }
 
     /// Increases the amount of stake of the entire stake pool by the supplied amount
     ///
-    pub fn increase_total_stake(increment: u64) {
+    pub fn increase_total_stake(increment: BalanceOf<T>) {
         // --- We update the total staking pool with the new funds.
-        let total_stake: u64 = TotalStake::<T>::get();
+        let total_stake: BalanceOf<T> = TotalStake::<T>::get();
 
         // Sanity check
-        debug_assert!(increment <= u64::MAX.saturating_sub(total_stake));
+        debug_assert!(increment <= BalanceOf::<T>::max_value().saturating_sub(total_stake));
 
         TotalStake::<T>::put(total_stake.saturating_add(increment));
     }
 
     /// Reduces the amount of stake of the entire stake pool by the supplied amount
     ///
-    pub fn decrease_total_stake(decrement: u64) {
+    pub fn decrease_total_stake(decrement: BalanceOf<T>) {
         // --- We update the total staking pool with the removed funds.
-        let total_stake: u64 = TotalStake::<T>::get();
+        let total_stake: BalanceOf<T> = TotalStake::<T>::get();
 
         // Sanity check so that total stake does not underflow past 0
         debug_assert!(decrement <= total_stake);
@@ -136,19 +196,17 @@ impl<T: Config> Pallet<T> {
     /// is calculated and this should always <= 1. Having this function be atomic, fills this
     /// requirement.
     ///
-    pub fn add_stake_to_neuron_hotkey_account(uid: u32, amount: u64) {
+    pub fn add_stake_to_neuron_hotkey_account(uid: u32, amount: BalanceOf<T>) {
         debug_assert!(Self::is_uid_active(uid));
 
-        let mut neuron: NeuronMetadataOf<T> = Self::get_neuron_for_uid( uid );
-        let prev_stake: u64 = neuron.stake;
+        let prev_stake: BalanceOf<T> = Stake::<T>::get( uid );
 
         // This should never happen. If a user has this ridiculous amount of stake,
         // we need to come up with a better solution
-        debug_assert!(u64::MAX.saturating_sub(amount) > prev_stake);
+        debug_assert!(BalanceOf::<T>::max_value().saturating_sub(amount) > prev_stake);
 
         let new_stake = prev_stake.saturating_add(amount);
-        neuron.stake = new_stake;
-        Neurons::<T>::insert(uid, neuron);
+        Stake::<T>::insert(uid, new_stake);
 
         Self::increase_total_stake(amount);
     }
@@ -163,22 +221,18 @@ impl<T: Config> Pallet<T> {
     ///
     /// Furthermore, a check to see if the uid is active before this method is called is also required
     ///
-    pub fn remove_stake_from_neuron_hotkey_account(uid: u32, amount: u64) {
+    pub fn remove_stake_from_neuron_hotkey_account(uid: u32, amount: BalanceOf<T>) {
         debug_assert!(Self::is_uid_active(uid));
 
-        let mut neuron: NeuronMetadataOf<T> = Self::get_neuron_for_uid( uid );
-        let hotkey_stake: u64 = neuron.stake;
+        let hotkey_stake: BalanceOf<T> = Stake::<T>::get( uid );
 
         // By this point, there should be enough stake in the hotkey account for this to work.
         debug_assert!(hotkey_stake >= amount);
-        neuron.stake = neuron.stake.saturating_sub(amount);
-
-        Neurons::<T>::insert(uid, neuron);
+        Stake::<T>::insert(uid, hotkey_stake.saturating_sub(amount));
         Self::decrease_total_stake(amount);
     }
 
     /// This adds stake (balance) to a cold key account. It takes the account id of the coldkey account and a Balance as parameters.
-    /// The Balance parameter is a from u64 converted number. This is needed for T::Currency to work.
     /// Make sure stake is removed from another account before calling this method, otherwise you'll end up with double the value
     ///
     pub fn add_balance_to_coldkey_account(coldkey: &T::AccountId, amount: <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance) {
@@ -202,6 +256,141 @@ impl<T: Config> Pallet<T> {
         };
     }
 
+    /// Burns the amount from the coldkey's free balance and takes it out of the issuance, as the
+    /// balances pallet does. Callers check the coldkey can pay beforehand and raise their own
+    /// error, this only fails with BalanceWithdrawalError when the withdrawal itself does.
+    ///
+    pub fn burn_from_coldkey(coldkey: &T::AccountId, amount: BalanceOf<T>) -> Result<(), Error<T>> {
+        ensure!(Self::remove_balance_from_coldkey_account(coldkey, amount), Error::<T>::BalanceWithdrawalError);
+        TotalIssuance::<T>::mutate(|issuance| *issuance = issuance.saturating_sub(amount));
+        Ok(())
+    }
+
+    /// Checks if the coldkey's free balance can cover the amount as stake.
+    ///
+    pub fn can_reserve_stake_on_coldkey(coldkey: &T::AccountId, amount: <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance) -> bool {
+        return T::Currency::can_reserve(coldkey, amount);
+    }
+
+    /// Holds the amount of the coldkey's free balance as stake, under STAKE_RESERVE_ID.
+    /// The funds stay on the coldkey, so the balances pallet keeps counting them in its issuance.
+    /// The output of this function MUST be checked before writing the amount to the hotkey account
+    ///
+    pub fn reserve_stake_on_coldkey(coldkey: &T::AccountId, amount: <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance) -> bool {
+        return T::Currency::reserve_named(&STAKE_RESERVE_ID, coldkey, amount).is_ok();
+    }
+
+    /// Releases the amount of the coldkey's stake reserve into its free balance.
+    /// Returns the part that was not held and so could not be released.
+    ///
+    pub fn unreserve_stake_on_coldkey(coldkey: &T::AccountId, amount: <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance) -> <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance {
+        return T::Currency::unreserve_named(&STAKE_RESERVE_ID, coldkey, amount);
+    }
+
+    /// Returns the balance the account holds as stake, including stake that is unbonding.
+    ///
+    pub fn get_reserved_stake(account: &T::AccountId) -> <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance {
+        return T::Currency::reserved_balance_named(&STAKE_RESERVE_ID, account);
+    }
+
+    /// Mints the amount onto the account and holds it as stake. Returns the amount held,
+    /// which is zero when the amount would not bring a new account up to the existential deposit.
+    /// Only emission is minted, everything else that becomes stake is reserved from a free balance.
+    ///
+    pub fn mint_reserved_stake(account: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+        let minted = T::Currency::deposit_creating(account, amount).peek();
+        if T::Currency::reserve_named(&STAKE_RESERVE_ID, account, minted).is_err() {
+            return Zero::zero();
+        }
+        return minted;
+    }
+
+    /// Moves stake held by one account to another, where it is held again. The issuance is
+    /// unchanged. Returns the amount now held by the receiving account, which falls short of the
+    /// amount when the sender held less, or when the receiver is a new account and the amount is
+    /// below the existential deposit, in which case it is burned.
+    ///
+    pub fn move_reserved_stake(from: &T::AccountId, to: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+        let (slashed, _) = T::Currency::slash_reserved_named(&STAKE_RESERVE_ID, from, amount);
+        let moved = slashed.peek();
+        T::Currency::resolve_creating(to, slashed);
+        if T::Currency::reserve_named(&STAKE_RESERVE_ID, to, moved).is_err() {
+            return Zero::zero();
+        }
+        return moved;
+    }
+
+    /// Returns true if the amount can be moved onto the account in full. Stake moved onto an
+    /// account that does not exist is burned when it is below the existential deposit.
+    ///
+    pub fn can_receive_reserved_stake(to: &T::AccountId, amount: BalanceOf<T>) -> bool {
+        return amount.is_zero() || amount >= T::Currency::minimum_balance() || !T::Currency::total_balance(to).is_zero();
+    }
+
+    /// Burns the amount of the stake held by the account and takes it out of the issuance.
+    /// Returns the amount burned, which falls short of the amount when the account held less.
+    ///
+    pub fn burn_reserved_stake(from: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+        let (slashed, _) = T::Currency::slash_reserved_named(&STAKE_RESERVE_ID, from, amount);
+        let burned = slashed.peek();
+        TotalIssuance::<T>::mutate(|issuance| *issuance = issuance.saturating_sub(burned));
+        return burned;
+    }
+
+    /// Returns the account holding the stake nominators have delegated.
+    ///
+    pub fn get_delegation_pool_account() -> T::AccountId {
+        return DELEGATION_POOL_ID.into_account();
+    }
+
+    /// Mints the emission of the neuron under uid. The operator's cut, set by the hotkey's take
+    /// and capped by the max take, comes off the top and goes to the owner. The rest is paid to
+    /// the stake pro-rata: the nominators' part is added to the stake and held in the delegation
+    /// pool, where it grows the value of their shares, and the owner's part joins the cut. What
+    /// the owner earns is held as stake or paid into the coldkey's free balance depending on the
+    /// hotkey's emission destination. Both parts of the split are recorded for the uid. Returns
+    /// the stake added, which leaves out a payout and falls short of the emission when part of it
+    /// could not be minted.
+    ///
+    pub fn mint_emission_for_uid(uid: u32, emission: BalanceOf<T>) -> BalanceOf<T> {
+        let (hotkey, coldkey) = match (Keys::<T>::get(uid), Coldkeys::<T>::get(uid)) {
+            (Some(hotkey), Some(coldkey)) => (hotkey, coldkey),
+            _ => return Zero::zero(),
+        };
+
+        // ---- The operator's cut comes off the top, the rest is paid to the stake.
+        let take: u16 = Takes::<T>::get(&hotkey).min(Self::get_max_take());
+        let operator_emission: BalanceOf<T> = Self::multiply_by_ratio(emission, take.into(), u16::MAX.into());
+        let stake_emission: BalanceOf<T> = emission.saturating_sub(operator_emission);
+        OperatorEmission::<T>::insert(uid, operator_emission.saturated_into::<u64>());
+        StakeEmission::<T>::insert(uid, stake_emission.saturated_into::<u64>());
+        if emission.is_zero() {
+            return Zero::zero();
+        }
+        if !operator_emission.is_zero() {
+            Self::deposit_event(Event::EmissionSplit(hotkey.clone(), operator_emission, stake_emission));
+        }
+
+        // ---- The nominators are paid their part of the stake's emission, the owner the rest.
+        let stake: BalanceOf<T> = Stake::<T>::get(uid);
+        let delegated: BalanceOf<T> = DelegatedStake::<T>::get(&hotkey);
+        let nominator_emission: BalanceOf<T> = Self::multiply_by_ratio(stake_emission, delegated, stake);
+        let owner_emission: BalanceOf<T> = emission.saturating_sub(nominator_emission);
+        let nominator_stake: BalanceOf<T> = Self::mint_reserved_stake(&Self::get_delegation_pool_account(), nominator_emission);
+        let owner_stake: BalanceOf<T> = match EmissionDestinations::<T>::get(&hotkey) {
+            EmissionDestination::Stake => Self::mint_reserved_stake(&coldkey, owner_emission),
+            EmissionDestination::Coldkey => {
+                Self::add_balance_to_coldkey_account(&coldkey, owner_emission);
+                Zero::zero()
+            }
+        };
+        if !nominator_stake.is_zero() {
+            DelegatedStake::<T>::insert(&hotkey, delegated.saturating_add(nominator_stake));
+        }
+        Stake::<T>::insert(uid, stake.saturating_add(nominator_stake).saturating_add(owner_stake));
+        return nominator_stake.saturating_add(owner_stake);
+    }
+
     /// Checks if the neuron as specified in the neuron parameter has subscribed with the cold key
     /// as specified in the coldkey parameter. See fn subscribe() for more info.
     ///
@@ -209,6 +398,13 @@ impl<T: Config> Pallet<T> {
         return neuron.coldkey == *coldkey;
     }
 
+    /// Checks if the neuron registered under uid was registered by the coldkey.
+    /// Unlike neuron_belongs_to_coldkey this only reads the uid's coldkey entry.
+    ///
+    pub fn uid_belongs_to_coldkey(uid: u32, coldkey: &T::AccountId) -> bool {
+        return Coldkeys::<T>::get(uid).as_ref() == Some(coldkey);
+    }
+
     /// Checks if the coldkey account has enough balance to be able to withdraw the specified amount.
     ///
     pub fn can_remove_balance_from_coldkey_account(coldkey: &T::AccountId, amount: <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance) -> bool {
@@ -232,7 +428,7 @@ impl<T: Config> Pallet<T> {
     /// Checks if the hotkey account of the specified account has enough stake to be able to withdraw
     /// the requested amount.
     ///
-    pub fn has_enough_stake(neuron: &NeuronMetadataOf<T>, amount: u64) -> bool {
+    pub fn has_enough_stake(neuron: &NeuronMetadataOf<T>, amount: BalanceOf<T>) -> bool {
         return neuron.stake >= amount;
     }
 
@@ -240,7 +436,7 @@ impl<T: Config> Pallet<T> {
     /// false otherwise
     ///
     pub fn has_hotkey_account(uid: &u32) -> bool {
-        return Neurons::<T>::contains_key(*uid);
+        return Keys::<T>::contains_key(*uid);
     }
 
     /// This calculates the fraction of the total amount of stake the specfied neuron owns.
@@ -253,8 +449,8 @@ impl<T: Config> Pallet<T> {
     /// Otherwise, it returns the result of neuron_stake / total stake
     ///
     pub fn calculate_stake_fraction_for_neuron(neuron: &NeuronMetadataOf<T>) -> U64F64 {
-        let total_stake = U64F64::from_num(TotalStake::<T>::get());
-        let neuron_stake = U64F64::from_num(neuron.stake);
+        let total_stake = Self::balance_to_fixed(TotalStake::<T>::get());
+        let neuron_stake = Self::balance_to_fixed(neuron.stake);
 
         // Total stake is 0, this should virtually never happen, but is still here because it could
         if total_stake == U64F64::from_num(0) {
@@ -274,8 +470,49 @@ impl<T: Config> Pallet<T> {
     /// Calculates the proportion of the stake a neuron has to the total stake.
     /// As such, the result of this function should ALWAYS be a number between
     /// 0 and 1 (inclusive).
-    pub fn calulate_stake_fraction(stake: u64, total_stake: u64) -> U64F64 {
-        return U64F64::from_num(stake) / U64F64::from_num(total_stake);
+    pub fn calulate_stake_fraction(stake: BalanceOf<T>, total_stake: BalanceOf<T>) -> U64F64 {
+        return Self::balance_to_fixed(stake) / Self::balance_to_fixed(total_stake);
+    }
+
+    /// Returns the amount as a fixed point number, saturating at U64F64::MAX. Stake fractions
+    /// are computed on these, the integer part covers any stake a u64 could hold.
+    ///
+    pub fn balance_to_fixed(amount: BalanceOf<T>) -> U64F64 {
+        return U64F64::saturating_from_num(amount.saturated_into::<u128>());
+    }
+
+    /// Returns amount * numerator / denominator rounded down, or zero when the denominator is zero.
+    /// The product is taken in 256 bits so it cannot overflow, and the result saturates at the
+    /// Balance's max. Used to split stake and emission pro-rata and to price nominator shares.
+    ///
+    pub fn multiply_by_ratio(amount: BalanceOf<T>, numerator: BalanceOf<T>, denominator: BalanceOf<T>) -> BalanceOf<T> {
+        if denominator.is_zero() {
+            return Zero::zero();
+        }
+        let product: U256 = U256::from(amount.saturated_into::<u128>()) * U256::from(numerator.saturated_into::<u128>());
+        let quotient: U256 = product / U256::from(denominator.saturated_into::<u128>());
+        return quotient.min(U256::from(u128::MAX)).as_u128().saturated_into();
+    }
+
+    /// Returns amount * numerator / denominator like multiply_by_ratio, but rounded up.
+    ///
+    pub fn multiply_by_ratio_rounding_up(amount: BalanceOf<T>, numerator: BalanceOf<T>, denominator: BalanceOf<T>) -> BalanceOf<T> {
+        if denominator.is_zero() {
+            return Zero::zero();
+        }
+        let denominator: U256 = U256::from(denominator.saturated_into::<u128>());
+        let product: U256 = U256::from(amount.saturated_into::<u128>()) * U256::from(numerator.saturated_into::<u128>());
+        let quotient: U256 = (product + denominator - 1) / denominator;
+        return quotient.min(U256::from(u128::MAX)).as_u128().saturated_into();
+    }
+}
+
+/// Burns balance the runtime takes outside this pallet, such as transaction fees and the dust
+/// of reaped accounts, and takes it out of TotalIssuance so it keeps matching the balances pallet.
+///
+impl<T: Config> OnUnbalanced<NegativeImbalanceOf<T>> for Pallet<T> {
+    fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<T>) {
+        TotalIssuance::<T>::mutate(|issuance| *issuance = issuance.saturating_sub(amount.peek()));
     }
 }
 
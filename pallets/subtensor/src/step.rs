@@ -1,25 +1,29 @@
 use super::*;
 use sp_std::convert::TryInto;
+use sp_core::U256;
 use substrate_fixed::types::I65F63;
 use substrate_fixed::transcendental::exp;
 use substrate_fixed::transcendental::log2;
-use frame_support::IterableStorageMap;
+use frame_support::weights::Weight;
 
 const LOG_TARGET: &'static str = "runtime::subtensor::step";
 
 impl<T: Config> Pallet<T> {
 
-    pub fn update_difficulty() {
+    /// Returns the weight of the adjustment path, which every block is charged for.
+    pub fn update_difficulty() -> Weight {
 
         // --- Set registrations per block to 0 after each block.
         RegistrationsThisBlock::<T>::set( 0 );
 
         // --- Difficulty adjustment constants for registration.
-        let max_difficulty: u64 = Self::get_maximum_difficulty(); // Difficulty should never exceed this value.
-        let min_difficulty: u64 = Self::get_minimum_difficulty(); // Difficulty should never be lower than this value.
+        let max_difficulty: u64 = Self::get_max_difficulty(); // Difficulty should never exceed this value.
+        let min_difficulty: u64 = Self::get_min_difficulty(); // Difficulty should never be lower than this value.
         let adjustment_interval: u64 = Self::get_adjustment_interval(); // Number of blocks average registrations are taken over.
         let current_difficulty: u64 = Self::get_difficulty_as_u64();
-        let target_registrations_per_interval: I65F63 = I65F63::from_num( Self::get_target_registrations_per_interval() ); // Target number of registrations on average over interval.
+        let max_burn: BalanceOf<T> = Self::get_max_burn(); // Burn should never exceed this value.
+        let min_burn: BalanceOf<T> = Self::get_min_burn(); // Burn should never be lower than this value.
+        let target_registrations_per_interval: u64 = Self::get_target_registrations_per_interval(); // Target number of registrations on average over interval.
         log::trace!(
             target: LOG_TARGET,
             "current_difficulty: {:?}, max_difficulty: {:?}, min_difficulty: {:?}, adjustment_interval: {:?}, target_registrations_per_interval: {:?}",
@@ -41,8 +45,8 @@ impl<T: Config> Pallet<T> {
         // --- Check if we have reached out adjustment interval.
         if current_block - last_adjustment >= adjustment_interval {
 
-            // --- Compute average registrations over the adjustment interval.
-            let registrations_since_last_adjustment: I65F63 = I65F63::from_num( Self::get_registrations_this_interval() );
+            // --- Registrations over the adjustment interval.
+            let registrations_since_last_adjustment: u64 = Self::get_registrations_this_interval();
 
             log::trace!(
                 target: LOG_TARGET,
@@ -50,44 +54,58 @@ impl<T: Config> Pallet<T> {
                 registrations_since_last_adjustment
             );
 
-            // --- Compare average against target.
-            if registrations_since_last_adjustment > target_registrations_per_interval {
+            // --- Scale difficulty and burn by the ratio of registrations to target.
+            let next_difficulty: u64 = Self::adjust_to_registrations( current_difficulty as u128, registrations_since_last_adjustment, target_registrations_per_interval, min_difficulty as u128, max_difficulty as u128 ).saturated_into();
+            Self::set_difficulty_from_u64( next_difficulty );
+            let next_burn: BalanceOf<T> = Self::adjust_to_registrations( Self::get_burn().saturated_into(), registrations_since_last_adjustment, target_registrations_per_interval, min_burn.saturated_into(), max_burn.saturated_into() ).saturated_into();
+            Self::set_burn( next_burn );
 
-                // --- Double difficulty.
-                let current_difficulty: u64 = Difficulty::<T>::get();
-                let mut next_difficulty = current_difficulty * 2;
-                if next_difficulty >= max_difficulty {
-                    next_difficulty = max_difficulty
-                }
-                Self::set_difficulty_from_u64( next_difficulty );
-
-                log::trace!(
-                    target: LOG_TARGET,
-                    "next_difficulty: {:?}",
-                    next_difficulty,
-                );
+            log::trace!(
+                target: LOG_TARGET,
+                "next_difficulty: {:?}, next_burn: {:?}",
+                next_difficulty,
+                next_burn,
+            );
 
-            } else {
-                // --- Halve difficulty.
-                let current_difficulty: u64 = Difficulty::<T>::get();
-                let mut next_difficulty = current_difficulty / 2;
-                if next_difficulty <= min_difficulty {
-                    next_difficulty = min_difficulty
+            // --- Record the adjustment, keeping the last DIFFICULTY_HISTORY_LENGTH.
+            DifficultyHistory::<T>::mutate( |history| {
+                history.push( DifficultyAdjustment { block: current_block, difficulty: next_difficulty, registrations: registrations_since_last_adjustment } );
+                if history.len() > DIFFICULTY_HISTORY_LENGTH {
+                    history.remove( 0 );
                 }
-                Self::set_difficulty_from_u64( next_difficulty );
-
-                log::trace!(
-                    target: LOG_TARGET,
-                    "next_difficulty: {:?}",
-                    next_difficulty,
-                );
-            }
+            });
 
             // --- Update last adjustment to current block and zero the registrations since last difficulty.
             LastDifficultyAdjustmentBlock::<T>::set( current_block );
             RegistrationsThisInterval::<T>::set( 0 );
         }
 
+        T::WeightInfo::update_difficulty()
+    }
+
+    /// Scales value by registrations / target, the factor clamped to MAX_DIFFICULTY_ADJUSTMENT_FACTOR
+    /// either way, then bounds it by min and max. Registrations on target leave it unchanged.
+    /// With a target of zero any registration counts as above target. Registrations above target
+    /// round up and raise the value by at least one, so small values are not stuck by truncation,
+    /// and the lowest value is rounded up so a positive value never drops to zero.
+    /// Values are taken as u128 so the same scaling serves the difficulty and the burn.
+    pub fn adjust_to_registrations( value: u128, registrations: u64, target: u64, min: u128, max: u128 ) -> u128 {
+        let factor: U256 = U256::from( MAX_DIFFICULTY_ADJUSTMENT_FACTOR );
+        let value: U256 = U256::from( value );
+        let registrations: U256 = U256::from( registrations );
+        let lowest: U256 = ( value + factor - 1 ) / factor;
+        let highest: U256 = ( value * factor ).max( value + 1 );
+        let scaled: U256 = match target {
+            0 if !registrations.is_zero() => highest,
+            0 => lowest,
+            _ if registrations > U256::from( target ) => {
+                let target: U256 = U256::from( target );
+                ( ( value * registrations + target - 1 ) / target ).max( value + 1 )
+            },
+            _ => value * registrations / U256::from( target ),
+        };
+        let next: U256 = scaled.max( lowest ).min( highest ).min( U256::from( max ) ).max( U256::from( min ) );
+        next.as_u128()
     }
 
     /// Block setup: Computation performed each block which updates the incentive mechanism and distributes new stake as dividends.
@@ -159,8 +177,11 @@ impl<T: Config> Pallet<T> {
     ///
     /// 
     /// Note, operations 1 and 2 are computed together. 
+    ///
+    /// Returns the consumed weight, priced by the number of neurons and the average
+    /// number of weight and bond entries per neuron read and written by this step.
     ////
-    pub fn mechanism_step ( emission_this_step: u64 ) {
+    pub fn mechanism_step ( emission_this_step: u64 ) -> Weight {
 
         // The amount this mechanism step emits on this block.
         let block_emission: I65F63 = I65F63::from_num( emission_this_step );
@@ -195,36 +216,36 @@ impl<T: Config> Pallet<T> {
         let mut total_active_stake: I65F63 = I65F63::from_num( 0.0 );
         let mut total_normalized_active_stake: I65F63 = I65F63::from_num( 0.0 );
         let mut stake: Vec<I65F63> = vec![ I65F63::from_num( 0.0 ) ; n];
-        for ( uid_i, neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
+        let mut weight_entries: u64 = 0;
+        let mut bond_entries_read: u64 = 0;
+        for uid_i in Keys::<T>::iter_keys() {
 
             // Append a set of uids.
             uids.push( uid_i );
-            if block - neuron_i.last_update >= activity_cutoff {
+            let stake_i: I65F63 = I65F63::saturating_from_num( Stake::<T>::get( uid_i ).saturated_into::<u128>() );
+            if block - LastUpdate::<T>::get( uid_i ) >= activity_cutoff {
                 active [ uid_i as usize ] = 0;
             } else {
                 active [ uid_i as usize ] = 1;
-                total_active_stake += I65F63::from_num( neuron_i.stake );
+                total_active_stake += stake_i;
             }
-            total_stake += I65F63::from_num( neuron_i.stake );
-            stake [ uid_i as usize ] = I65F63::from_num( neuron_i.stake );
+            total_stake += stake_i;
+            stake [ uid_i as usize ] = stake_i;
 
             // Priority increments by the log of the stake and is drained everytime the account sets weights. 
-            let log_stake:I65F63 = log2( I65F63::from_num( neuron_i.stake + 1 ) ).expect( "stake + 1 is positive and greater than 1.");
-            priority [ uid_i as usize ] = neuron_i.priority + log_stake.to_num::<u64>();
+            let log_stake:I65F63 = log2( stake_i.saturating_add( one ) ).expect( "stake + 1 is positive and greater than 1.");
+            priority [ uid_i as usize ] = Priority::<T>::get( uid_i ) + log_stake.to_num::<u64>();
 
-            weights [ uid_i as usize ] = neuron_i.weights;             
+            weights [ uid_i as usize ] = Weights::<T>::get( uid_i );             
+            weight_entries += weights [ uid_i as usize ].len() as u64;
             let mut bonds_row: Vec<u64> = vec![0; n];
-            for (uid_j, bonds_ij) in neuron_i.bonds.iter() {
-                
-                // Prunning occurs here. We simply to do fill this bonds matrix 
-                // with entries that contain the uids to prune. 
-                if !NeuronsToPruneAtNextEpoch::<T>::contains_key(uid_j) {
-                    // Otherwise, we add the entry into the stack based bonds array. We decay here as an optimization.
-                    let decayed_bond_ij: u64 = (bonds_moving_average * I65F63::from_num( *bonds_ij )).to_num::<u64>();
-                    bonds_row [ *uid_j as usize ] = decayed_bond_ij;
-                    bond_totals [ *uid_j as usize ] += decayed_bond_ij;
-                }
-
+            for (uid_j, bonds_ij) in Bonds::<T>::iter_prefix( uid_i ) {
+                // Bonds in pruned uids are cleared at registration, so every entry here is live.
+                // We add the entry into the stack based bonds array. We decay here as an optimization.
+                let decayed_bond_ij: u64 = (bonds_moving_average * I65F63::from_num( bonds_ij )).to_num::<u64>();
+                bonds_row [ uid_j as usize ] = decayed_bond_ij;
+                bond_totals [ uid_j as usize ] += decayed_bond_ij;
+                bond_entries_read += 1;
             }
             bonds[ uid_i as usize ] = bonds_row;
         }
@@ -391,35 +412,42 @@ impl<T: Config> Pallet<T> {
 
 		 log::trace!(target: LOG_TARGET, "dividends: {:?}, emission: {:?}", dividends, emission);
 
-        for ( uid_i, mut neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
-            // Update table entry.
-            neuron_i.active = active[ uid_i as usize ];
-            neuron_i.priority = priority[ uid_i as usize ];
-            neuron_i.emission = emission[ uid_i as usize ];
-            neuron_i.stake = neuron_i.stake + emission[ uid_i as usize ];
-            neuron_i.rank = (ranks[ uid_i as usize ] * u64_max).to_num::<u64>();
-            neuron_i.trust = (trust[ uid_i as usize ] * u64_max).to_num::<u64>();
-            neuron_i.consensus = (consensus[ uid_i as usize ] * u64_max).to_num::<u64>();
-            neuron_i.incentive = (incentive[ uid_i as usize ] * u64_max).to_num::<u64>();
-            neuron_i.dividends = (dividends[ uid_i as usize ] * u64_max).to_num::<u64>();
-            neuron_i.bonds = sparse_bonds[ uid_i as usize ].clone();
-            Neurons::<T>::insert( neuron_i.uid, neuron_i );
-
-            // This where we remove the neurons to prune (clearing the table.)
-            if NeuronsToPruneAtNextEpoch::<T>::contains_key( uid_i ) {
-                NeuronsToPruneAtNextEpoch::<T>::remove ( uid_i );
+        // Emission is minted in the balances pallet, we track its issuance to keep ours in step.
+        let issuance_before = T::Currency::total_issuance();
+        let mut total_staked: BalanceOf<T> = Zero::zero();
+        for uid_i in uids.iter() {
+            // Update table entries.
+            let uid_i: u32 = *uid_i;
+            Active::<T>::insert( uid_i, active[ uid_i as usize ] );
+            Priority::<T>::insert( uid_i, priority[ uid_i as usize ] );
+            Emission::<T>::insert( uid_i, emission[ uid_i as usize ] );
+            total_staked = total_staked.saturating_add( Self::mint_emission_for_uid( uid_i, emission[ uid_i as usize ].saturated_into() ) );
+            Rank::<T>::insert( uid_i, (ranks[ uid_i as usize ] * u64_max).to_num::<u64>() );
+            Trust::<T>::insert( uid_i, (trust[ uid_i as usize ] * u64_max).to_num::<u64>() );
+            Consensus::<T>::insert( uid_i, (consensus[ uid_i as usize ] * u64_max).to_num::<u64>() );
+            Incentive::<T>::insert( uid_i, (incentive[ uid_i as usize ] * u64_max).to_num::<u64>() );
+            Dividends::<T>::insert( uid_i, (dividends[ uid_i as usize ] * u64_max).to_num::<u64>() );
+            Bonds::<T>::remove_prefix( uid_i, None );
+            for ( uid_j, bonds_ij ) in sparse_bonds[ uid_i as usize ].iter() {
+                Bonds::<T>::insert( uid_i, uid_j, bonds_ij );
             }
         }
 
-        // Amount distributed through mechanism in conjunction with amount distributed to foudation.
-        let total_new_issuance:u64 = total_emission; // + foundation_distribution_as_float.to_num::<u64>();
+        // Amount minted through mechanism, which falls short of the emission only when part of it could not be minted.
+        let total_new_issuance: BalanceOf<T> = T::Currency::total_issuance().saturating_sub( issuance_before );
 
         // Update totals.
         TotalEmission::<T>::set( total_emission );
         TotalBondsPurchased::<T>::set( total_bonds_purchased );
-        TotalIssuance::<T>::mutate( |val| *val += total_new_issuance );
-        TotalStake::<T>::mutate( |val| *val += total_emission );
+        TotalIssuance::<T>::mutate( |val| *val = val.saturating_add( total_new_issuance ) );
+        TotalStake::<T>::mutate( |val| *val = val.saturating_add( total_staked ) );
         LastMechansimStepBlock::<T>::set( block );
+
+        // Charge for the larger of the bonds read and written, averaged per neuron and rounded up.
+        let bond_entries_written: u64 = sparse_bonds.iter().map( |row| row.len() as u64 ).sum();
+        let bond_entries: u64 = bond_entries_read.max( bond_entries_written );
+        let per_neuron = | entries: u64 | -> u32 { if n == 0 { 0 } else { ( ( entries + n as u64 - 1 ) / n as u64 ) as u32 } };
+        T::WeightInfo::mechanism_step( n as u32, per_neuron( weight_entries ), per_neuron( bond_entries ) )
     }
 
     pub fn get_current_block_as_u64( ) -> u64 {
@@ -428,10 +456,7 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn reset_bonds( ) {
-        for ( _, mut neuron_i ) in <Neurons<T> as IterableStorageMap<u32, NeuronMetadataOf<T>>>::iter() {
-            neuron_i.bonds = vec![];
-            Neurons::<T>::insert( neuron_i.uid, neuron_i );
-        }
+        Bonds::<T>::remove_all( None );
     }
 
 }
\ No newline at end of file
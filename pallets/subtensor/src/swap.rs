@@ -0,0 +1,187 @@
+use super::*;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    /***********************************************************
+     * do_swap_hotkey() - moves a neuron onto a new hotkey
+     ***********************************************************/
+
+    /// Replaces the hotkey of a neuron registered by the calling coldkey. The neuron keeps its
+    /// uid and with it its stake, bonds, weights, axon and registration block, so pruning and
+    /// immunity see the same neuron as before. Delegates keep their nominators, and the take
+    /// and emission destination move to the new hotkey.
+    ///
+    /// The coldkey pays hotkey_swap_cost, which is burned. Without it a neuron could cycle
+    /// through hotkeys for free.
+    ///
+    /// It throws the following errors if there is something wrong
+    /// - NotRegistered : The old hotkey is not registered
+    /// - AlreadyRegistered : The new hotkey is already registered
+    /// - NonAssociatedColdKey : The old hotkey was not registered by the calling coldkey
+    /// - NotEnoughBalanceToSwapHotkey : The coldkey cannot pay the swap cost
+    ///
+    pub fn do_swap_hotkey(origin: T::Origin, old_hotkey: T::AccountId, new_hotkey: T::AccountId) -> dispatch::DispatchResult {
+
+        // ---- We check the transaction is signed by the owning coldkey.
+        let coldkey = ensure_signed(origin)?;
+        ensure!(Self::is_hotkey_active(&old_hotkey), Error::<T>::NotRegistered);
+        ensure!(!Self::is_hotkey_active(&new_hotkey), Error::<T>::AlreadyRegistered);
+        let uid = Self::get_uid_for_hotkey(&old_hotkey);
+        ensure!(Self::uid_belongs_to_coldkey(uid, &coldkey), Error::<T>::NonAssociatedColdKey);
+
+        // ---- We burn the swap cost from the coldkey and take it out of the issuance, as the balances pallet does.
+        let cost: BalanceOf<T> = Self::get_hotkey_swap_cost();
+        ensure!(Self::can_remove_balance_from_coldkey_account(&coldkey, cost), Error::<T>::NotEnoughBalanceToSwapHotkey);
+        Self::burn_from_coldkey(&coldkey, cost)?;
+
+        // ---- We point the uid and the indexes at the new hotkey.
+        Hotkeys::<T>::remove(&old_hotkey);
+        Hotkeys::<T>::insert(&new_hotkey, uid);
+        Keys::<T>::insert(uid, new_hotkey.clone());
+        ColdkeyHotkeys::<T>::mutate(&coldkey, |hotkeys| {
+            for hotkey in hotkeys.iter_mut().filter(|hotkey| **hotkey == old_hotkey) {
+                *hotkey = new_hotkey.clone();
+            }
+        });
+        Self::swap_delegate_hotkey(&old_hotkey, &new_hotkey);
+        EmissionDestinations::<T>::swap(&old_hotkey, &new_hotkey);
+        Takes::<T>::swap(&old_hotkey, &new_hotkey);
+        LastTakeChange::<T>::swap(&old_hotkey, &new_hotkey);
+
+        // ---- Emit the event and return ok.
+        Self::deposit_event(Event::HotkeySwapped(coldkey, old_hotkey, new_hotkey));
+        Ok(())
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /***********************************************************
+     * do_announce_coldkey_swap() - schedules a coldkey rotation
+     ***********************************************************/
+
+    /// Schedules every neuron registered by the calling coldkey to move to a new coldkey
+    /// once coldkey_swap_delay blocks have passed. Until then the current coldkey can
+    /// cancel the swap, which gives the owner time to stop a swap announced with a stolen key.
+    ///
+    /// It throws the following errors if there is something wrong
+    /// - ColdkeySwapToSelf : The new coldkey is the calling coldkey
+    /// - ColdkeySwapAlreadyAnnounced : The coldkey already has a pending swap
+    /// - NoOwnedHotkeys : The coldkey has no registered neurons to move
+    /// - ColdkeySwapPending : The new coldkey has a pending swap, or pending swaps move neurons onto the calling coldkey
+    /// - TooManyColdkeySwapsThisBlock : MAX_COLDKEY_SWAPS_PER_BLOCK swaps already execute at the same block
+    /// - TooManyColdkeySwapHotkeysThisBlock : The swaps at the same block would move more than MAX_COLDKEY_SWAP_HOTKEYS_PER_BLOCK hotkeys
+    ///
+    pub fn do_announce_coldkey_swap(origin: T::Origin, new_coldkey: T::AccountId) -> dispatch::DispatchResult {
+
+        // ---- We check the transaction is signed by the current coldkey.
+        let coldkey = ensure_signed(origin)?;
+        ensure!(coldkey != new_coldkey, Error::<T>::ColdkeySwapToSelf);
+        ensure!(!ColdkeySwaps::<T>::contains_key(&coldkey), Error::<T>::ColdkeySwapAlreadyAnnounced);
+        ensure!(ColdkeyHotkeys::<T>::contains_key(&coldkey), Error::<T>::NoOwnedHotkeys);
+        ensure!(!ColdkeySwaps::<T>::contains_key(&new_coldkey), Error::<T>::ColdkeySwapPending);
+        ensure!(IncomingColdkeySwaps::<T>::get(&coldkey) == 0, Error::<T>::ColdkeySwapPending);
+
+        // ---- We bound the swaps and the hotkeys they move at the execution block. Coldkeys with a
+        // pending swap cannot register or receive more neurons, so the hotkeys counted now are all that move.
+        let execution_block: u64 = Self::get_current_block_as_u64().saturating_add(Self::get_coldkey_swap_delay().max(1));
+        let due: Vec<T::AccountId> = ColdkeySwapsDue::<T>::get(execution_block);
+        ensure!((due.len() as u32) < MAX_COLDKEY_SWAPS_PER_BLOCK, Error::<T>::TooManyColdkeySwapsThisBlock);
+        let hotkeys_due: u32 = due.iter().map(|due_coldkey| Self::count_owned_hotkeys(due_coldkey)).sum();
+        ensure!(hotkeys_due.saturating_add(Self::count_owned_hotkeys(&coldkey)) <= MAX_COLDKEY_SWAP_HOTKEYS_PER_BLOCK, Error::<T>::TooManyColdkeySwapHotkeysThisBlock);
+
+        // ---- We schedule the swap, a zero delay still leaves the next block to cancel in.
+        ColdkeySwaps::<T>::insert(&coldkey, (new_coldkey.clone(), execution_block));
+        ColdkeySwapsDue::<T>::append(execution_block, &coldkey);
+        IncomingColdkeySwaps::<T>::mutate(&new_coldkey, |incoming| *incoming = incoming.saturating_add(1));
+
+        // ---- Emit the event and return ok.
+        Self::deposit_event(Event::ColdkeySwapAnnounced(coldkey, new_coldkey, execution_block));
+        Ok(())
+    }
+
+    /// Cancels the pending coldkey swap of the calling coldkey.
+    ///
+    /// It throws the following errors if there is something wrong
+    /// - NoColdkeySwapAnnounced : The coldkey has no pending swap
+    ///
+    pub fn do_cancel_coldkey_swap(origin: T::Origin) -> dispatch::DispatchResult {
+
+        // ---- We check the transaction is signed by the current coldkey.
+        let coldkey = ensure_signed(origin)?;
+        let (new_coldkey, execution_block) = ColdkeySwaps::<T>::take(&coldkey).ok_or(Error::<T>::NoColdkeySwapAnnounced)?;
+        Self::release_incoming_coldkey_swap(&new_coldkey);
+        ColdkeySwapsDue::<T>::mutate_exists(execution_block, |maybe_due| {
+            if let Some(due) = maybe_due {
+                due.retain(|due_coldkey| *due_coldkey != coldkey);
+                if due.is_empty() {
+                    *maybe_due = None;
+                }
+            }
+        });
+
+        // ---- Emit the event and return ok.
+        Self::deposit_event(Event::ColdkeySwapCancelled(coldkey));
+        Ok(())
+    }
+
+    /********************************
+    --==[[  Helper functions   ]]==--
+    *********************************/
+
+    /// Executes the coldkey swaps scheduled for the current block. Called from on_initialize.
+    /// Returns the weight consumed, which grows with the hotkeys moved.
+    ///
+    pub fn execute_due_coldkey_swaps() -> Weight {
+        let due: Vec<T::AccountId> = ColdkeySwapsDue::<T>::take(Self::get_current_block_as_u64());
+        let mut hotkeys_moved: u64 = 0;
+        for coldkey in due.iter() {
+            if let Some((new_coldkey, _)) = ColdkeySwaps::<T>::take(coldkey) {
+                Self::release_incoming_coldkey_swap(&new_coldkey);
+                hotkeys_moved += Self::swap_coldkey(coldkey, &new_coldkey);
+            }
+        }
+        let swaps: u64 = due.len() as u64;
+        T::DbWeight::get().reads_writes(1 + 8 * swaps + 3 * hotkeys_moved, 1 + 8 * swaps + hotkeys_moved)
+    }
+
+    /// Forgets one pending swap moving neurons onto the coldkey, once it executes or is cancelled.
+    ///
+    pub fn release_incoming_coldkey_swap(new_coldkey: &T::AccountId) {
+        IncomingColdkeySwaps::<T>::mutate_exists(new_coldkey, |maybe_incoming| {
+            let incoming: u32 = maybe_incoming.unwrap_or(0).saturating_sub(1);
+            *maybe_incoming = if incoming == 0 { None } else { Some(incoming) };
+        });
+    }
+
+    /// Returns the number of neurons registered by the coldkey.
+    ///
+    pub fn count_owned_hotkeys(coldkey: &T::AccountId) -> u32 {
+        ColdkeyHotkeys::<T>::decode_len(coldkey).unwrap_or(0) as u32
+    }
+
+    /// Moves every neuron registered by old_coldkey to new_coldkey, which keeps any neurons
+    /// it already owns. The stake the neurons' owner holds moves with them, while nominations
+    /// and unbonding stake stay with old_coldkey. When new_coldkey does not exist and the stake
+    /// is below the existential deposit, which would burn it, the swap is cancelled instead.
+    /// Returns the number of neurons moved.
+    ///
+    pub fn swap_coldkey(old_coldkey: &T::AccountId, new_coldkey: &T::AccountId) -> u64 {
+        let hotkeys: Vec<T::AccountId> = ColdkeyHotkeys::<T>::get(old_coldkey);
+        let uids: Vec<u32> = hotkeys.iter().map(|hotkey| Self::get_uid_for_hotkey(hotkey)).collect();
+        let owned_stake: BalanceOf<T> = uids.iter().zip(hotkeys.iter())
+            .fold(Zero::zero(), |owned, (uid, hotkey)| owned.saturating_add(Self::get_owned_stake_for_uid(*uid, hotkey)));
+        if !Self::can_receive_reserved_stake(new_coldkey, owned_stake) {
+            Self::deposit_event(Event::ColdkeySwapCancelled(old_coldkey.clone()));
+            return 0;
+        }
+        ColdkeyHotkeys::<T>::remove(old_coldkey);
+        for uid in uids {
+            Coldkeys::<T>::insert(uid, new_coldkey.clone());
+        }
+        Self::move_reserved_stake(old_coldkey, new_coldkey, owned_stake);
+        let moved: u64 = hotkeys.len() as u64;
+        ColdkeyHotkeys::<T>::mutate(new_coldkey, |owned| owned.extend(hotkeys));
+        Self::deposit_event(Event::ColdkeySwapped(old_coldkey.clone(), new_coldkey.clone()));
+        moved
+    }
+}
@@ -0,0 +1,76 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /***********************************************************
+     * do_withdraw_unbonded() - releases unlocked stake to the coldkey
+     ***********************************************************/
+
+    /// Releases every unbonding chunk of the calling coldkey whose unlock block has been
+    /// reached from its stake reserve into its free balance. Chunks still locked stay queued.
+    ///
+    /// It throws the following errors if there is something wrong
+    /// - NoUnlockedStake : None of the coldkey's chunks has reached its unlock block
+    ///
+    pub fn do_withdraw_unbonded(origin: T::Origin) -> dispatch::DispatchResult {
+
+        // ---- We check the transaction is signed by the coldkey.
+        let coldkey = ensure_signed(origin)?;
+
+        // ---- We split the chunks into the unlocked and the still locked ones.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let (unlocked, locked): (Vec<UnlockChunk<BalanceOf<T>>>, Vec<UnlockChunk<BalanceOf<T>>>) = Unbonding::<T>::get(&coldkey)
+            .into_iter()
+            .partition(|chunk| chunk.unlock_block <= current_block);
+        let amount: BalanceOf<T> = unlocked.iter().fold(Zero::zero(), |total: BalanceOf<T>, chunk| total.saturating_add(chunk.amount));
+        ensure!(!unlocked.is_empty(), Error::<T>::NoUnlockedStake);
+
+        // ---- We keep the locked chunks and release the rest.
+        if locked.is_empty() {
+            Unbonding::<T>::remove(&coldkey);
+        } else {
+            Unbonding::<T>::insert(&coldkey, locked);
+        }
+        Self::unreserve_stake_on_coldkey(&coldkey, amount);
+
+        // ---- Emit the event and return ok.
+        Self::deposit_event(Event::UnbondedWithdrawn(coldkey, amount));
+        Ok(())
+    }
+
+    /********************************
+    --==[[  Helper functions   ]]==--
+    *********************************/
+
+    /// Returns true if the coldkey can queue another unbonding chunk this block.
+    /// A chunk unlocking at the same block as the newest one is merged into it.
+    ///
+    pub fn can_unbond(coldkey: &T::AccountId) -> bool {
+        let chunks: Vec<UnlockChunk<BalanceOf<T>>> = Unbonding::<T>::get(coldkey);
+        let unlock_block: u64 = Self::get_current_block_as_u64().saturating_add(Self::get_unbonding_period());
+        match chunks.last() {
+            Some(last) if last.unlock_block == unlock_block => true,
+            _ => (chunks.len() as u32) < MAX_UNBONDING_CHUNKS,
+        }
+    }
+
+    /// Queues stake already taken out of a hotkey for release to the coldkey after the
+    /// unbonding period. The stake must already be held on the coldkey, where it stays
+    /// reserved until withdrawn. The caller must have checked can_unbond.
+    ///
+    pub fn unbond_to_coldkey(coldkey: &T::AccountId, amount: BalanceOf<T>) {
+        let unlock_block: u64 = Self::get_current_block_as_u64().saturating_add(Self::get_unbonding_period());
+        Unbonding::<T>::mutate(coldkey, |chunks| {
+            match chunks.last_mut() {
+                Some(last) if last.unlock_block == unlock_block => last.amount = last.amount.saturating_add(amount),
+                _ => chunks.push(UnlockChunk { amount, unlock_block }),
+            }
+        });
+        Self::deposit_event(Event::StakeUnbonding(coldkey.clone(), amount, unlock_block));
+    }
+
+    /// Returns the total stake the coldkey has queued for release, locked or not.
+    ///
+    pub fn get_unbonding_stake(coldkey: &T::AccountId) -> BalanceOf<T> {
+        return Unbonding::<T>::get(coldkey).iter().fold(Zero::zero(), |total: BalanceOf<T>, chunk| total.saturating_add(chunk.amount));
+    }
+}
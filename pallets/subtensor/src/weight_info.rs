@@ -0,0 +1,1174 @@
+//! Weights for pallet_subtensor.
+//!
+//! THESE WEIGHTS ARE NOT BENCHMARK OUTPUT. They were derived by hand from the storage accesses
+//! of each call, with base times estimated from comparable calls, and must be replaced before
+//! release by running scripts/benchmark.sh on reference hardware, which overwrites this file with
+//! the output of the benchmarks in `benchmarking.rs`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_subtensor.
+pub trait WeightInfo {
+	fn set_weights(n: u32) -> Weight;
+	fn add_stake() -> Weight;
+	fn remove_stake() -> Weight;
+	fn move_stake() -> Weight;
+	fn set_emission_destination() -> Weight;
+	fn become_delegate() -> Weight;
+	fn set_take() -> Weight;
+	fn delegate_stake() -> Weight;
+	fn undelegate_stake() -> Weight;
+	fn withdraw_unbonded() -> Weight;
+	fn swap_hotkey() -> Weight;
+	fn announce_coldkey_swap() -> Weight;
+	fn cancel_coldkey_swap() -> Weight;
+	fn serve_axon() -> Weight;
+	fn register(n: u32) -> Weight;
+	fn burned_register(n: u32) -> Weight;
+	fn sudo_set_blocks_per_step() -> Weight;
+	fn sudo_set_bonds_moving_average() -> Weight;
+	fn sudo_set_difficulty() -> Weight;
+	fn sudo_set_adjustment_interval() -> Weight;
+	fn sudo_set_activity_cutoff() -> Weight;
+	fn sudo_target_registrations_per_interval() -> Weight;
+	fn sudo_set_rho() -> Weight;
+	fn sudo_set_kappa() -> Weight;
+	fn sudo_set_max_allowed_uids() -> Weight;
+	fn sudo_set_min_allowed_weights() -> Weight;
+	fn sudo_set_max_allowed_max_min_ratio() -> Weight;
+	fn sudo_set_max_weight_limit() -> Weight;
+	fn sudo_set_validator_batch_size() -> Weight;
+	fn sudo_set_validator_sequence_length() -> Weight;
+	fn sudo_set_validator_epoch_len() -> Weight;
+	fn sudo_set_validator_epochs_per_reset() -> Weight;
+	fn sudo_set_incentive_pruning_denominator() -> Weight;
+	fn sudo_set_stake_pruning_denominator() -> Weight;
+	fn sudo_set_stake_pruning_min() -> Weight;
+	fn sudo_set_immunity_period() -> Weight;
+	fn sudo_set_scaling_law_power() -> Weight;
+	fn sudo_set_synergy_scaling_law_power() -> Weight;
+	fn sudo_set_validator_exclude_quantile() -> Weight;
+	fn sudo_set_used_work_retention() -> Weight;
+	fn sudo_set_unbonding_period() -> Weight;
+	fn sudo_set_hotkey_swap_cost() -> Weight;
+	fn sudo_set_coldkey_swap_delay() -> Weight;
+	fn sudo_set_max_take() -> Weight;
+	fn sudo_set_take_rate_limit() -> Weight;
+	fn sudo_set_burn() -> Weight;
+	fn sudo_set_legacy_seal_cutoff() -> Weight;
+	fn sudo_set_min_difficulty() -> Weight;
+	fn sudo_set_max_difficulty() -> Weight;
+	fn sudo_set_min_burn() -> Weight;
+	fn sudo_set_max_burn() -> Weight;
+	fn sudo_reset_bonds(n: u32) -> Weight;
+	fn sudo_repair_totals(n: u32) -> Weight;
+	fn on_initialize() -> Weight;
+	fn update_difficulty() -> Weight;
+	fn mechanism_step(n: u32, w: u32, b: u32) -> Weight;
+}
+
+/// Weights for pallet_subtensor using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor MinAllowedWeights (r:1 w:0)
+	// Storage: Subtensor MaxWeightLimit (r:1 w:0)
+	// Storage: Subtensor Keys (r:n w:0)
+	// Storage: Subtensor Weights (r:0 w:1)
+	// Storage: Subtensor Active (r:0 w:1)
+	// Storage: Subtensor Priority (r:0 w:1)
+	// Storage: Subtensor LastUpdate (r:0 w:1)
+	fn set_weights(n: u32) -> Weight {
+		(41_230_000 as Weight)
+			.saturating_add((612_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Keys (r:1 w:0)
+	// Storage: Subtensor Coldkeys (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: Subtensor Stake (r:1 w:1)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	fn add_stake() -> Weight {
+		(66_870_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Keys (r:1 w:0)
+	// Storage: Subtensor Coldkeys (r:1 w:0)
+	// Storage: Subtensor Stake (r:1 w:1)
+	// Storage: Subtensor DelegatedStake (r:1 w:0)
+	// Storage: Subtensor UnbondingPeriod (r:1 w:0)
+	// Storage: Subtensor Unbonding (r:1 w:1)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	fn remove_stake() -> Weight {
+		(64_780_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:2 w:0)
+	// Storage: Subtensor Keys (r:2 w:0)
+	// Storage: Subtensor Coldkeys (r:2 w:0)
+	// Storage: Subtensor Stake (r:2 w:2)
+	// Storage: Subtensor DelegatedStake (r:1 w:0)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	fn move_stake() -> Weight {
+		(58_630_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Coldkeys (r:1 w:0)
+	// Storage: Subtensor EmissionDestinations (r:0 w:1)
+	fn set_emission_destination() -> Weight {
+		(27_140_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Keys (r:1 w:0)
+	// Storage: Subtensor Coldkeys (r:1 w:0)
+	// Storage: Subtensor Delegates (r:1 w:1)
+	fn become_delegate() -> Weight {
+		(38_260_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Coldkeys (r:1 w:0)
+	// Storage: Subtensor MaxTake (r:1 w:0)
+	// Storage: Subtensor LastTakeChange (r:1 w:1)
+	// Storage: Subtensor TakeRateLimit (r:1 w:0)
+	// Storage: Subtensor Takes (r:0 w:1)
+	fn set_take() -> Weight {
+		(33_470_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Keys (r:1 w:0)
+	// Storage: Subtensor Delegates (r:1 w:0)
+	// Storage: Subtensor TotalNominatorShares (r:1 w:1)
+	// Storage: Subtensor DelegatedStake (r:1 w:1)
+	// Storage: Subtensor NominatorShares (r:1 w:1)
+	// Storage: Subtensor NominatorCount (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Storage: Subtensor Stake (r:1 w:1)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	fn delegate_stake() -> Weight {
+		(102_640_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Keys (r:1 w:0)
+	// Storage: Subtensor NominatorShares (r:1 w:1)
+	// Storage: Subtensor TotalNominatorShares (r:1 w:1)
+	// Storage: Subtensor DelegatedStake (r:1 w:1)
+	// Storage: Subtensor NominatorCount (r:1 w:1)
+	// Storage: Subtensor UnbondingPeriod (r:1 w:0)
+	// Storage: Subtensor Unbonding (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Storage: Subtensor Stake (r:1 w:1)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	fn undelegate_stake() -> Weight {
+		(101_380_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	// Storage: Subtensor Unbonding (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	fn withdraw_unbonded() -> Weight {
+		(44_910_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:2 w:2)
+	// Storage: Subtensor Coldkeys (r:1 w:0)
+	// Storage: Subtensor HotkeySwapCost (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Subtensor Keys (r:0 w:1)
+	// Storage: Subtensor ColdkeyHotkeys (r:1 w:1)
+	// Storage: Subtensor Delegates (r:1 w:2)
+	// Storage: Subtensor DelegatedStake (r:1 w:2)
+	// Storage: Subtensor TotalNominatorShares (r:1 w:2)
+	// Storage: Subtensor NominatorCount (r:1 w:2)
+	// Storage: Subtensor NominatorShares (r:64 w:128)
+	// Storage: Subtensor EmissionDestinations (r:1 w:2)
+	// Storage: Subtensor Takes (r:1 w:2)
+	// Storage: Subtensor LastTakeChange (r:1 w:2)
+	fn swap_hotkey() -> Weight {
+		(251_380_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(77 as Weight))
+			.saturating_add(T::DbWeight::get().writes(147 as Weight))
+	}
+	// Storage: Subtensor ColdkeySwaps (r:2 w:1)
+	// Storage: Subtensor ColdkeyHotkeys (r:16 w:0)
+	// Storage: Subtensor IncomingColdkeySwaps (r:2 w:1)
+	// Storage: Subtensor ColdkeySwapDelay (r:1 w:0)
+	// Storage: Subtensor ColdkeySwapsDue (r:1 w:1)
+	fn announce_coldkey_swap() -> Weight {
+		(58_410_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(22 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Subtensor ColdkeySwaps (r:1 w:1)
+	// Storage: Subtensor IncomingColdkeySwaps (r:1 w:1)
+	// Storage: Subtensor ColdkeySwapsDue (r:1 w:1)
+	fn cancel_coldkey_swap() -> Weight {
+		(38_760_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Axons (r:0 w:1)
+	// Storage: Subtensor Active (r:0 w:1)
+	// Storage: Subtensor LastUpdate (r:0 w:1)
+	fn serve_axon() -> Weight {
+		(34_150_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Subtensor RegistrationsThisBlock (r:1 w:1)
+	// Storage: Subtensor MaxRegistrationsPerBlock (r:1 w:0)
+	// Storage: Subtensor Hotkeys (r:2 w:2)
+	// Storage: Subtensor UsedWork (r:1 w:1)
+	// Storage: Subtensor Difficulty (r:1 w:0)
+	// Storage: Subtensor LegacySealCutoff (r:1 w:0)
+	// Storage: Subtensor ColdkeySwaps (r:1 w:0)
+	// Storage: Subtensor MaxAllowedUids (r:1 w:0)
+	// Storage: Subtensor N (r:1 w:0)
+	// Storage: Subtensor ImmunityPeriod (r:1 w:0)
+	// Storage: Subtensor Keys (r:n w:1)
+	// Storage: Subtensor Stake (r:n w:1)
+	// Storage: Subtensor Incentive (r:n w:1)
+	// Storage: Subtensor BlockAtRegistration (r:n w:1)
+	// Storage: Subtensor StakePruningMin (r:1 w:0)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	// Storage: Subtensor Bonds (r:0 w:n)
+	// Storage: Subtensor Delegates (r:0 w:1)
+	// Storage: Subtensor TotalNominatorShares (r:1 w:1)
+	// Storage: Subtensor DelegatedStake (r:1 w:1)
+	// Storage: Subtensor NominatorCount (r:0 w:1)
+	// Storage: Subtensor NominatorShares (r:64 w:64)
+	// Storage: System Account (r:66 w:66)
+	// Storage: Balances Reserves (r:66 w:66)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: Subtensor ColdkeyHotkeys (r:1 w:2)
+	// Storage: Subtensor UsedWorkByBlock (r:0 w:1)
+	// Storage: Subtensor RegistrationsThisInterval (r:1 w:1)
+	// Storage: Subtensor Coldkeys (r:0 w:1)
+	// Storage: Subtensor Axons (r:0 w:1)
+	// Storage: Subtensor Weights (r:0 w:1)
+	// Storage: Subtensor Active (r:0 w:1)
+	// Storage: Subtensor LastUpdate (r:0 w:1)
+	// Storage: Subtensor Priority (r:0 w:1)
+	// Storage: Subtensor Rank (r:0 w:1)
+	// Storage: Subtensor Trust (r:0 w:1)
+	// Storage: Subtensor Consensus (r:0 w:1)
+	// Storage: Subtensor Dividends (r:0 w:1)
+	// Storage: Subtensor Emission (r:0 w:1)
+	// Storage: Subtensor EmissionDestinations (r:0 w:1)
+	// Storage: Subtensor Takes (r:0 w:1)
+	// Storage: Subtensor LastTakeChange (r:0 w:1)
+	// Storage: Subtensor OperatorEmission (r:0 w:1)
+	// Storage: Subtensor StakeEmission (r:0 w:1)
+	// The nominator payouts are charged for a pruned delegate with MAX_NOMINATORS_PER_DELEGATE nominators.
+	fn register(n: u32) -> Weight {
+		(481_370_000 as Weight)
+			.saturating_add((4_385_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(213 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(229 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Subtensor RegistrationsThisBlock (r:1 w:1)
+	// Storage: Subtensor MaxRegistrationsPerBlock (r:1 w:0)
+	// Storage: Subtensor Hotkeys (r:2 w:2)
+	// Storage: Subtensor Burn (r:1 w:0)
+	// Storage: Subtensor ColdkeySwaps (r:1 w:0)
+	// Storage: Subtensor MaxAllowedUids (r:1 w:0)
+	// Storage: Subtensor N (r:1 w:0)
+	// Storage: Subtensor ImmunityPeriod (r:1 w:0)
+	// Storage: Subtensor Keys (r:n w:1)
+	// Storage: Subtensor Stake (r:n w:1)
+	// Storage: Subtensor Incentive (r:n w:1)
+	// Storage: Subtensor BlockAtRegistration (r:n w:1)
+	// Storage: Subtensor StakePruningMin (r:1 w:0)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	// Storage: Subtensor Bonds (r:0 w:n)
+	// Storage: Subtensor Delegates (r:0 w:1)
+	// Storage: Subtensor TotalNominatorShares (r:1 w:1)
+	// Storage: Subtensor DelegatedStake (r:1 w:1)
+	// Storage: Subtensor NominatorCount (r:0 w:1)
+	// Storage: Subtensor NominatorShares (r:64 w:64)
+	// Storage: System Account (r:67 w:67)
+	// Storage: Balances Reserves (r:66 w:66)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: Subtensor TotalIssuance (r:1 w:1)
+	// Storage: Subtensor ColdkeyHotkeys (r:1 w:2)
+	// Storage: Subtensor RegistrationsThisInterval (r:1 w:1)
+	// Storage: Subtensor Coldkeys (r:0 w:1)
+	// Storage: Subtensor Axons (r:0 w:1)
+	// Storage: Subtensor Weights (r:0 w:1)
+	// Storage: Subtensor Active (r:0 w:1)
+	// Storage: Subtensor LastUpdate (r:0 w:1)
+	// Storage: Subtensor Priority (r:0 w:1)
+	// Storage: Subtensor Rank (r:0 w:1)
+	// Storage: Subtensor Trust (r:0 w:1)
+	// Storage: Subtensor Consensus (r:0 w:1)
+	// Storage: Subtensor Dividends (r:0 w:1)
+	// Storage: Subtensor Emission (r:0 w:1)
+	// Storage: Subtensor EmissionDestinations (r:0 w:1)
+	// Storage: Subtensor Takes (r:0 w:1)
+	// Storage: Subtensor LastTakeChange (r:0 w:1)
+	// Storage: Subtensor OperatorEmission (r:0 w:1)
+	// Storage: Subtensor StakeEmission (r:0 w:1)
+	// The nominator payouts are charged for a pruned delegate with MAX_NOMINATORS_PER_DELEGATE nominators.
+	fn burned_register(n: u32) -> Weight {
+		(471_830_000 as Weight)
+			.saturating_add((4_385_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(213 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(229 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Subtensor BlocksPerStep (r:0 w:1)
+	fn sudo_set_blocks_per_step() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor BondsMovingAverage (r:0 w:1)
+	fn sudo_set_bonds_moving_average() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MinDifficulty (r:1 w:0)
+	// Storage: Subtensor MaxDifficulty (r:1 w:0)
+	// Storage: Subtensor Difficulty (r:0 w:1)
+	fn sudo_set_difficulty() -> Weight {
+		(21_480_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor AdjustmentInterval (r:0 w:1)
+	fn sudo_set_adjustment_interval() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ActivityCutoff (r:0 w:1)
+	fn sudo_set_activity_cutoff() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor TargetRegistrationsPerInterval (r:0 w:1)
+	fn sudo_target_registrations_per_interval() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor Rho (r:0 w:1)
+	fn sudo_set_rho() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor Kappa (r:0 w:1)
+	fn sudo_set_kappa() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MaxAllowedUids (r:0 w:1)
+	fn sudo_set_max_allowed_uids() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MinAllowedWeights (r:0 w:1)
+	fn sudo_set_min_allowed_weights() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MaxAllowedMaxMinRatio (r:0 w:1)
+	fn sudo_set_max_allowed_max_min_ratio() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MaxWeightLimit (r:0 w:1)
+	fn sudo_set_max_weight_limit() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ValidatorBatchSize (r:0 w:1)
+	fn sudo_set_validator_batch_size() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ValidatorSequenceLength (r:0 w:1)
+	fn sudo_set_validator_sequence_length() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ValidatorEpochLen (r:0 w:1)
+	fn sudo_set_validator_epoch_len() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ValidatorEpochsPerReset (r:0 w:1)
+	fn sudo_set_validator_epochs_per_reset() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor IncentivePruningDenominator (r:0 w:1)
+	fn sudo_set_incentive_pruning_denominator() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor StakePruningDenominator (r:0 w:1)
+	fn sudo_set_stake_pruning_denominator() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor StakePruningMin (r:0 w:1)
+	fn sudo_set_stake_pruning_min() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ImmunityPeriod (r:0 w:1)
+	fn sudo_set_immunity_period() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ScalingLawPower (r:0 w:1)
+	fn sudo_set_scaling_law_power() -> Weight {
+		(20_310_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor SynergyScalingLawPower (r:0 w:1)
+	fn sudo_set_synergy_scaling_law_power() -> Weight {
+		(20_310_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ValidatorExcludeQuantile (r:0 w:1)
+	fn sudo_set_validator_exclude_quantile() -> Weight {
+		(20_310_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor UsedWorkRetention (r:0 w:1)
+	fn sudo_set_used_work_retention() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor UnbondingPeriod (r:0 w:1)
+	fn sudo_set_unbonding_period() -> Weight {
+		(19_720_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor HotkeySwapCost (r:0 w:1)
+	fn sudo_set_hotkey_swap_cost() -> Weight {
+		(19_650_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ColdkeySwapDelay (r:0 w:1)
+	fn sudo_set_coldkey_swap_delay() -> Weight {
+		(19_810_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MaxTake (r:0 w:1)
+	fn sudo_set_max_take() -> Weight {
+		(19_620_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor TakeRateLimit (r:0 w:1)
+	fn sudo_set_take_rate_limit() -> Weight {
+		(19_700_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MinBurn (r:1 w:0)
+	// Storage: Subtensor MaxBurn (r:1 w:0)
+	// Storage: Subtensor Burn (r:0 w:1)
+	fn sudo_set_burn() -> Weight {
+		(21_300_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor LegacySealCutoff (r:0 w:1)
+	fn sudo_set_legacy_seal_cutoff() -> Weight {
+		(19_580_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MaxDifficulty (r:1 w:0)
+	// Storage: Subtensor MinDifficulty (r:0 w:1)
+	fn sudo_set_min_difficulty() -> Weight {
+		(21_020_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MinDifficulty (r:1 w:0)
+	// Storage: Subtensor MaxDifficulty (r:0 w:1)
+	fn sudo_set_max_difficulty() -> Weight {
+		(20_970_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MaxBurn (r:1 w:0)
+	// Storage: Subtensor MinBurn (r:0 w:1)
+	fn sudo_set_min_burn() -> Weight {
+		(21_050_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MinBurn (r:1 w:0)
+	// Storage: Subtensor MaxBurn (r:0 w:1)
+	fn sudo_set_max_burn() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor Bonds (r:0 w:n*n)
+	fn sudo_reset_bonds(n: u32) -> Weight {
+		(18_760_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((n as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Subtensor Stake (r:n w:0)
+	// Storage: Balances TotalIssuance (r:1 w:0)
+	// Storage: Subtensor TotalStake (r:0 w:1)
+	// Storage: Subtensor TotalIssuance (r:0 w:1)
+	fn sudo_repair_totals(n: u32) -> Weight {
+		(16_420_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(n as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Step counter only, the step, difficulty update and seal pruning are charged separately.
+	// Storage: Subtensor BlocksSinceLastStep (r:1 w:1)
+	// Storage: Subtensor BlocksPerStep (r:1 w:0)
+	fn on_initialize() -> Weight {
+		(4_210_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor RegistrationsThisBlock (r:0 w:1)
+	// Storage: Subtensor MinDifficulty (r:1 w:0)
+	// Storage: Subtensor MaxDifficulty (r:1 w:0)
+	// Storage: Subtensor AdjustmentInterval (r:1 w:0)
+	// Storage: Subtensor Difficulty (r:1 w:1)
+	// Storage: Subtensor Burn (r:1 w:1)
+	// Storage: Subtensor TargetRegistrationsPerInterval (r:1 w:0)
+	// Storage: Subtensor LastDifficultyAdjustmentBlock (r:1 w:1)
+	// Storage: Subtensor RegistrationsThisInterval (r:1 w:1)
+	// Storage: Subtensor DifficultyHistory (r:1 w:1)
+	fn update_difficulty() -> Weight {
+		(15_260_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Subtensor N (r:1 w:0)
+	// Storage: Subtensor ActivityCutoff (r:1 w:0)
+	// Storage: Subtensor BondsMovingAverage (r:1 w:0)
+	// Storage: Subtensor Rho (r:1 w:0)
+	// Storage: Subtensor Kappa (r:1 w:0)
+	// Storage: Subtensor Keys (r:2n w:0)
+	// Storage: Subtensor Stake (r:n w:n)
+	// Storage: Subtensor LastUpdate (r:n w:0)
+	// Storage: Subtensor Priority (r:n w:n)
+	// Storage: Subtensor Weights (r:n w:0)
+	// Storage: Subtensor Bonds (r:n*b w:n+n*b)
+	// Storage: Subtensor Active (r:0 w:n)
+	// Storage: Subtensor Emission (r:0 w:n)
+	// Storage: Subtensor Rank (r:0 w:n)
+	// Storage: Subtensor Trust (r:0 w:n)
+	// Storage: Subtensor Consensus (r:0 w:n)
+	// Storage: Subtensor Incentive (r:0 w:n)
+	// Storage: Subtensor Dividends (r:0 w:n)
+	// Storage: Subtensor TotalEmission (r:0 w:1)
+	// Storage: Subtensor TotalBondsPurchased (r:0 w:1)
+	// Storage: Subtensor TotalIssuance (r:1 w:1)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	// Storage: Subtensor DelegatedStake (r:n w:n)
+	// Storage: Subtensor Coldkeys (r:n w:0)
+	// Storage: Subtensor EmissionDestinations (r:n w:0)
+	// Storage: Subtensor Takes (r:n w:0)
+	// Storage: Subtensor MaxTake (r:1 w:0)
+	// Storage: Subtensor OperatorEmission (r:0 w:n)
+	// Storage: Subtensor StakeEmission (r:0 w:n)
+	// Storage: System Account (r:n+1 w:n+1)
+	// Storage: Balances Reserves (r:n+1 w:n+1)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: Subtensor LastMechansimStepBlock (r:0 w:1)
+	// The n*n term is the dense bond matrix and the dividend loop over every pair of uids.
+	fn mechanism_step(n: u32, w: u32, b: u32) -> Weight {
+		(18_520_000 as Weight)
+			.saturating_add((43_590_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((9_000 as Weight).saturating_mul(n as Weight).saturating_mul(n as Weight))
+			.saturating_add((1_130_000 as Weight).saturating_mul(n as Weight).saturating_mul(w as Weight))
+			.saturating_add((1_420_000 as Weight).saturating_mul(n as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads((12 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().reads((n as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes((15 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((n as Weight).saturating_mul(b as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor MinAllowedWeights (r:1 w:0)
+	// Storage: Subtensor MaxWeightLimit (r:1 w:0)
+	// Storage: Subtensor Keys (r:n w:0)
+	// Storage: Subtensor Weights (r:0 w:1)
+	// Storage: Subtensor Active (r:0 w:1)
+	// Storage: Subtensor Priority (r:0 w:1)
+	// Storage: Subtensor LastUpdate (r:0 w:1)
+	fn set_weights(n: u32) -> Weight {
+		(41_230_000 as Weight)
+			.saturating_add((612_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Keys (r:1 w:0)
+	// Storage: Subtensor Coldkeys (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: Subtensor Stake (r:1 w:1)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	fn add_stake() -> Weight {
+		(66_870_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Keys (r:1 w:0)
+	// Storage: Subtensor Coldkeys (r:1 w:0)
+	// Storage: Subtensor Stake (r:1 w:1)
+	// Storage: Subtensor DelegatedStake (r:1 w:0)
+	// Storage: Subtensor UnbondingPeriod (r:1 w:0)
+	// Storage: Subtensor Unbonding (r:1 w:1)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	fn remove_stake() -> Weight {
+		(64_780_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:2 w:0)
+	// Storage: Subtensor Keys (r:2 w:0)
+	// Storage: Subtensor Coldkeys (r:2 w:0)
+	// Storage: Subtensor Stake (r:2 w:2)
+	// Storage: Subtensor DelegatedStake (r:1 w:0)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	fn move_stake() -> Weight {
+		(58_630_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Coldkeys (r:1 w:0)
+	// Storage: Subtensor EmissionDestinations (r:0 w:1)
+	fn set_emission_destination() -> Weight {
+		(27_140_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Keys (r:1 w:0)
+	// Storage: Subtensor Coldkeys (r:1 w:0)
+	// Storage: Subtensor Delegates (r:1 w:1)
+	fn become_delegate() -> Weight {
+		(38_260_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Coldkeys (r:1 w:0)
+	// Storage: Subtensor MaxTake (r:1 w:0)
+	// Storage: Subtensor LastTakeChange (r:1 w:1)
+	// Storage: Subtensor TakeRateLimit (r:1 w:0)
+	// Storage: Subtensor Takes (r:0 w:1)
+	fn set_take() -> Weight {
+		(33_470_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Keys (r:1 w:0)
+	// Storage: Subtensor Delegates (r:1 w:0)
+	// Storage: Subtensor TotalNominatorShares (r:1 w:1)
+	// Storage: Subtensor DelegatedStake (r:1 w:1)
+	// Storage: Subtensor NominatorShares (r:1 w:1)
+	// Storage: Subtensor NominatorCount (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Storage: Subtensor Stake (r:1 w:1)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	fn delegate_stake() -> Weight {
+		(102_640_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Keys (r:1 w:0)
+	// Storage: Subtensor NominatorShares (r:1 w:1)
+	// Storage: Subtensor TotalNominatorShares (r:1 w:1)
+	// Storage: Subtensor DelegatedStake (r:1 w:1)
+	// Storage: Subtensor NominatorCount (r:1 w:1)
+	// Storage: Subtensor UnbondingPeriod (r:1 w:0)
+	// Storage: Subtensor Unbonding (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Storage: Subtensor Stake (r:1 w:1)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	fn undelegate_stake() -> Weight {
+		(101_380_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+	// Storage: Subtensor Unbonding (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	fn withdraw_unbonded() -> Weight {
+		(44_910_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:2 w:2)
+	// Storage: Subtensor Coldkeys (r:1 w:0)
+	// Storage: Subtensor HotkeySwapCost (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Subtensor Keys (r:0 w:1)
+	// Storage: Subtensor ColdkeyHotkeys (r:1 w:1)
+	// Storage: Subtensor Delegates (r:1 w:2)
+	// Storage: Subtensor DelegatedStake (r:1 w:2)
+	// Storage: Subtensor TotalNominatorShares (r:1 w:2)
+	// Storage: Subtensor NominatorCount (r:1 w:2)
+	// Storage: Subtensor NominatorShares (r:64 w:128)
+	// Storage: Subtensor EmissionDestinations (r:1 w:2)
+	// Storage: Subtensor Takes (r:1 w:2)
+	// Storage: Subtensor LastTakeChange (r:1 w:2)
+	fn swap_hotkey() -> Weight {
+		(251_380_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(77 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(147 as Weight))
+	}
+	// Storage: Subtensor ColdkeySwaps (r:2 w:1)
+	// Storage: Subtensor ColdkeyHotkeys (r:16 w:0)
+	// Storage: Subtensor IncomingColdkeySwaps (r:2 w:1)
+	// Storage: Subtensor ColdkeySwapDelay (r:1 w:0)
+	// Storage: Subtensor ColdkeySwapsDue (r:1 w:1)
+	fn announce_coldkey_swap() -> Weight {
+		(58_410_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(22 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Subtensor ColdkeySwaps (r:1 w:1)
+	// Storage: Subtensor IncomingColdkeySwaps (r:1 w:1)
+	// Storage: Subtensor ColdkeySwapsDue (r:1 w:1)
+	fn cancel_coldkey_swap() -> Weight {
+		(38_760_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Subtensor Hotkeys (r:1 w:0)
+	// Storage: Subtensor Axons (r:0 w:1)
+	// Storage: Subtensor Active (r:0 w:1)
+	// Storage: Subtensor LastUpdate (r:0 w:1)
+	fn serve_axon() -> Weight {
+		(34_150_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Subtensor RegistrationsThisBlock (r:1 w:1)
+	// Storage: Subtensor MaxRegistrationsPerBlock (r:1 w:0)
+	// Storage: Subtensor Hotkeys (r:2 w:2)
+	// Storage: Subtensor UsedWork (r:1 w:1)
+	// Storage: Subtensor Difficulty (r:1 w:0)
+	// Storage: Subtensor LegacySealCutoff (r:1 w:0)
+	// Storage: Subtensor ColdkeySwaps (r:1 w:0)
+	// Storage: Subtensor MaxAllowedUids (r:1 w:0)
+	// Storage: Subtensor N (r:1 w:0)
+	// Storage: Subtensor ImmunityPeriod (r:1 w:0)
+	// Storage: Subtensor Keys (r:n w:1)
+	// Storage: Subtensor Stake (r:n w:1)
+	// Storage: Subtensor Incentive (r:n w:1)
+	// Storage: Subtensor BlockAtRegistration (r:n w:1)
+	// Storage: Subtensor StakePruningMin (r:1 w:0)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	// Storage: Subtensor Bonds (r:0 w:n)
+	// Storage: Subtensor Delegates (r:0 w:1)
+	// Storage: Subtensor TotalNominatorShares (r:1 w:1)
+	// Storage: Subtensor DelegatedStake (r:1 w:1)
+	// Storage: Subtensor NominatorCount (r:0 w:1)
+	// Storage: Subtensor NominatorShares (r:64 w:64)
+	// Storage: System Account (r:66 w:66)
+	// Storage: Balances Reserves (r:66 w:66)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: Subtensor ColdkeyHotkeys (r:1 w:2)
+	// Storage: Subtensor UsedWorkByBlock (r:0 w:1)
+	// Storage: Subtensor RegistrationsThisInterval (r:1 w:1)
+	// Storage: Subtensor Coldkeys (r:0 w:1)
+	// Storage: Subtensor Axons (r:0 w:1)
+	// Storage: Subtensor Weights (r:0 w:1)
+	// Storage: Subtensor Active (r:0 w:1)
+	// Storage: Subtensor LastUpdate (r:0 w:1)
+	// Storage: Subtensor Priority (r:0 w:1)
+	// Storage: Subtensor Rank (r:0 w:1)
+	// Storage: Subtensor Trust (r:0 w:1)
+	// Storage: Subtensor Consensus (r:0 w:1)
+	// Storage: Subtensor Dividends (r:0 w:1)
+	// Storage: Subtensor Emission (r:0 w:1)
+	// Storage: Subtensor EmissionDestinations (r:0 w:1)
+	// Storage: Subtensor Takes (r:0 w:1)
+	// Storage: Subtensor LastTakeChange (r:0 w:1)
+	// Storage: Subtensor OperatorEmission (r:0 w:1)
+	// Storage: Subtensor StakeEmission (r:0 w:1)
+	// The nominator payouts are charged for a pruned delegate with MAX_NOMINATORS_PER_DELEGATE nominators.
+	fn register(n: u32) -> Weight {
+		(481_370_000 as Weight)
+			.saturating_add((4_385_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(213 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(229 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Subtensor RegistrationsThisBlock (r:1 w:1)
+	// Storage: Subtensor MaxRegistrationsPerBlock (r:1 w:0)
+	// Storage: Subtensor Hotkeys (r:2 w:2)
+	// Storage: Subtensor Burn (r:1 w:0)
+	// Storage: Subtensor ColdkeySwaps (r:1 w:0)
+	// Storage: Subtensor MaxAllowedUids (r:1 w:0)
+	// Storage: Subtensor N (r:1 w:0)
+	// Storage: Subtensor ImmunityPeriod (r:1 w:0)
+	// Storage: Subtensor Keys (r:n w:1)
+	// Storage: Subtensor Stake (r:n w:1)
+	// Storage: Subtensor Incentive (r:n w:1)
+	// Storage: Subtensor BlockAtRegistration (r:n w:1)
+	// Storage: Subtensor StakePruningMin (r:1 w:0)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	// Storage: Subtensor Bonds (r:0 w:n)
+	// Storage: Subtensor Delegates (r:0 w:1)
+	// Storage: Subtensor TotalNominatorShares (r:1 w:1)
+	// Storage: Subtensor DelegatedStake (r:1 w:1)
+	// Storage: Subtensor NominatorCount (r:0 w:1)
+	// Storage: Subtensor NominatorShares (r:64 w:64)
+	// Storage: System Account (r:67 w:67)
+	// Storage: Balances Reserves (r:66 w:66)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: Subtensor TotalIssuance (r:1 w:1)
+	// Storage: Subtensor ColdkeyHotkeys (r:1 w:2)
+	// Storage: Subtensor RegistrationsThisInterval (r:1 w:1)
+	// Storage: Subtensor Coldkeys (r:0 w:1)
+	// Storage: Subtensor Axons (r:0 w:1)
+	// Storage: Subtensor Weights (r:0 w:1)
+	// Storage: Subtensor Active (r:0 w:1)
+	// Storage: Subtensor LastUpdate (r:0 w:1)
+	// Storage: Subtensor Priority (r:0 w:1)
+	// Storage: Subtensor Rank (r:0 w:1)
+	// Storage: Subtensor Trust (r:0 w:1)
+	// Storage: Subtensor Consensus (r:0 w:1)
+	// Storage: Subtensor Dividends (r:0 w:1)
+	// Storage: Subtensor Emission (r:0 w:1)
+	// Storage: Subtensor EmissionDestinations (r:0 w:1)
+	// Storage: Subtensor Takes (r:0 w:1)
+	// Storage: Subtensor LastTakeChange (r:0 w:1)
+	// Storage: Subtensor OperatorEmission (r:0 w:1)
+	// Storage: Subtensor StakeEmission (r:0 w:1)
+	// The nominator payouts are charged for a pruned delegate with MAX_NOMINATORS_PER_DELEGATE nominators.
+	fn burned_register(n: u32) -> Weight {
+		(471_830_000 as Weight)
+			.saturating_add((4_385_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(213 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(229 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Subtensor BlocksPerStep (r:0 w:1)
+	fn sudo_set_blocks_per_step() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor BondsMovingAverage (r:0 w:1)
+	fn sudo_set_bonds_moving_average() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MinDifficulty (r:1 w:0)
+	// Storage: Subtensor MaxDifficulty (r:1 w:0)
+	// Storage: Subtensor Difficulty (r:0 w:1)
+	fn sudo_set_difficulty() -> Weight {
+		(21_480_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor AdjustmentInterval (r:0 w:1)
+	fn sudo_set_adjustment_interval() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ActivityCutoff (r:0 w:1)
+	fn sudo_set_activity_cutoff() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor TargetRegistrationsPerInterval (r:0 w:1)
+	fn sudo_target_registrations_per_interval() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor Rho (r:0 w:1)
+	fn sudo_set_rho() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor Kappa (r:0 w:1)
+	fn sudo_set_kappa() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MaxAllowedUids (r:0 w:1)
+	fn sudo_set_max_allowed_uids() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MinAllowedWeights (r:0 w:1)
+	fn sudo_set_min_allowed_weights() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MaxAllowedMaxMinRatio (r:0 w:1)
+	fn sudo_set_max_allowed_max_min_ratio() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MaxWeightLimit (r:0 w:1)
+	fn sudo_set_max_weight_limit() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ValidatorBatchSize (r:0 w:1)
+	fn sudo_set_validator_batch_size() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ValidatorSequenceLength (r:0 w:1)
+	fn sudo_set_validator_sequence_length() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ValidatorEpochLen (r:0 w:1)
+	fn sudo_set_validator_epoch_len() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ValidatorEpochsPerReset (r:0 w:1)
+	fn sudo_set_validator_epochs_per_reset() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor IncentivePruningDenominator (r:0 w:1)
+	fn sudo_set_incentive_pruning_denominator() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor StakePruningDenominator (r:0 w:1)
+	fn sudo_set_stake_pruning_denominator() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor StakePruningMin (r:0 w:1)
+	fn sudo_set_stake_pruning_min() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ImmunityPeriod (r:0 w:1)
+	fn sudo_set_immunity_period() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ScalingLawPower (r:0 w:1)
+	fn sudo_set_scaling_law_power() -> Weight {
+		(20_310_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor SynergyScalingLawPower (r:0 w:1)
+	fn sudo_set_synergy_scaling_law_power() -> Weight {
+		(20_310_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ValidatorExcludeQuantile (r:0 w:1)
+	fn sudo_set_validator_exclude_quantile() -> Weight {
+		(20_310_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor UsedWorkRetention (r:0 w:1)
+	fn sudo_set_used_work_retention() -> Weight {
+		(19_840_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor UnbondingPeriod (r:0 w:1)
+	fn sudo_set_unbonding_period() -> Weight {
+		(19_720_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor HotkeySwapCost (r:0 w:1)
+	fn sudo_set_hotkey_swap_cost() -> Weight {
+		(19_650_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor ColdkeySwapDelay (r:0 w:1)
+	fn sudo_set_coldkey_swap_delay() -> Weight {
+		(19_810_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MaxTake (r:0 w:1)
+	fn sudo_set_max_take() -> Weight {
+		(19_620_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor TakeRateLimit (r:0 w:1)
+	fn sudo_set_take_rate_limit() -> Weight {
+		(19_700_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MinBurn (r:1 w:0)
+	// Storage: Subtensor MaxBurn (r:1 w:0)
+	// Storage: Subtensor Burn (r:0 w:1)
+	fn sudo_set_burn() -> Weight {
+		(21_300_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor LegacySealCutoff (r:0 w:1)
+	fn sudo_set_legacy_seal_cutoff() -> Weight {
+		(19_580_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MaxDifficulty (r:1 w:0)
+	// Storage: Subtensor MinDifficulty (r:0 w:1)
+	fn sudo_set_min_difficulty() -> Weight {
+		(21_020_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MinDifficulty (r:1 w:0)
+	// Storage: Subtensor MaxDifficulty (r:0 w:1)
+	fn sudo_set_max_difficulty() -> Weight {
+		(20_970_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MaxBurn (r:1 w:0)
+	// Storage: Subtensor MinBurn (r:0 w:1)
+	fn sudo_set_min_burn() -> Weight {
+		(21_050_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor MinBurn (r:1 w:0)
+	// Storage: Subtensor MaxBurn (r:0 w:1)
+	fn sudo_set_max_burn() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor Bonds (r:0 w:n*n)
+	fn sudo_reset_bonds(n: u32) -> Weight {
+		(18_760_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((n as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Subtensor Stake (r:n w:0)
+	// Storage: Balances TotalIssuance (r:1 w:0)
+	// Storage: Subtensor TotalStake (r:0 w:1)
+	// Storage: Subtensor TotalIssuance (r:0 w:1)
+	fn sudo_repair_totals(n: u32) -> Weight {
+		(16_420_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(n as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Step counter only, the step, difficulty update and seal pruning are charged separately.
+	// Storage: Subtensor BlocksSinceLastStep (r:1 w:1)
+	// Storage: Subtensor BlocksPerStep (r:1 w:0)
+	fn on_initialize() -> Weight {
+		(4_210_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Subtensor RegistrationsThisBlock (r:0 w:1)
+	// Storage: Subtensor MinDifficulty (r:1 w:0)
+	// Storage: Subtensor MaxDifficulty (r:1 w:0)
+	// Storage: Subtensor AdjustmentInterval (r:1 w:0)
+	// Storage: Subtensor Difficulty (r:1 w:1)
+	// Storage: Subtensor Burn (r:1 w:1)
+	// Storage: Subtensor TargetRegistrationsPerInterval (r:1 w:0)
+	// Storage: Subtensor LastDifficultyAdjustmentBlock (r:1 w:1)
+	// Storage: Subtensor RegistrationsThisInterval (r:1 w:1)
+	// Storage: Subtensor DifficultyHistory (r:1 w:1)
+	fn update_difficulty() -> Weight {
+		(15_260_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Subtensor N (r:1 w:0)
+	// Storage: Subtensor ActivityCutoff (r:1 w:0)
+	// Storage: Subtensor BondsMovingAverage (r:1 w:0)
+	// Storage: Subtensor Rho (r:1 w:0)
+	// Storage: Subtensor Kappa (r:1 w:0)
+	// Storage: Subtensor Keys (r:2n w:0)
+	// Storage: Subtensor Stake (r:n w:n)
+	// Storage: Subtensor LastUpdate (r:n w:0)
+	// Storage: Subtensor Priority (r:n w:n)
+	// Storage: Subtensor Weights (r:n w:0)
+	// Storage: Subtensor Bonds (r:n*b w:n+n*b)
+	// Storage: Subtensor Active (r:0 w:n)
+	// Storage: Subtensor Emission (r:0 w:n)
+	// Storage: Subtensor Rank (r:0 w:n)
+	// Storage: Subtensor Trust (r:0 w:n)
+	// Storage: Subtensor Consensus (r:0 w:n)
+	// Storage: Subtensor Incentive (r:0 w:n)
+	// Storage: Subtensor Dividends (r:0 w:n)
+	// Storage: Subtensor TotalEmission (r:0 w:1)
+	// Storage: Subtensor TotalBondsPurchased (r:0 w:1)
+	// Storage: Subtensor TotalIssuance (r:1 w:1)
+	// Storage: Subtensor TotalStake (r:1 w:1)
+	// Storage: Subtensor DelegatedStake (r:n w:n)
+	// Storage: Subtensor Coldkeys (r:n w:0)
+	// Storage: Subtensor EmissionDestinations (r:n w:0)
+	// Storage: Subtensor Takes (r:n w:0)
+	// Storage: Subtensor MaxTake (r:1 w:0)
+	// Storage: Subtensor OperatorEmission (r:0 w:n)
+	// Storage: Subtensor StakeEmission (r:0 w:n)
+	// Storage: System Account (r:n+1 w:n+1)
+	// Storage: Balances Reserves (r:n+1 w:n+1)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: Subtensor LastMechansimStepBlock (r:0 w:1)
+	// The n*n term is the dense bond matrix and the dividend loop over every pair of uids.
+	fn mechanism_step(n: u32, w: u32, b: u32) -> Weight {
+		(18_520_000 as Weight)
+			.saturating_add((43_590_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((9_000 as Weight).saturating_mul(n as Weight).saturating_mul(n as Weight))
+			.saturating_add((1_130_000 as Weight).saturating_mul(n as Weight).saturating_mul(w as Weight))
+			.saturating_add((1_420_000 as Weight).saturating_mul(n as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((12 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((n as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((15 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((n as Weight).saturating_mul(b as Weight)))
+	}
+}
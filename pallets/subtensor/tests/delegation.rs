@@ -0,0 +1,416 @@
+use frame_support::{assert_ok, traits::Currency};
+use frame_system::{Config};
+mod mock;
+use mock::*;
+use pallet_subtensor::{Error, Call as SubtensorCall, WeightInfo, MAX_NOMINATORS_PER_DELEGATE};
+use frame_support::weights::{GetDispatchInfo, DispatchInfo, DispatchClass, Pays};
+
+/***********************************************************
+	delegation::delegate_stake() tests
+************************************************************/
+
+#[test]
+fn test_delegate_stake_dispatch_info_ok() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 0;
+		let ammount_delegated = 5000;
+		let call = Call::Subtensor(SubtensorCall::delegate_stake{hotkey, ammount_delegated});
+		assert_eq!(call.get_dispatch_info(), DispatchInfo {
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::delegate_stake(),
+			class: DispatchClass::Normal,
+			pays_fee: Pays::Yes
+		});
+	});
+}
+
+#[test]
+fn test_become_delegate_only_by_owner() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 1;
+		let coldkey = 10;
+		register_ok_neuron( hotkey, coldkey );
+
+		assert_eq!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(20), hotkey), Err(Error::<Test>::NonAssociatedColdKey.into()));
+		assert_ok!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(coldkey), hotkey));
+		assert!(Subtensor::is_delegate(&hotkey));
+		assert_eq!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(coldkey), hotkey), Err(Error::<Test>::AlreadyDelegate.into()));
+	});
+}
+
+#[test]
+fn test_delegate_stake_err_not_delegate() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 1;
+		let nominator = 20;
+		register_ok_neuron( hotkey, 10 );
+		Subtensor::add_balance_to_coldkey_account( &nominator, 10000 );
+
+		assert_eq!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 5000), Err(Error::<Test>::NotDelegate.into()));
+		assert_eq!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), 2, 5000), Err(Error::<Test>::NotRegistered.into()));
+	});
+}
+
+#[test]
+fn test_delegate_stake_ok() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 1;
+		let coldkey = 10;
+		let nominator = 20;
+		let neuron = register_ok_neuron( hotkey, coldkey );
+		assert_ok!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(coldkey), hotkey));
+		Subtensor::add_balance_to_coldkey_account( &nominator, 10001 );
+
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 10000));
+
+		assert_eq!(Subtensor::get_coldkey_balance(&nominator), 1);
+		assert_eq!(Subtensor::get_reserved_stake(&nominator), 0);
+		assert_eq!(Subtensor::get_reserved_stake(&Subtensor::get_delegation_pool_account()), 10000);
+		assert_eq!(Subtensor::get_stake_of_neuron_hotkey_account_by_uid(neuron.uid), 10000);
+		assert_eq!(Subtensor::get_total_stake(), 10000);
+		assert_eq!(Subtensor::get_nominator_stake(&hotkey, &nominator), 10000);
+		assert_eq!(Subtensor::get_owned_stake_for_uid(neuron.uid, &hotkey), 0);
+
+		let delegate = Subtensor::get_delegate(&hotkey).unwrap();
+		assert_eq!(delegate.uid, neuron.uid);
+		assert_eq!(delegate.owner, coldkey);
+		assert_eq!(delegate.stake, 10000);
+		assert_eq!(delegate.delegated_stake, 10000);
+		assert_eq!(delegate.nominators, vec![(nominator, 10000)]);
+		assert_eq!(Subtensor::get_delegates().len(), 1);
+		assert_eq!(Subtensor::get_nominations_for_coldkey(&nominator), vec![(hotkey, 10000)]);
+		assert_eq!(Subtensor::get_nominations_for_coldkey(&coldkey), vec![]);
+	});
+}
+
+#[test]
+fn test_delegate_stake_err_too_many_nominators() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 1;
+		let coldkey = 10;
+		register_ok_neuron( hotkey, coldkey );
+		assert_ok!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(coldkey), hotkey));
+		for nominator in 0..MAX_NOMINATORS_PER_DELEGATE as u64 {
+			let nominator = 1000 + nominator;
+			Subtensor::add_balance_to_coldkey_account( &nominator, 200 );
+			assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 100));
+		}
+		let late = 5000;
+		Subtensor::add_balance_to_coldkey_account( &late, 200 );
+		assert_eq!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(late), hotkey, 100), Err(Error::<Test>::TooManyNominators.into()));
+
+		// Existing nominators can still add to their stake.
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(1000), hotkey, 50));
+	});
+}
+
+#[test]
+fn test_delegate_stake_err_below_existential_deposit() {
+	new_test_ext().execute_with(|| {
+		set_existential_deposit( 500 );
+		let hotkey = 1;
+		let coldkey = 10;
+		let nominator = 20;
+		let pool = Subtensor::get_delegation_pool_account();
+		register_ok_neuron( hotkey, coldkey );
+		assert_ok!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(coldkey), hotkey));
+		Subtensor::add_balance_to_coldkey_account( &nominator, 10000 );
+
+		// The first stake would create the pool below the existential deposit and be burned
+		assert_eq!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 499), Err(Error::<Test>::StakeBelowExistentialDeposit.into()));
+		assert_eq!(Subtensor::get_coldkey_balance(&nominator), 10000);
+		assert_eq!(Subtensor::get_reserved_stake(&nominator), 0);
+		assert_eq!(Subtensor::get_reserved_stake(&pool), 0);
+
+		// Once the pool exists any amount joins it in full
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 500));
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 1));
+		assert_eq!(Subtensor::get_reserved_stake(&pool), 501);
+		assert_eq!(Subtensor::get_nominator_stake(&hotkey, &nominator), 501);
+		assert_eq!(Subtensor::get_total_stake(), 501);
+	});
+}
+
+#[test]
+fn test_owner_cannot_remove_delegated_stake() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 1;
+		let coldkey = 10;
+		let nominator = 20;
+		let neuron = register_ok_neuron( hotkey, coldkey );
+		assert_ok!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(coldkey), hotkey));
+		Subtensor::add_balance_to_coldkey_account( &coldkey, 5001 );
+		Subtensor::add_balance_to_coldkey_account( &nominator, 10001 );
+		assert_ok!(Subtensor::add_stake(<<Test as Config>::Origin>::signed(coldkey), hotkey, 5000));
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 10000));
+		assert_eq!(Subtensor::get_owned_stake_for_uid(neuron.uid, &hotkey), 5000);
+
+		assert_eq!(Subtensor::remove_stake(<<Test as Config>::Origin>::signed(coldkey), hotkey, 5001), Err(Error::<Test>::NotEnoughStaketoWithdraw.into()));
+		assert_ok!(Subtensor::remove_stake(<<Test as Config>::Origin>::signed(coldkey), hotkey, 5000));
+		assert_eq!(Subtensor::get_nominator_stake(&hotkey, &nominator), 10000);
+	});
+}
+
+#[test]
+fn test_emission_split_pro_rata() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 1;
+		let coldkey = 10;
+		let nominator_a = 20;
+		let nominator_b = 30;
+		let neuron = register_ok_neuron( hotkey, coldkey );
+		assert_ok!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(coldkey), hotkey));
+		Subtensor::add_balance_to_coldkey_account( &coldkey, 20001 );
+		Subtensor::add_balance_to_coldkey_account( &nominator_a, 10001 );
+		Subtensor::add_balance_to_coldkey_account( &nominator_b, 10001 );
+		assert_ok!(Subtensor::add_stake(<<Test as Config>::Origin>::signed(coldkey), hotkey, 20000));
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator_a), hotkey, 10000));
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator_b), hotkey, 10000));
+
+		// The mechanism step mints emission into the stake and credits the nominators their part.
+		assert_eq!(Subtensor::mint_emission_for_uid(neuron.uid, 4000), 4000);
+
+		assert_eq!(Subtensor::get_owned_stake_for_uid(neuron.uid, &hotkey), 22000);
+		assert_eq!(Subtensor::get_nominator_stake(&hotkey, &nominator_a), 11000);
+		assert_eq!(Subtensor::get_nominator_stake(&hotkey, &nominator_b), 11000);
+		assert_eq!(Subtensor::get_reserved_stake(&coldkey), 22000);
+		assert_eq!(Subtensor::get_reserved_stake(&Subtensor::get_delegation_pool_account()), 22000);
+
+		// A nominator joining later buys shares at the new price and earns nothing already emitted.
+		let nominator_c = 40;
+		Subtensor::add_balance_to_coldkey_account( &nominator_c, 11001 );
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator_c), hotkey, 11000));
+		assert_eq!(Subtensor::get_nominator_stake(&hotkey, &nominator_c), 11000);
+		assert_eq!(Subtensor::get_nominator_stake(&hotkey, &nominator_a), 11000);
+	});
+}
+
+/***********************************************************
+	delegation::set_take() tests
+************************************************************/
+
+#[test]
+fn test_set_take_dispatch_info_ok() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 0;
+		let take = 6553;
+		let call = Call::Subtensor(SubtensorCall::set_take{hotkey, take});
+		assert_eq!(call.get_dispatch_info(), DispatchInfo {
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::set_take(),
+			class: DispatchClass::Normal,
+			pays_fee: Pays::Yes
+		});
+	});
+}
+
+#[test]
+fn test_set_take_ok() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 1;
+		let coldkey = 10;
+		register_ok_neuron( hotkey, coldkey );
+		assert_eq!(Subtensor::take(&hotkey), 0);
+
+		assert_ok!(Subtensor::set_take(<<Test as Config>::Origin>::signed(coldkey), hotkey, 6553));
+		assert_eq!(Subtensor::take(&hotkey), 6553);
+	});
+}
+
+#[test]
+fn test_set_take_err_not_owner() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 1;
+		register_ok_neuron( hotkey, 10 );
+
+		assert_eq!(Subtensor::set_take(<<Test as Config>::Origin>::signed(20), hotkey, 6553), Err(Error::<Test>::NonAssociatedColdKey.into()));
+		assert_eq!(Subtensor::set_take(<<Test as Config>::Origin>::signed(10), 2, 6553), Err(Error::<Test>::NotRegistered.into()));
+		assert_eq!(Subtensor::take(&hotkey), 0);
+	});
+}
+
+#[test]
+fn test_set_take_err_too_high() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 1;
+		let coldkey = 10;
+		register_ok_neuron( hotkey, coldkey );
+		let max_take: u16 = Subtensor::get_max_take();
+
+		assert_eq!(Subtensor::set_take(<<Test as Config>::Origin>::signed(coldkey), hotkey, max_take + 1), Err(Error::<Test>::TakeTooHigh.into()));
+		assert_ok!(Subtensor::set_take(<<Test as Config>::Origin>::signed(coldkey), hotkey, max_take));
+	});
+}
+
+#[test]
+fn test_set_take_rate_limited() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 1;
+		let coldkey = 10;
+		register_ok_neuron( hotkey, coldkey );
+		assert_ok!(Subtensor::set_take(<<Test as Config>::Origin>::signed(coldkey), hotkey, 6553));
+
+		// The take cannot change again until the rate limit has passed.
+		assert_eq!(Subtensor::set_take(<<Test as Config>::Origin>::signed(coldkey), hotkey, 0), Err(Error::<Test>::TakeRateLimitExceeded.into()));
+		step_block( Subtensor::get_take_rate_limit() );
+		assert_ok!(Subtensor::set_take(<<Test as Config>::Origin>::signed(coldkey), hotkey, 0));
+		assert_eq!(Subtensor::take(&hotkey), 0);
+	});
+}
+
+#[test]
+fn test_emission_split_with_take() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 1;
+		let coldkey = 10;
+		let nominator = 20;
+		let neuron = register_ok_neuron( hotkey, coldkey );
+		assert_ok!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(coldkey), hotkey));
+		Subtensor::add_balance_to_coldkey_account( &coldkey, 20001 );
+		Subtensor::add_balance_to_coldkey_account( &nominator, 20001 );
+		assert_ok!(Subtensor::add_stake(<<Test as Config>::Origin>::signed(coldkey), hotkey, 20000));
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 20000));
+		assert_ok!(Subtensor::set_take(<<Test as Config>::Origin>::signed(coldkey), hotkey, 13107)); // 20%
+
+		// The operator takes 800 off the top, the remaining 3200 is split evenly with the nominator.
+		assert_eq!(Subtensor::mint_emission_for_uid(neuron.uid, 4000), 4000);
+		assert_eq!(Subtensor::operator_emission(neuron.uid), 800);
+		assert_eq!(Subtensor::stake_emission(neuron.uid), 3200);
+		assert_eq!(Subtensor::get_owned_stake_for_uid(neuron.uid, &hotkey), 22400);
+		assert_eq!(Subtensor::get_nominator_stake(&hotkey, &nominator), 21600);
+
+		// A lowered max take caps the take already set.
+		Subtensor::set_max_take( 0 );
+		assert_eq!(Subtensor::mint_emission_for_uid(neuron.uid, 4400), 4400);
+		assert_eq!(Subtensor::operator_emission(neuron.uid), 0);
+		assert_eq!(Subtensor::stake_emission(neuron.uid), 4400);
+	});
+}
+
+/***********************************************************
+	delegation::undelegate_stake() tests
+************************************************************/
+
+#[test]
+fn test_undelegate_stake_ok() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 1;
+		let coldkey = 10;
+		let nominator = 20;
+		let neuron = register_ok_neuron( hotkey, coldkey );
+		assert_ok!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(coldkey), hotkey));
+		Subtensor::add_balance_to_coldkey_account( &nominator, 10001 );
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 10000));
+
+		assert_eq!(Subtensor::undelegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 10001), Err(Error::<Test>::NotEnoughStaketoWithdraw.into()));
+		assert_eq!(Subtensor::undelegate_stake(<<Test as Config>::Origin>::signed(coldkey), hotkey, 1), Err(Error::<Test>::NotEnoughStaketoWithdraw.into()));
+
+		assert_ok!(Subtensor::undelegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 4000));
+		assert_eq!(Subtensor::get_nominator_stake(&hotkey, &nominator), 6000);
+		assert_eq!(Subtensor::get_unbonding_stake(&nominator), 4000);
+
+		assert_ok!(Subtensor::undelegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 6000));
+		assert_eq!(Subtensor::get_nominator_stake(&hotkey, &nominator), 0);
+		assert_eq!(Subtensor::get_stake_of_neuron_hotkey_account_by_uid(neuron.uid), 0);
+		assert_eq!(Subtensor::get_total_stake(), 0);
+		assert_eq!(Subtensor::get_delegate(&hotkey).unwrap().nominators, vec![]);
+		assert_eq!(Subtensor::get_nominations_for_coldkey(&nominator), vec![]);
+
+		// The undelegated stake reaches the balance once the unbonding period has passed.
+		assert_eq!(Subtensor::get_coldkey_balance(&nominator), 1);
+		assert_eq!(Subtensor::get_unbonding_stake(&nominator), 10000);
+		step_block( Subtensor::get_unbonding_period() );
+		assert_ok!(Subtensor::withdraw_unbonded(<<Test as Config>::Origin>::signed(nominator)));
+		assert_eq!(Subtensor::get_coldkey_balance(&nominator), 10001);
+	});
+}
+
+#[test]
+fn test_undelegate_stake_err_below_existential_deposit() {
+	new_test_ext().execute_with(|| {
+		set_existential_deposit( 500 );
+		let hotkey = 1;
+		let coldkey = 10;
+		let nominator = 20;
+		register_ok_neuron( hotkey, coldkey );
+		assert_ok!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(coldkey), hotkey));
+		Subtensor::add_balance_to_coldkey_account( &nominator, 1000 );
+
+		// Delegating everything leaves the nominator's account empty, so it is reaped
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 1000));
+		assert_eq!(Balances::total_balance(&nominator), 0);
+
+		// A payout below the existential deposit would be burned on the reaped account
+		assert_eq!(Subtensor::undelegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 499), Err(Error::<Test>::StakeBelowExistentialDeposit.into()));
+		assert_eq!(Subtensor::get_nominator_stake(&hotkey, &nominator), 1000);
+		assert_eq!(Subtensor::get_unbonding_stake(&nominator), 0);
+
+		assert_ok!(Subtensor::undelegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 500));
+		assert_ok!(Subtensor::undelegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 500));
+		assert_eq!(Subtensor::get_unbonding_stake(&nominator), 1000);
+		assert_eq!(Subtensor::get_reserved_stake(&Subtensor::get_delegation_pool_account()), 0);
+	});
+}
+
+#[test]
+fn test_pruned_delegate_pays_nominators() {
+	new_test_ext().execute_with(|| {
+		Subtensor::set_max_allowed_uids( 1 );
+		Subtensor::set_immunity_period( 0 );
+		let hotkey = 1;
+		let coldkey = 10;
+		let nominator = 20;
+		register_ok_neuron( hotkey, coldkey );
+		assert_ok!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(coldkey), hotkey));
+		Subtensor::add_balance_to_coldkey_account( &coldkey, 5001 );
+		Subtensor::add_balance_to_coldkey_account( &nominator, 10001 );
+		assert_ok!(Subtensor::add_stake(<<Test as Config>::Origin>::signed(coldkey), hotkey, 5000));
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 10000));
+
+		// The next registration replaces the delegate.
+		step_block( 1 );
+		register_ok_neuron( 2, 30 );
+		assert!(!Subtensor::is_hotkey_active(&hotkey));
+
+		assert_eq!(Subtensor::get_coldkey_balance(&nominator), 10001);
+		assert_eq!(Subtensor::get_coldkey_balance(&coldkey), 5001);
+		assert_eq!(Subtensor::get_reserved_stake(&Subtensor::get_delegation_pool_account()), 0);
+		assert_eq!(Subtensor::get_total_stake(), 0);
+		assert!(!Subtensor::is_delegate(&hotkey));
+		assert_eq!(Subtensor::get_nominator_stake(&hotkey, &nominator), 0);
+		assert_eq!(Subtensor::get_delegates().len(), 0);
+	});
+}
+
+#[test]
+fn test_pruned_delegate_burns_payouts_below_existential_deposit() {
+	new_test_ext().execute_with(|| {
+		set_existential_deposit( 500 );
+		Subtensor::set_max_allowed_uids( 1 );
+		Subtensor::set_immunity_period( 0 );
+		let hotkey = 1;
+		let coldkey = 10;
+		let nominator = 20;
+		let small_nominator = 21;
+		register_ok_neuron( hotkey, coldkey );
+		assert_ok!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(coldkey), hotkey));
+		Subtensor::add_balance_to_coldkey_account( &nominator, 10000 );
+		Subtensor::add_balance_to_coldkey_account( &small_nominator, 300 );
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), hotkey, 1000));
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(small_nominator), hotkey, 300));
+		assert_eq!(Balances::total_balance(&small_nominator), 0);
+		let issuance = Subtensor::get_total_issuance();
+		let balances_issuance = Balances::total_issuance();
+
+		// The next registration replaces the delegate.
+		step_block( 1 );
+		register_ok_neuron( 2, 30 );
+		assert!(!Subtensor::is_hotkey_active(&hotkey));
+
+		// The payout to the reaped account is burned and taken out of both issuances
+		assert_eq!(Subtensor::get_coldkey_balance(&nominator), 10000);
+		assert_eq!(Balances::total_balance(&small_nominator), 0);
+		assert_eq!(Subtensor::get_total_issuance(), issuance - 300);
+		assert_eq!(Balances::total_issuance(), balances_issuance - 300);
+		assert_eq!(Subtensor::get_reserved_stake(&Subtensor::get_delegation_pool_account()), 0);
+		assert_eq!(Subtensor::get_total_stake(), 0);
+	});
+}
@@ -0,0 +1,125 @@
+use pallet_subtensor::{AxonInfo, GenesisConfig, GenesisHyperparams, GenesisNeuron};
+mod mock;
+use mock::*;
+
+fn genesis_neuron( hotkey: u64, coldkey: u64, stake: u128, weights: Vec<(u32, u32)> ) -> GenesisNeuron<u64, u128> {
+	GenesisNeuron {
+		hotkey,
+		coldkey,
+		stake,
+		axon: AxonInfo { version: 1, ip: ipv4(8,8,8,8), port: 8883, ip_type: 4, modality: 0 },
+		weights,
+	}
+}
+
+/********************************************
+	GenesisConfig::build() tests
+*********************************************/
+#[test]
+fn test_genesis_default_is_empty() {
+	test_ext_with_genesis( GenesisConfig::default() ).execute_with(|| {
+		assert_eq!( Subtensor::get_neuron_count(), 0 );
+		assert_eq!( Subtensor::get_total_stake(), 0 );
+		assert_eq!( Subtensor::get_rho(), 10 );
+	});
+}
+
+#[test]
+fn test_genesis_seeds_neurons() {
+	let genesis = GenesisConfig {
+		stake: vec![ (1, 5) ],
+		neurons: vec![
+			genesis_neuron( 1, 10, 100, vec![ (1, u32::MAX) ] ),
+			genesis_neuron( 2, 10, 200, vec![] ),
+			genesis_neuron( 3, 20, 0, vec![ (0, u32::MAX / 2), (1, u32::MAX / 2) ] ),
+		],
+		hyperparameters: GenesisHyperparams::default(),
+	};
+	test_ext_with_genesis( genesis ).execute_with(|| {
+		assert_eq!( Subtensor::get_neuron_count(), 3 );
+		assert_eq!( Subtensor::get_stake(), vec![ 100, 205, 0 ] );
+		assert_eq!( Subtensor::get_total_stake(), 305 );
+		// Genesis stake is minted and held on the coldkeys.
+		assert_eq!( Subtensor::get_reserved_stake( &10 ), 305 );
+		assert_eq!( Subtensor::get_total_issuance(), 305 );
+		for ( uid, hotkey ) in [ (0u32, 1u64), (1, 2), (2, 3) ] {
+			assert!( Subtensor::is_hotkey_active( &hotkey ) );
+			assert_eq!( Subtensor::get_uid_for_hotkey( &hotkey ), uid );
+			assert_eq!( Subtensor::block_at_registration( uid ), 0 );
+		}
+		assert_eq!( Subtensor::coldkey_hotkeys( 10 ), vec![ 1, 2 ] );
+		assert_eq!( Subtensor::coldkey_hotkeys( 20 ), vec![ 3 ] );
+
+		let neuron0 = Subtensor::get_neuron_for_uid( 0 );
+		assert_eq!( neuron0.ip, ipv4(8,8,8,8) );
+		assert_eq!( neuron0.port, 8883 );
+		assert_eq!( neuron0.weights, vec![ (1, u32::MAX) ] );
+		// Without weights the neuron starts with a self weight, as at registration.
+		assert_eq!( Subtensor::get_neuron_for_uid( 1 ).weights, vec![ (1, u32::MAX) ] );
+		assert_eq!( Subtensor::get_neuron_for_uid( 2 ).coldkey, 20 );
+	});
+}
+
+#[test]
+fn test_genesis_overrides_hyperparameters() {
+	let genesis = GenesisConfig {
+		stake: vec![],
+		neurons: vec![],
+		hyperparameters: GenesisHyperparams {
+			rho: Some( 20 ),
+			max_allowed_uids: Some( 4096 ),
+			difficulty: Some( 123456 ),
+			scaling_law_power: Some( 60 ),
+			..Default::default()
+		},
+	};
+	test_ext_with_genesis( genesis ).execute_with(|| {
+		assert_eq!( Subtensor::get_rho(), 20 );
+		assert_eq!( Subtensor::get_max_allowed_uids(), 4096 );
+		assert_eq!( Subtensor::get_difficulty_as_u64(), 123456 );
+		assert_eq!( Subtensor::get_scaling_law_power(), 60 );
+		// Fields left unset keep their initial value.
+		assert_eq!( Subtensor::get_kappa(), 2 );
+	});
+}
+
+#[test]
+#[should_panic]
+fn test_genesis_rejects_min_difficulty_above_max() {
+	let genesis = GenesisConfig {
+		stake: vec![],
+		neurons: vec![],
+		hyperparameters: GenesisHyperparams {
+			min_difficulty: Some( 20000 ),
+			max_difficulty: Some( 10000 ),
+			..Default::default()
+		},
+	};
+	test_ext_with_genesis( genesis );
+}
+
+#[test]
+#[should_panic]
+fn test_genesis_rejects_min_burn_above_max() {
+	let genesis = GenesisConfig {
+		stake: vec![],
+		neurons: vec![],
+		hyperparameters: GenesisHyperparams {
+			min_burn: Some( 2000 ),
+			max_burn: Some( 1000 ),
+			..Default::default()
+		},
+	};
+	test_ext_with_genesis( genesis );
+}
+
+#[test]
+#[should_panic]
+fn test_genesis_rejects_duplicate_hotkeys() {
+	let genesis = GenesisConfig {
+		stake: vec![],
+		neurons: vec![ genesis_neuron( 1, 10, 0, vec![] ), genesis_neuron( 1, 20, 0, vec![] ) ],
+		hyperparameters: GenesisHyperparams::default(),
+	};
+	test_ext_with_genesis( genesis );
+}
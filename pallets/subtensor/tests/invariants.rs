@@ -0,0 +1,186 @@
+use frame_support::{assert_ok, traits::{Currency, NamedReservableCurrency, OnUnbalanced}};
+use frame_system::{Config};
+mod mock;
+use mock::*;
+use pallet_subtensor::{Call as SubtensorCall, WeightInfo, InvariantViolation, N, STAKE_RESERVE_ID, TotalIssuance, TotalStake};
+use frame_support::weights::{GetDispatchInfo, DispatchInfo, DispatchClass, Pays};
+
+// Funds coldkeys 10 and 30 at genesis, so TotalIssuance starts out as their balances.
+fn new_invariants_test_ext() -> sp_io::TestExternalities {
+	test_ext_with_issued_balances( vec![ (10, 10000), (30, 10000) ] )
+}
+
+// Registers two neurons and stakes 6000 on the first from its funded coldkey.
+fn register_and_stake() {
+	register_ok_neuron( 1, 10 );
+	register_ok_neuron( 2, 20 );
+	assert_ok!(Subtensor::add_stake(<<Test as Config>::Origin>::signed(10), 1, 6000));
+}
+
+/***********************************************************
+	invariants::check_invariants() tests
+************************************************************/
+
+#[test]
+fn test_check_invariants_ok() {
+	new_invariants_test_ext().execute_with(|| {
+		assert_eq!(Subtensor::check_invariants(), vec![]);
+		register_and_stake();
+		assert_eq!(Subtensor::check_invariants(), vec![]);
+		assert_ok!(Subtensor::ensure_invariants());
+
+		// Emission keeps the accounting consistent.
+		run_to_block( 3 );
+		assert_eq!(Subtensor::check_invariants(), vec![]);
+	});
+}
+
+#[test]
+fn test_check_invariants_detects_total_stake() {
+	new_invariants_test_ext().execute_with(|| {
+		register_and_stake();
+		TotalStake::<Test>::put( 100 );
+		assert_eq!(Subtensor::check_invariants(), vec![ InvariantViolation::TotalStake { recorded: 100, summed: 6000 } ]);
+		assert!(Subtensor::ensure_invariants().is_err());
+	});
+}
+
+#[test]
+fn test_check_invariants_detects_delegated_stake() {
+	new_invariants_test_ext().execute_with(|| {
+		register_and_stake();
+		assert_ok!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(10), 1));
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(30), 1, 1000));
+		assert_eq!(Subtensor::check_invariants(), vec![]);
+
+		// The delegate loses stake the nominators own.
+		Subtensor::set_stake_from_vector( vec![ 500, 0 ] );
+		assert_eq!(Subtensor::check_invariants(), vec![
+			InvariantViolation::DelegatedStake { uid: 0 },
+			InvariantViolation::ReservedStake { stake: 500, reserved: 7000 },
+		]);
+	});
+}
+
+#[test]
+fn test_check_invariants_detects_hotkey_index() {
+	new_invariants_test_ext().execute_with(|| {
+		register_and_stake();
+
+		// The hotkey points at the wrong uid.
+		Subtensor::add_hotkey_to_active_set( &2, 0 );
+		assert_eq!(Subtensor::check_invariants(), vec![ InvariantViolation::HotkeyIndex { uid: 1 } ]);
+
+		// A hotkey without a neuron.
+		Subtensor::add_hotkey_to_active_set( &2, 1 );
+		Subtensor::add_hotkey_to_active_set( &3, 2 );
+		assert_eq!(Subtensor::check_invariants(), vec![ InvariantViolation::HotkeyCount { hotkeys: 3, neurons: 2 } ]);
+	});
+}
+
+#[test]
+fn test_check_invariants_detects_neuron_count() {
+	new_invariants_test_ext().execute_with(|| {
+		register_and_stake();
+		N::<Test>::put( 3 );
+		assert_eq!(Subtensor::check_invariants(), vec![ InvariantViolation::NeuronCount { n: 3, neurons: 2, next_uid: 2 } ]);
+	});
+}
+
+#[test]
+fn test_check_invariants_detects_issuance_below_stake() {
+	new_invariants_test_ext().execute_with(|| {
+		register_and_stake();
+		TotalIssuance::<Test>::put( 5000 );
+		assert_eq!(Subtensor::check_invariants(), vec![
+			InvariantViolation::IssuanceBelowStake { issuance: 5000, stake: 6000 },
+			InvariantViolation::Issuance { recorded: 5000, issued: 20000 },
+		]);
+	});
+}
+
+#[test]
+fn test_check_invariants_detects_issuance() {
+	new_invariants_test_ext().execute_with(|| {
+		register_and_stake();
+
+		// Minted outside the pallet.
+		Subtensor::add_balance_to_coldkey_account( &30, 500 );
+		assert_eq!(Subtensor::check_invariants(), vec![ InvariantViolation::Issuance { recorded: 20000, issued: 20500 } ]);
+	});
+}
+
+#[test]
+fn test_check_invariants_tracks_burned_balance() {
+	new_invariants_test_ext().execute_with(|| {
+		register_and_stake();
+
+		// Balance burned outside the pallet, as transaction fees are, passes through it.
+		let (fee, _) = Balances::slash( &30, 500 );
+		Subtensor::on_unbalanced( fee );
+		assert_eq!(Subtensor::get_total_issuance(), 19500);
+		assert_eq!(Subtensor::check_invariants(), vec![]);
+	});
+}
+
+#[test]
+fn test_check_invariants_detects_reserved_stake() {
+	new_invariants_test_ext().execute_with(|| {
+		register_and_stake();
+		assert_ok!(Subtensor::remove_stake(<<Test as Config>::Origin>::signed(10), 1, 1000));
+		assert_eq!(Subtensor::check_invariants(), vec![]);
+
+		// Stake released without leaving the stake map.
+		Balances::unreserve_named( &STAKE_RESERVE_ID, &10, 2000 );
+		assert_eq!(Subtensor::check_invariants(), vec![ InvariantViolation::ReservedStake { stake: 6000, reserved: 4000 } ]);
+	});
+}
+
+#[test]
+fn test_check_invariants_detects_coldkey_index() {
+	new_invariants_test_ext().execute_with(|| {
+		register_and_stake();
+
+		// The uid points at a coldkey which does not own its hotkey.
+		Subtensor::init_neuron( 1, &2, &10, 0 );
+		assert_eq!(Subtensor::check_invariants(), vec![ InvariantViolation::ColdkeyIndex { uid: 1 } ]);
+
+		// The coldkey no longer owns the hotkey either.
+		Subtensor::init_neuron( 1, &2, &20, 0 );
+		Subtensor::remove_hotkey_from_coldkey( &20, &2 );
+		assert_eq!(Subtensor::check_invariants(), vec![
+			InvariantViolation::ColdkeyIndex { uid: 1 },
+			InvariantViolation::ColdkeyCount { coldkeys: 2, owned_hotkeys: 1, neurons: 2 },
+		]);
+	});
+}
+
+/***********************************************************
+	invariants::sudo_repair_totals() tests
+************************************************************/
+
+#[test]
+fn test_sudo_repair_totals_dispatch_info_ok() {
+	new_test_ext().execute_with(|| {
+		let call = Call::Subtensor(SubtensorCall::sudo_repair_totals{});
+		assert_eq!(call.get_dispatch_info(), DispatchInfo {
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::sudo_repair_totals(0),
+			class: DispatchClass::Operational,
+			pays_fee: Pays::No
+		});
+	});
+}
+
+#[test]
+fn test_sudo_repair_totals_recomputes_totals() {
+	new_invariants_test_ext().execute_with(|| {
+		register_and_stake();
+		TotalStake::<Test>::put( 100 );
+		TotalIssuance::<Test>::put( 5000 );
+
+		assert_ok!(Subtensor::sudo_repair_totals(<<Test as Config>::Origin>::root()));
+		assert_eq!(Subtensor::get_total_stake(), 6000);
+		assert_eq!(Subtensor::get_total_issuance(), 20000);
+		assert_eq!(Subtensor::check_invariants(), vec![]);
+	});
+}
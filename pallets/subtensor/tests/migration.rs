@@ -0,0 +1,218 @@
+use frame_support::storage::migration::{put_storage_value, get_storage_value};
+use frame_support::{assert_ok, Blake2_128Concat, StorageHasher};
+use frame_support::traits::{GetStorageVersion, Hooks, StorageVersion};
+use pallet_subtensor::{NeuronMetadata, N, STORAGE_VERSION, UsedWorkBacklogCursor, UnlockChunk};
+use codec::Encode;
+mod mock;
+use mock::*;
+
+// Writes a neuron in the layout used before the per uid storage split.
+fn put_old_neuron( neuron: &NeuronMetadata<u64, u64> ) {
+	put_storage_value( b"Subtensor", b"Neurons", &neuron.uid.encode(), neuron );
+	Subtensor::add_hotkey_to_active_set( &neuron.hotkey, neuron.uid );
+}
+
+// Writes the stake of a uid in the layout used while stake was a u64.
+fn put_old_stake( uid: u32, stake: u64 ) {
+	put_storage_value( b"Subtensor", b"Stake", &uid.encode(), stake );
+}
+
+fn old_neuron<B: From<u64>>( uid: u32, hotkey: u64, coldkey: u64, stake: u64 ) -> NeuronMetadata<u64, B> {
+	NeuronMetadata {
+		version: 1,
+		ip: ipv4(8,8,8,8),
+		port: 8883,
+		ip_type: 4,
+		uid,
+		modality: 0,
+		hotkey,
+		coldkey,
+		active: 1,
+		last_update: 7,
+		priority: 3,
+		stake: stake.into(),
+		rank: 11,
+		trust: 12,
+		consensus: 13,
+		incentive: 14,
+		dividends: 15,
+		emission: 16,
+		bonds: vec![(0, 100), (1, 200)],
+		weights: vec![(0, u32::MAX / 2), (1, u32::MAX / 2)],
+	}
+}
+
+/********************************************
+	migrations::migrate_to_split_neuron_storage() tests
+*********************************************/
+#[test]
+fn test_migrate_to_split_neuron_storage() {
+	new_test_ext().execute_with(|| {
+		put_old_neuron( &old_neuron::<u64>( 0, 1, 10, 1000 ) );
+		put_old_neuron( &old_neuron::<u64>( 1, 2, 10, 2000 ) );
+		N::<Test>::put( 2 );
+
+		Subtensor::on_runtime_upgrade();
+
+		// Every field survives the split, stake widened to a balance, and the old entries are gone.
+		let neuron0: NeuronMetadata<u64, u128> = old_neuron( 0, 1, 10, 1000 );
+		let neuron1: NeuronMetadata<u64, u128> = old_neuron( 1, 2, 10, 2000 );
+		assert_eq!( Subtensor::get_neuron_for_uid( 0 ), neuron0 );
+		assert_eq!( Subtensor::get_neuron_for_uid( 1 ), neuron1 );
+		assert_eq!( Subtensor::get_stake(), vec![ 1000, 2000 ] );
+		assert_eq!( get_storage_value::<NeuronMetadata<u64, u64>>( b"Subtensor", b"Neurons", &0u32.encode() ), None );
+		assert_eq!( get_storage_value::<NeuronMetadata<u64, u64>>( b"Subtensor", b"Neurons", &1u32.encode() ), None );
+
+		// The coldkey index is built from the migrated neurons.
+		assert_eq!( Subtensor::coldkey_hotkeys( 10 ), vec![ 1, 2 ] );
+
+		// Running the upgrade again changes nothing.
+		Subtensor::on_runtime_upgrade();
+		assert_eq!( Subtensor::get_neuron_for_uid( 0 ), neuron0 );
+		assert_eq!( Subtensor::coldkey_hotkeys( 10 ), vec![ 1, 2 ] );
+	});
+}
+
+/********************************************
+	migrations::migrate_bonds_to_double_map() tests
+*********************************************/
+#[test]
+fn test_migrate_bonds_to_double_map() {
+	new_test_ext().execute_with(|| {
+		let neuron0 = register_ok_neuron( 1, 10 );
+		let neuron1 = register_ok_neuron( 2, 20 );
+
+		// Bonds in the one row per uid layout, with uid 1 still waiting to be pruned.
+		put_storage_value( b"Subtensor", b"Bonds", &neuron0.uid.encode(), vec![ (0u32, 100u64), (1u32, 200u64) ] );
+		put_storage_value( b"Subtensor", b"Bonds", &neuron1.uid.encode(), vec![ (0u32, 300u64), (1u32, 0u64) ] );
+		put_storage_value( b"Subtensor", b"NeuronsToPruneAtNextEpoch", &neuron1.uid.encode(), neuron1.uid );
+
+		Subtensor::on_runtime_upgrade();
+
+		// Bonds held in the pruned uid are dropped, the rest land in the double map.
+		assert_eq!( Subtensor::get_bonds(), vec![ vec![ 100, 0 ], vec![ 300, 0 ] ] );
+		assert_eq!( Subtensor::get_sparse_bonds_for_uid( neuron0.uid ), vec![ (0, 100) ] );
+		assert_eq!( get_storage_value::<u32>( b"Subtensor", b"NeuronsToPruneAtNextEpoch", &neuron1.uid.encode() ), None );
+
+		// Running the upgrade again changes nothing.
+		Subtensor::on_runtime_upgrade();
+		assert_eq!( Subtensor::get_bonds(), vec![ vec![ 100, 0 ], vec![ 300, 0 ] ] );
+	});
+}
+
+/********************************************
+	migrations::migrate_to_current_version() tests
+*********************************************/
+#[test]
+fn test_on_runtime_upgrade_bumps_storage_version() {
+	new_test_ext().execute_with(|| {
+		assert_eq!( Subtensor::on_chain_storage_version(), StorageVersion::new(0) );
+		Subtensor::on_runtime_upgrade();
+		assert_eq!( Subtensor::on_chain_storage_version(), STORAGE_VERSION );
+		assert_eq!( Subtensor::current_storage_version(), STORAGE_VERSION );
+	});
+}
+
+#[test]
+fn test_on_runtime_upgrade_skips_applied_steps() {
+	new_test_ext().execute_with(|| {
+		// A chain already at the current version keeps whatever sits under the old prefixes.
+		STORAGE_VERSION.put::<Subtensor>();
+		let neuron0: NeuronMetadata<u64, u64> = old_neuron( 0, 1, 10, 1000 );
+		put_old_neuron( &neuron0 );
+
+		Subtensor::on_runtime_upgrade();
+
+		assert_eq!( get_storage_value::<NeuronMetadata<u64, u64>>( b"Subtensor", b"Neurons", &0u32.encode() ), Some( neuron0 ) );
+		assert_eq!( Subtensor::get_stake(), Vec::<u128>::new() );
+	});
+}
+
+/********************************************
+	migrations::migrate_used_work_backlog() tests
+*********************************************/
+#[test]
+fn test_migrate_used_work_backlog() {
+	new_test_ext().execute_with(|| {
+		// Seals recorded before UsedWork was indexed by block.
+		let old_work: Vec<u8> = vec![ 1; 32 ];
+		let recent_work: Vec<u8> = vec![ 2; 32 ];
+		put_storage_value( b"Subtensor", b"UsedWork", &old_work.encode(), 5u64 );
+		put_storage_value( b"Subtensor", b"UsedWork", &recent_work.encode(), 95u64 );
+		System::set_block_number( 100 );
+
+		Subtensor::on_runtime_upgrade();
+		assert!( UsedWorkBacklogCursor::<Test>::get().is_some() );
+		assert_eq!( Subtensor::usedwork( &old_work ), 5 );
+
+		// The backlog is pruned from on_initialize, keeping seals inside the retention window.
+		step_block( 1 );
+		assert_eq!( Subtensor::usedwork( &old_work ), 0 );
+		assert_eq!( Subtensor::usedwork( &recent_work ), 95 );
+		assert_eq!( UsedWorkBacklogCursor::<Test>::get(), None );
+	});
+}
+
+/********************************************
+	migrations::migrate_stake_to_reserves() tests
+*********************************************/
+#[test]
+fn test_migrate_stake_to_reserves() {
+	new_test_ext().execute_with(|| {
+		// Stake recorded as a u64 while it was still withdrawn from the coldkey, 400 of it unbonding.
+		StorageVersion::new( 4 ).put::<Subtensor>();
+		let neuron = register_ok_neuron( 1, 10 );
+		put_old_stake( neuron.uid, 600 );
+		put_storage_value( b"Subtensor", b"TotalStake", &[], 600u64 );
+		put_storage_value( b"Subtensor", b"Unbonding", &Blake2_128Concat::hash( &10u64.encode() ), vec![ (400u64, 20u64) ] );
+		Subtensor::add_balance_to_coldkey_account( &10, 50 );
+		assert_eq!( Subtensor::get_reserved_stake( &10 ), 0 );
+
+		Subtensor::on_runtime_upgrade();
+
+		// The staked and the unbonding stake are held on the coldkey, its free balance is untouched.
+		assert_eq!( Subtensor::get_reserved_stake( &10 ), 1000 );
+		assert_eq!( Subtensor::get_coldkey_balance( &10 ), 50 );
+		assert_eq!( Subtensor::get_stake(), vec![ 600 ] );
+		assert_eq!( Subtensor::get_pending_unlocks( &10 ), vec![ UnlockChunk { amount: 400, unlock_block: 20 } ] );
+		assert_eq!( Subtensor::get_total_issuance(), Balances::total_issuance() );
+		assert_eq!( Subtensor::on_chain_storage_version(), STORAGE_VERSION );
+	});
+}
+
+/********************************************
+	migrations::migrate_stake_to_balance() tests
+*********************************************/
+#[test]
+fn test_migrate_stake_to_balance() {
+	new_test_ext().execute_with(|| {
+		// A delegate with one nominator and an unbonding chunk, every amount recorded as a u64.
+		StorageVersion::new( 5 ).put::<Subtensor>();
+		let neuron = register_ok_neuron( 1, 10 );
+		assert_ok!( Subtensor::become_delegate( <<Test as frame_system::Config>::Origin>::signed( 10 ), 1 ) );
+		let hotkey_key = Blake2_128Concat::hash( &1u64.encode() );
+		let nominator_key = [ hotkey_key.clone(), Blake2_128Concat::hash( &20u64.encode() ) ].concat();
+		put_old_stake( neuron.uid, 1500 );
+		put_storage_value( b"Subtensor", b"DelegatedStake", &hotkey_key, 500u64 );
+		put_storage_value( b"Subtensor", b"TotalNominatorShares", &hotkey_key, 500u64 );
+		put_storage_value( b"Subtensor", b"NominatorShares", &nominator_key, 500u64 );
+		put_storage_value( b"Subtensor", b"Unbonding", &Blake2_128Concat::hash( &20u64.encode() ), vec![ (100u64, 30u64) ] );
+		put_storage_value( b"Subtensor", b"TotalStake", &[], 1500u64 );
+		put_storage_value( b"Subtensor", b"TotalIssuance", &[], 5000u64 );
+		put_storage_value( b"Subtensor", b"Burn", &[], 2000u64 );
+		put_storage_value( b"Subtensor", b"HotkeySwapCost", &[], 3000u64 );
+
+		Subtensor::on_runtime_upgrade();
+
+		// Every amount keeps its value.
+		assert_eq!( Subtensor::get_stake(), vec![ 1500 ] );
+		assert_eq!( Subtensor::get_total_stake(), 1500 );
+		assert_eq!( Subtensor::get_total_issuance(), 5000 );
+		assert_eq!( Subtensor::get_delegate( &1 ).unwrap().delegated_stake, 500 );
+		assert_eq!( Subtensor::get_nominator_stake( &1, &20 ), 500 );
+		assert_eq!( Subtensor::get_pending_unlocks( &20 ), vec![ UnlockChunk { amount: 100, unlock_block: 30 } ] );
+		assert_eq!( Subtensor::get_burn(), 2000 );
+		assert_eq!( Subtensor::get_hotkey_swap_cost(), 3000 );
+		assert_eq!( Subtensor::on_chain_storage_version(), STORAGE_VERSION );
+	});
+}
@@ -56,7 +56,7 @@ frame_support::construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Config<T>, Storage, Event<T>},
-		Subtensor: pallet_subtensor::{Pallet, Call, Config, Storage, Event<T>},
+		Subtensor: pallet_subtensor::{Pallet, Call, Config<T>, Storage, Event<T>},
 	}
 );
 
@@ -76,7 +76,6 @@ pub type TestRuntimeCall = frame_system::Call<Test>;
 parameter_types! {
 	pub const BlockHashCount: BlockNumber = 640;
 	pub BlockWeights: limits::BlockWeights = limits::BlockWeights::simple_max(1024);
-	pub const ExistentialDeposit: Balance = 1;
 	pub const TransactionByteFee: Balance = 100;
 	pub const SDebug:u64 = 1;
 	pub const InitialRho: u64 = 10;
@@ -101,12 +100,22 @@ parameter_types! {
 	pub const InitialBlocksPerStep: u64 = 1;
 	pub const InitialIssuance: u64 = 548833985028256;
 	pub const InitialDifficulty: u64 = 10000;
-	pub const MinimumDifficulty: u64 = 10000;
+	pub const InitialMinDifficulty: u64 = 10000;
 	pub const InitialActivityCutoff: u64 = 5000;
-	pub const MaximumDifficulty: u64 = u64::MAX/4;
+	pub const InitialMaxDifficulty: u64 = u64::MAX/4;
+	pub const InitialBurn: Balance = 1000;
+	pub const InitialMinBurn: Balance = 100;
+	pub const InitialMaxBurn: Balance = 100000;
+	pub const InitialLegacySealCutoff: u64 = u64::MAX;
 	pub const InitialAdjustmentInterval: u64 = 100;
 	pub const InitialMaxRegistrationsPerBlock: u64 = 2;
 	pub const InitialTargetRegistrationsPerInterval: u64 = 2;
+	pub const InitialUsedWorkRetention: u64 = 10;
+	pub const InitialUnbondingPeriod: u64 = 10;
+	pub const InitialHotkeySwapCost: Balance = 1000;
+	pub const InitialColdkeySwapDelay: u64 = 10;
+	pub const InitialMaxTake: u16 = 13107;
+	pub const InitialTakeRateLimit: u64 = 10;
 
 	pub const InitialScalingLawPower: u8 = 50;
 	pub const InitialSynergyScalingLawPower: u8 = 60;
@@ -168,11 +177,28 @@ impl frame_system::Config for Test {
 #[allow(dead_code)]
 pub type AccountId = u64;
 
+thread_local! {
+	static EXISTENTIAL_DEPOSIT: RefCell<Balance> = RefCell::new(1);
+}
+
+pub struct ExistentialDeposit;
+impl frame_support::traits::Get<Balance> for ExistentialDeposit {
+	fn get() -> Balance {
+		EXISTENTIAL_DEPOSIT.with(|deposit| *deposit.borrow())
+	}
+}
+
+/// Sets the existential deposit of the balances pallet for the rest of the test.
+#[allow(dead_code)]
+pub fn set_existential_deposit(deposit: Balance) {
+	EXISTENTIAL_DEPOSIT.with(|existential_deposit| *existential_deposit.borrow_mut() = deposit);
+}
+
 impl pallet_balances::Config for Test {
 	type Balance = Balance;
 	type Event = ();
-	type DustRemoval = ();
-	type ExistentialDeposit = ();
+	type DustRemoval = Subtensor;
+	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = StorageMapShim<
 		pallet_balances::Account<Test>,
 		frame_system::Provider<Test>,
@@ -181,8 +207,8 @@ impl pallet_balances::Config for Test {
 	>;
 	type MaxLocks = ();
 	type WeightInfo = ();
-	type MaxReserves = ();
-	type ReserveIdentifier = ();
+	type MaxReserves = frame_support::traits::ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
 }
 
 impl pallet_subtensor::Config for Test {
@@ -215,13 +241,24 @@ impl pallet_subtensor::Config for Test {
 	type InitialFoundationDistribution = InitialFoundationDistribution;
 	type InitialIssuance = InitialIssuance;
 	type InitialDifficulty = InitialDifficulty;
-	type MinimumDifficulty = MinimumDifficulty;
-	type MaximumDifficulty = MaximumDifficulty;
+	type InitialMinDifficulty = InitialMinDifficulty;
+	type InitialMaxDifficulty = InitialMaxDifficulty;
+	type InitialBurn = InitialBurn;
+	type InitialMinBurn = InitialMinBurn;
+	type InitialMaxBurn = InitialMaxBurn;
+	type InitialLegacySealCutoff = InitialLegacySealCutoff;
 	type InitialBlocksPerStep = InitialBlocksPerStep;
 	type InitialActivityCutoff = InitialActivityCutoff;
 	type InitialAdjustmentInterval = InitialAdjustmentInterval;
 	type InitialMaxRegistrationsPerBlock = InitialMaxRegistrationsPerBlock;
 	type InitialTargetRegistrationsPerInterval = InitialTargetRegistrationsPerInterval;
+	type InitialUsedWorkRetention = InitialUsedWorkRetention;
+	type InitialUnbondingPeriod = InitialUnbondingPeriod;
+	type InitialHotkeySwapCost = InitialHotkeySwapCost;
+	type InitialColdkeySwapDelay = InitialColdkeySwapDelay;
+	type InitialMaxTake = InitialMaxTake;
+	type InitialTakeRateLimit = InitialTakeRateLimit;
+	type WeightInfo = ();
 
 }
 
@@ -321,31 +358,52 @@ pub fn test_ext_with_balances(balances : Vec<(u64, u128)>) -> sp_io::TestExterna
 }
 
 
-// #[allow(dead_code)]
-// pub fn test_ext_with_stake(stake : Vec<(u64, u64)>) -> sp_io::TestExternalities {
-// 	let mut t = frame_system::GenesisConfig::default()
-// 	.build_storage::<Test>()
-// 	.unwrap();
-// 	pallet_subtensor::GenesisConfig {
-// 		stake: vec![]
-// 	}.assimilate_storage::<Test>(&mut t)
-// 		.unwrap();
-// 	t.into()
-// }
+// Funds the balances and builds the default subtensor genesis on top of them, so TotalIssuance
+// starts out as the issuance of the balances pallet.
+#[allow(dead_code)]
+pub fn test_ext_with_issued_balances(balances : Vec<(u64, u128)>) -> sp_io::TestExternalities {
+	sp_tracing::try_init_simple();
+	let mut t = frame_system::GenesisConfig::default()
+		.build_storage::<Test>()
+		.unwrap();
+
+	pallet_balances::GenesisConfig::<Test> { balances }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	pallet_subtensor::GenesisConfig::<Test>::default()
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	t.into()
+}
+
+#[allow(dead_code)]
+pub fn test_ext_with_genesis(genesis: pallet_subtensor::GenesisConfig<Test>) -> sp_io::TestExternalities {
+	sp_tracing::try_init_simple();
+	let mut t = frame_system::GenesisConfig::default()
+		.build_storage::<Test>()
+		.unwrap();
+
+	genesis.assimilate_storage(&mut t)
+		.unwrap();
+
+	t.into()
+}
 
 #[allow(dead_code)]
-pub fn register_ok_neuron( hotkey_account_id: u64, coldkey_account_id: u64) -> NeuronMetadata<u64> {
+pub fn register_ok_neuron( hotkey_account_id: u64, coldkey_account_id: u64) -> NeuronMetadata<u64, u128> {
 	let block_number: u64 = Subtensor::get_current_block_as_u64();
-	let (nonce, work): (u64, Vec<u8>) = Subtensor::create_work_for_block_number( block_number, (hotkey_account_id + coldkey_account_id) * 1000000 );
+	let (nonce, work): (u64, Vec<u8>) = Subtensor::create_work_for_hotkey( block_number, (hotkey_account_id + coldkey_account_id) * 1000000, &hotkey_account_id );
 	let result = Subtensor::register( <<Test as frame_system::Config>::Origin>::signed(hotkey_account_id), block_number, nonce, work, hotkey_account_id, coldkey_account_id );
 	assert_ok!(result);
 	let neuron = Subtensor::get_neuron_for_hotkey(&hotkey_account_id);
 	neuron
 }
 #[allow(dead_code)]
-pub fn register_ok_neuron_with_nonce( hotkey_account_id: u64, coldkey_account_id: u64, nonce: u64 ) -> NeuronMetadata<u64> {
+pub fn register_ok_neuron_with_nonce( hotkey_account_id: u64, coldkey_account_id: u64, nonce: u64 ) -> NeuronMetadata<u64, u128> {
 	let block_number: u64 = Subtensor::get_current_block_as_u64();
-	let (nonce2, work): (u64, Vec<u8>) = Subtensor::create_work_for_block_number( block_number, nonce );
+	let (nonce2, work): (u64, Vec<u8>) = Subtensor::create_work_for_hotkey( block_number, nonce, &hotkey_account_id );
 	let result = Subtensor::register( <<Test as frame_system::Config>::Origin>::signed(hotkey_account_id), block_number, nonce2, work, hotkey_account_id, coldkey_account_id );
 	assert_ok!(result);
 	let neuron = Subtensor::get_neuron_for_hotkey(&hotkey_account_id);
@@ -353,7 +411,7 @@ pub fn register_ok_neuron_with_nonce( hotkey_account_id: u64, coldkey_account_id
 }
 
 #[allow(dead_code)]
-pub fn serve_axon( hotkey_account_id : u64, version: u32, ip: u128, port: u16, ip_type : u8, modality: u8 ) -> NeuronMetadata<u64> {
+pub fn serve_axon( hotkey_account_id : u64, version: u32, ip: u128, port: u16, ip_type : u8, modality: u8 ) -> NeuronMetadata<u64, u128> {
 	let result = Subtensor::serve_axon(<<Test as frame_system::Config>::Origin>::signed(hotkey_account_id), version, ip, port, ip_type, modality );
 	assert_ok!(result);
 	let neuron = Subtensor::get_neuron_for_hotkey(&hotkey_account_id);
@@ -361,10 +419,10 @@ pub fn serve_axon( hotkey_account_id : u64, version: u32, ip: u128, port: u16, i
 }
 
 // #[allow(dead_code)]
-// pub fn n_subscribe_ok_neuron(n: usize) -> Vec<NeuronMetadata<u64>> {
-// 	let mut neurons: Vec<NeuronMetadata<u64>> = vec![];
+// pub fn n_subscribe_ok_neuron(n: usize) -> Vec<NeuronMetadata<u64, u128>> {
+// 	let mut neurons: Vec<NeuronMetadata<u64, u128>> = vec![];
 // 	for i in 0..n {
-// 		let neuron: NeuronMetadata<u64> = register_ok_neuron(0, i as u8, i as u64, i as u64);
+// 		let neuron: NeuronMetadata<u64, u128> = register_ok_neuron(0, i as u8, i as u64, i as u64);
 // 		neurons.push(neuron);
 // 	}
 // 	return neurons;
@@ -381,6 +439,14 @@ pub(crate) fn run_to_block(n: u64) {
     }
 }
 
+// Initializes block n through Executive::initialize_block, which is also what a runtime api call
+// at block n - 1 runs on top of first. The parent hash recorded for n - 1 is derived from n.
+#[allow(dead_code)]
+pub(crate) fn initialize_block(n: u64) {
+	let parent_hash = sp_core::H256::from_low_u64_be(n);
+	Executive::initialize_block(&<Header as sp_runtime::traits::Header>::new(n, Default::default(), Default::default(), parent_hash, Default::default()));
+}
+
 #[allow(dead_code)]
 pub(crate) fn step_block(n: u64) {
 	for _ in 0..n {
@@ -1,4 +1,4 @@
-use pallet_subtensor::{Error};
+use pallet_subtensor::{DifficultyAdjustment, Error, RegistrationWorkError, UsedWorkByBlock, UsedWorkPruneCursor, WeightInfo, DIFFICULTY_HISTORY_LENGTH};
 use frame_support::{assert_ok};
 use frame_system::Config;
 mod mock;
@@ -6,6 +6,7 @@ use mock::*;
 use frame_support::sp_runtime::DispatchError;
 use frame_support::dispatch::{GetDispatchInfo, DispatchInfo};
 use frame_support::weights::{DispatchClass, Pays};
+use sp_core::U256;
 
 /********************************************
 	subscribing::subscribe() tests
@@ -20,7 +21,7 @@ fn test_subscribe_ok_dispatch_info_ok() {
 		let coldkey: u64 = 0;
         let call = Call::Subtensor(SubtensorCall::register{block_number, nonce, work, hotkey, coldkey });
 		assert_eq!(call.get_dispatch_info(), DispatchInfo {
-			weight: 0,
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::register(0),
 			class: DispatchClass::Normal,
 			pays_fee: Pays::No
 		});
@@ -192,9 +193,124 @@ fn test_difficulty_adjustment() {
 	});
 }
 
+// Registers `count` fresh hotkeys at the current block with hotkey bound work.
+fn register_at_current_block( first_hotkey: u64, count: u64 ) {
+	let block_number: u64 = Subtensor::get_current_block_as_u64();
+	for hotkey in first_hotkey..first_hotkey + count {
+		let (nonce, work): (u64, Vec<u8>) = Subtensor::create_work_for_hotkey( block_number, 0, &hotkey );
+		assert_ok!(Subtensor::register(<<Test as Config>::Origin>::signed(hotkey), block_number, nonce, work, hotkey, hotkey));
+	}
+}
+
+#[test]
+fn test_difficulty_adjustment_proportional() {
+	new_test_ext().execute_with(|| {
+		Subtensor::set_adjustment_interval( 1 );
+		Subtensor::set_target_registrations_per_interval( 2 );
+		Subtensor::set_max_registratations_per_block( 3 );
+		Subtensor::set_difficulty_from_u64( 20000 );
+
+		// Half again as many registrations as the target raise the difficulty by half.
+		register_at_current_block( 0, 3 );
+		step_block( 1 );
+		assert_eq!( Subtensor::get_difficulty_as_u64(), 30000 );
+
+		// Registrations on target leave it where it is.
+		register_at_current_block( 3, 2 );
+		step_block( 1 );
+		assert_eq!( Subtensor::get_difficulty_as_u64(), 30000 );
+
+		// Half the target halves it, and no registrations at all halve it at most, down to the minimum.
+		register_at_current_block( 5, 1 );
+		step_block( 1 );
+		assert_eq!( Subtensor::get_difficulty_as_u64(), 15000 );
+		step_block( 1 );
+		assert_eq!( Subtensor::get_difficulty_as_u64(), 10000 );
+
+		// The change per adjustment is clamped.
+		Subtensor::set_target_registrations_per_interval( 1 );
+		register_at_current_block( 6, 3 );
+		step_block( 1 );
+		assert_eq!( Subtensor::get_difficulty_as_u64(), 20000 );
+	});
+}
+
+#[test]
+fn test_difficulty_adjustment_bounds() {
+	new_test_ext().execute_with(|| {
+		Subtensor::set_adjustment_interval( 1 );
+		Subtensor::set_target_registrations_per_interval( 1 );
+		Subtensor::set_min_difficulty( 5000 );
+		Subtensor::set_max_difficulty( 12000 );
+		Subtensor::set_difficulty_from_u64( 11000 );
+		register_at_current_block( 0, 2 );
+		step_block( 1 );
+		assert_eq!( Subtensor::get_difficulty_as_u64(), 12000 );
+		step_block( 2 );
+		assert_eq!( Subtensor::get_difficulty_as_u64(), 5000 );
+	});
+}
+
+#[test]
+fn test_difficulty_adjustment_rounds_up() {
+	new_test_ext().execute_with(|| {
+		// Registrations above target raise the value by at least one, rounding up.
+		assert_eq!( Subtensor::adjust_to_registrations( 1, 3, 2, 0, u128::MAX ), 2 );
+		assert_eq!( Subtensor::adjust_to_registrations( 3, 3, 2, 0, u128::MAX ), 5 );
+		assert_eq!( Subtensor::adjust_to_registrations( 0, 1, 0, 0, u128::MAX ), 1 );
+
+		// Registrations below target round down, but no further than half the value rounded up.
+		assert_eq!( Subtensor::adjust_to_registrations( 5, 1, 2, 0, u128::MAX ), 3 );
+		assert_eq!( Subtensor::adjust_to_registrations( 1, 0, 2, 0, u128::MAX ), 1 );
+		assert_eq!( Subtensor::adjust_to_registrations( u128::MAX, 2, 1, 0, u128::MAX ), u128::MAX );
+	});
+}
+
+#[test]
+fn test_difficulty_adjustment_minimum_bound() {
+	new_test_ext().execute_with(|| {
+		Subtensor::set_adjustment_interval( 1 );
+		Subtensor::set_target_registrations_per_interval( 2 );
+		Subtensor::set_max_registratations_per_block( 3 );
+		Subtensor::set_min_difficulty( 0 );
+		Subtensor::set_difficulty_from_u64( 1 );
+
+		// Without registrations the difficulty never reaches zero, even with a minimum of zero.
+		step_block( 2 );
+		assert_eq!( Subtensor::get_difficulty_as_u64(), 1 );
+
+		// One registration above target raises it from there.
+		register_at_current_block( 0, 3 );
+		step_block( 1 );
+		assert_eq!( Subtensor::get_difficulty_as_u64(), 2 );
+		step_block( 1 );
+		assert_eq!( Subtensor::get_difficulty_as_u64(), 1 );
+	});
+}
+
+#[test]
+fn test_difficulty_history() {
+	new_test_ext().execute_with(|| {
+		Subtensor::set_adjustment_interval( 1 );
+		Subtensor::set_target_registrations_per_interval( 1 );
+		register_at_current_block( 0, 2 );
+		step_block( 1 );
+		assert_eq!( Subtensor::get_difficulty_history(), vec![ DifficultyAdjustment { block: 1, difficulty: 20000, registrations: 2 } ] );
+
+		// Only the most recent adjustments are kept, oldest first.
+		step_block( DIFFICULTY_HISTORY_LENGTH as u64 + 5 );
+		let history: Vec<DifficultyAdjustment> = Subtensor::get_difficulty_history();
+		let current_block: u64 = Subtensor::get_current_block_as_u64();
+		assert_eq!( history.len(), DIFFICULTY_HISTORY_LENGTH );
+		assert_eq!( history.last().unwrap().block, current_block );
+		assert_eq!( history[0].block, current_block + 1 - DIFFICULTY_HISTORY_LENGTH as u64 );
+	});
+}
+
 #[test]
 fn test_immunity_period() {
 	new_test_ext().execute_with(|| {
+		let stake_pruning_min: u128 = Subtensor::get_stake_pruning_min() as u128;
 		Subtensor::set_max_allowed_uids ( 2 );
 		Subtensor::set_immunity_period ( 2 );
 		assert_eq!( Subtensor::get_max_allowed_uids(), 2 );
@@ -205,8 +321,6 @@ fn test_immunity_period() {
 		assert_eq!( neuron0.uid, 0 );
 		let neuron1 = register_ok_neuron_with_nonce( 1, 1, 18912831231);
 		assert_eq!( neuron1.uid, 1 );
-		assert!( !Subtensor::will_be_prunned(0) );
-		assert!( !Subtensor::will_be_prunned(1) );
 
 		// Step to the next block.
 		step_block ( 1 );
@@ -221,13 +335,11 @@ fn test_immunity_period() {
 		// prune is now 0. All uids are immune at this stage.
 		let neuron3 = register_ok_neuron_with_nonce( 3, 3, 8129123823582 );
 		assert_eq!( neuron3.uid, 1 );
-		assert!( Subtensor::will_be_prunned(0) );
-		assert!( Subtensor::will_be_prunned(1) );
 
 		// Step to the next block.
 		// Add stake to Subtensor::::get_stake_pruning_min()
-		Subtensor::set_stake_from_vector( vec![ Subtensor::get_stake_pruning_min(), 0 ] );
-		assert_eq!( Subtensor::get_stake(), vec![ Subtensor::get_stake_pruning_min(), 0 ] );
+		Subtensor::set_stake_from_vector( vec![ stake_pruning_min, 0 ] );
+		assert_eq!( Subtensor::get_stake(), vec![ stake_pruning_min, 0 ] );
 		step_block ( 1 );
 
 		// Register the next neuron, the previous neurons have immunity however the first has stake.
@@ -238,10 +350,8 @@ fn test_immunity_period() {
 		// than neuron4, we go into slot 0
 		let neuron5 = register_ok_neuron_with_nonce( 5, 5, 1235325532);
 		assert_eq!( neuron5.uid, 0 );
-		assert!( Subtensor::will_be_prunned(0) );
-		assert!( Subtensor::will_be_prunned(1) );
 
-		Subtensor::set_stake_from_vector( vec![ Subtensor::get_stake_pruning_min(), 0 ] );
+		Subtensor::set_stake_from_vector( vec![ stake_pruning_min, 0 ] );
 		step_block ( 1 );
 		step_block ( 1 );
 		step_block ( 1 );
@@ -249,13 +359,8 @@ fn test_immunity_period() {
 		// Register the next neuron, the first slot has stake go into slot 1
 		let neuron6 = register_ok_neuron_with_nonce( 6, 6,21352352 );
 		assert_eq!( neuron6.uid, 1 );
-		assert!( !Subtensor::will_be_prunned(0) );
-		assert!( Subtensor::will_be_prunned(1) );
 
 		step_block ( 1 );
-		// Prunned set is dropped.
-		assert!( !Subtensor::will_be_prunned(0) );
-		assert!( !Subtensor::will_be_prunned(1) );
 		step_block ( 1 );
 		step_block ( 1 );
 
@@ -263,25 +368,23 @@ fn test_immunity_period() {
 		// so this goes into slot 1 again.
 		let neuron7 = register_ok_neuron_with_nonce( 7, 7,12352352532 );
 		assert_eq!( neuron7.uid, 1 );
-		assert!( !Subtensor::will_be_prunned(0) );
-		assert!( Subtensor::will_be_prunned(1) );
 
 		step_block ( 1 );
 
-		// Set stake of neuron7 to 2.
-		Subtensor::set_stake_from_vector( vec![ Subtensor::get_stake_pruning_min(), Subtensor::get_stake_pruning_min() * 2 ] );
+		// Set stake of neuron7 to 2. The stake in slot 0 is held on neuron 5's coldkey.
+		Subtensor::set_stake_from_vector( vec![ stake_pruning_min, stake_pruning_min * 2 ] );
+		Subtensor::add_balance_to_coldkey_account( &5, stake_pruning_min );
+		assert!( Subtensor::reserve_stake_on_coldkey( &5, stake_pruning_min ) );
 
 		// Register another this time going into slot 0.
 		let neuron8 = register_ok_neuron_with_nonce( 8, 8 , 123213124234);
 		assert_eq!( neuron8.uid, 0 );
-		assert!( Subtensor::will_be_prunned(0) );
-		assert!( !Subtensor::will_be_prunned(1) );
 
 		// Check that the stake in slot 0 has decremented.
 		// Note that the stake has been decremented.
-		assert_eq!( Subtensor::get_stake(), vec![0, Subtensor::get_stake_pruning_min() * 2 ] );
-		assert_eq!( Subtensor::get_total_stake(), Subtensor::get_stake_pruning_min() * 2 ); // Total stake has been decremented.
-		assert_eq!(Subtensor::get_coldkey_balance( &5 ) as u64, Subtensor::get_stake_pruning_min()); // The unstaked funds have been added to the neuron 5 coldkey account.
+		assert_eq!( Subtensor::get_stake(), vec![0, stake_pruning_min * 2 ] );
+		assert_eq!( Subtensor::get_total_stake(), stake_pruning_min * 2 ); // Total stake has been decremented.
+		assert_eq!(Subtensor::get_coldkey_balance( &5 ), stake_pruning_min); // The unstaked funds have been added to the neuron 5 coldkey account.
 
 		// Step blocks, nobody is immune anymore.
 		step_block ( 1 );
@@ -290,7 +393,7 @@ fn test_immunity_period() {
 		step_block ( 1 );
 
 		// Set weight matrix so that slot 1 has an incentive.
-		Subtensor::set_stake_from_vector( vec![ Subtensor::get_stake_pruning_min() * 2, Subtensor::get_stake_pruning_min() * 1 ] );
+		Subtensor::set_stake_from_vector( vec![ stake_pruning_min * 2, stake_pruning_min * 1 ] );
 		let weights_matrix: Vec<Vec<u32>> = vec! [
             vec! [0, u32::max_value()],
             vec! [0, u32::max_value()]
@@ -306,7 +409,6 @@ fn test_immunity_period() {
 		// Slot 1 has incentive proportion 1, slot0 has stake proportion 2/3. So this goes into slot 1.
 		let neuron9 = register_ok_neuron_with_nonce( 9, 9 , 18203182312);
 		assert_eq!( neuron9.uid, 1 );
-		assert!( Subtensor::will_be_prunned(1) );
 	});
 }
 
@@ -383,6 +485,59 @@ fn test_register_failed_no_signature() {
 	});
 }
 
+#[test]
+fn test_registration_hotkey_bound_seal_ok() {
+	new_test_ext().execute_with(|| {
+		let block_number: u64 = 0;
+		let hotkey_account_id = 1;
+		let coldkey_account_id = 667;
+		let (nonce, work): (u64, Vec<u8>) = Subtensor::create_work_for_hotkey( block_number, 0, &hotkey_account_id );
+		assert_ok!(Subtensor::register(<<Test as Config>::Origin>::signed(hotkey_account_id), block_number, nonce, work, hotkey_account_id, coldkey_account_id));
+		assert!( Subtensor::is_hotkey_active( &hotkey_account_id ) );
+	});
+}
+
+#[test]
+fn test_seal_for_block_hash_matches_seal_for_block_number() {
+	new_test_ext().execute_with(|| {
+		// Off chain solvers hash the block hash they read, register looks it up by number.
+		let block_hash = Subtensor::get_block_hash_from_u64( 0 );
+		assert_eq!( Subtensor::create_seal_hash_for_block_hash( &block_hash, 7, &1 ), Subtensor::create_seal_hash_for_hotkey( 0, 7, &1 ) );
+		assert_ne!( Subtensor::create_seal_hash_for_block_hash( &block_hash, 7, &1 ), Subtensor::create_seal_hash_for_block_hash( &block_hash, 7, &2 ) );
+	});
+}
+
+#[test]
+fn test_registration_hotkey_bound_seal_cannot_be_stolen() {
+	new_test_ext().execute_with(|| {
+		// Work seen in the mempool, resubmitted for another hotkey.
+		let block_number: u64 = 0;
+		let (nonce, work): (u64, Vec<u8>) = Subtensor::create_work_for_hotkey( block_number, 0, &1 );
+		let result = Subtensor::register(<<Test as Config>::Origin>::signed(2), block_number, nonce, work.clone(), 2, 667);
+		assert_eq!( result, Err(Error::<Test>::InvalidSeal.into()) );
+
+		// The hotkey it was found for can still use it.
+		assert_ok!(Subtensor::register(<<Test as Config>::Origin>::signed(1), block_number, nonce, work, 1, 667));
+	});
+}
+
+#[test]
+fn test_registration_legacy_seal_cutoff() {
+	new_test_ext().execute_with(|| {
+		let block_number: u64 = 0;
+		let (nonce, work): (u64, Vec<u8>) = Subtensor::create_work_for_block_number( block_number, 0 );
+
+		// Refused from the cutoff block on.
+		Subtensor::set_legacy_seal_cutoff( block_number );
+		let result = Subtensor::register(<<Test as Config>::Origin>::signed(1), block_number, nonce, work.clone(), 1, 667);
+		assert_eq!( result, Err(Error::<Test>::LegacySealExpired.into()) );
+
+		// Accepted before it.
+		Subtensor::set_legacy_seal_cutoff( block_number + 1 );
+		assert_ok!(Subtensor::register(<<Test as Config>::Origin>::signed(1), block_number, nonce, work, 1, 667));
+	});
+}
+
 /********************************************
 	subscribing::get_next_uid() tests
 *********************************************/
@@ -395,3 +550,316 @@ fn test_get_next_uid() {
 	});
 }
 
+
+#[test]
+fn test_coldkey_hotkeys_index() {
+	new_test_ext().execute_with(|| {
+		Subtensor::set_max_allowed_uids ( 2 );
+		Subtensor::set_immunity_period ( 0 );
+		let coldkey_a = 10;
+		let coldkey_b = 20;
+
+		// Both registrations land in the coldkey's owned set.
+		let neuron1 = register_ok_neuron( 1, coldkey_a );
+		let neuron2 = register_ok_neuron( 2, coldkey_a );
+		assert_eq!( Subtensor::coldkey_hotkeys( coldkey_a ), vec![ 1, 2 ] );
+		let owned = Subtensor::get_hotkeys_for_coldkey( &coldkey_a );
+		assert_eq!( owned.len(), 2 );
+		assert_eq!( owned[0].hotkey, 1 );
+		assert_eq!( owned[0].uid, neuron1.uid );
+		assert_eq!( owned[1].hotkey, 2 );
+		assert_eq!( owned[1].uid, neuron2.uid );
+
+		// A full graph prunes one of them, which must drop out of the index.
+		step_block ( 1 );
+		let neuron3 = register_ok_neuron( 3, coldkey_b );
+		let (pruned, kept) = if neuron3.uid == neuron1.uid { (1, 2) } else { (2, 1) };
+		assert_eq!( Subtensor::coldkey_hotkeys( coldkey_a ), vec![ kept ] );
+		assert_eq!( Subtensor::coldkey_hotkeys( coldkey_b ), vec![ 3 ] );
+		assert!( Subtensor::get_hotkeys_for_coldkey( &coldkey_a ).iter().all( |info| info.hotkey != pruned ) );
+		assert_eq!( Subtensor::get_hotkeys_for_coldkey( &100 ).len(), 0 );
+	});
+}
+
+#[test]
+fn test_pruning_clears_bonds() {
+	new_test_ext().execute_with(|| {
+		let stake_pruning_min: u128 = Subtensor::get_stake_pruning_min() as u128;
+		Subtensor::set_max_allowed_uids ( 2 );
+		Subtensor::set_immunity_period ( 0 );
+		let neuron0 = register_ok_neuron( 0, 0 );
+		let neuron1 = register_ok_neuron( 1, 1 );
+		Subtensor::set_bonds_from_matrix( vec![ vec![ 10, 20 ], vec![ 30, 40 ] ] );
+
+		// Keep uid 0 in the graph through stake so uid 1 is the one replaced.
+		Subtensor::set_stake_from_vector( vec![ stake_pruning_min, 0 ] );
+		step_block ( 1 );
+		let bonds_before: Vec<Vec<u64>> = Subtensor::get_bonds();
+		assert!( bonds_before[ neuron0.uid as usize ][ neuron1.uid as usize ] > 0 );
+		assert!( bonds_before[ neuron1.uid as usize ][ neuron0.uid as usize ] > 0 );
+
+		// Bonds held by and held in the replaced uid are gone as soon as it is replaced.
+		let neuron2 = register_ok_neuron( 2, 2 );
+		assert_eq!( neuron2.uid, neuron1.uid );
+		assert_eq!( Subtensor::get_bond( neuron0.uid, neuron2.uid ), 0 );
+		assert_eq!( Subtensor::get_bonds_for_neuron( &neuron2 ), vec![ 0, 0 ] );
+		assert_eq!( Subtensor::get_bond( neuron0.uid, neuron0.uid ), bonds_before[ 0 ][ 0 ] );
+	});
+}
+
+#[test]
+fn test_used_work_pruned_after_retention() {
+	new_test_ext().execute_with(|| {
+		step_block( 1 );
+		let block_number: u64 = 1;
+		let (nonce, work): (u64, Vec<u8>) = Subtensor::create_work_for_block_number( block_number, 0 );
+		assert_ok!(Subtensor::register(<<Test as Config>::Origin>::signed(1), block_number, nonce, work.clone(), 1, 667));
+		assert_eq!( Subtensor::usedwork( &work ), 1 );
+		assert_eq!( UsedWorkByBlock::<Test>::get( 1 ), vec![ work.clone() ] );
+
+		// Kept for the whole retention window.
+		let retention: u64 = Subtensor::get_used_work_retention();
+		step_block( retention );
+		assert_eq!( Subtensor::usedwork( &work ), 1 );
+
+		// Pruned once it falls out of it.
+		step_block( 1 );
+		assert_eq!( Subtensor::usedwork( &work ), 0 );
+		assert_eq!( UsedWorkByBlock::<Test>::get( 1 ), Vec::<Vec<u8>>::new() );
+		assert_eq!( UsedWorkPruneCursor::<Test>::get(), 2 );
+	});
+}
+
+/********************************************
+	registration::burned_register() tests
+*********************************************/
+#[test]
+fn test_burned_register_dispatch_info_ok() {
+	new_test_ext().execute_with(|| {
+		let hotkey: u64 = 0;
+		let call = Call::Subtensor(SubtensorCall::burned_register{ hotkey });
+		assert_eq!(call.get_dispatch_info(), DispatchInfo {
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::burned_register(0),
+			class: DispatchClass::Normal,
+			pays_fee: Pays::Yes
+		});
+	});
+}
+
+#[test]
+fn test_burned_register_ok() {
+	new_test_ext().execute_with(|| {
+		let hotkey: u64 = 1;
+		let coldkey: u64 = 10;
+		let burn: u128 = Subtensor::get_burn();
+		Subtensor::add_balance_to_coldkey_account( &coldkey, 10000 );
+		let issuance: u128 = Subtensor::get_total_issuance();
+
+		assert_ok!(Subtensor::burned_register(<<Test as Config>::Origin>::signed(coldkey), hotkey));
+
+		// The neuron is registered under the paying coldkey.
+		let neuron = Subtensor::get_neuron_by_hotkey( &hotkey ).unwrap();
+		assert_eq!( neuron.uid, 0 );
+		assert_eq!( neuron.coldkey, coldkey );
+		assert_eq!( Subtensor::coldkey_hotkeys( coldkey ), vec![ hotkey ] );
+		assert_eq!( Subtensor::get_registrations_this_block(), 1 );
+		assert_eq!( Subtensor::get_registrations_this_interval(), 1 );
+
+		// The burn leaves the coldkey and the issuance for good.
+		assert_eq!( Subtensor::get_coldkey_balance( &coldkey ), 10000 - burn );
+		assert_eq!( Subtensor::get_total_issuance(), issuance - burn );
+	});
+}
+
+#[test]
+fn test_burned_register_err_cannot_pay_burn() {
+	new_test_ext().execute_with(|| {
+		let coldkey: u64 = 10;
+		Subtensor::add_balance_to_coldkey_account( &coldkey, Subtensor::get_burn() - 1 );
+
+		assert_eq!( Subtensor::burned_register(<<Test as Config>::Origin>::signed(coldkey), 1), Err(Error::<Test>::NotEnoughBalanceToRegister.into()) );
+		assert!( !Subtensor::is_hotkey_active( &1 ) );
+		assert_eq!( Subtensor::get_coldkey_balance( &coldkey ), Subtensor::get_burn() - 1 );
+	});
+}
+
+#[test]
+fn test_burned_register_err_already_registered() {
+	new_test_ext().execute_with(|| {
+		register_ok_neuron( 1, 20 );
+		Subtensor::add_balance_to_coldkey_account( &10, 10000 );
+
+		assert_eq!( Subtensor::burned_register(<<Test as Config>::Origin>::signed(10), 1), Err(Error::<Test>::AlreadyRegistered.into()) );
+		assert_eq!( Subtensor::get_coldkey_balance( &10 ), 10000 );
+	});
+}
+
+#[test]
+fn test_burned_register_shares_registrations_per_block() {
+	new_test_ext().execute_with(|| {
+		Subtensor::add_balance_to_coldkey_account( &10, 10000 );
+		register_ok_neuron( 1, 20 );
+		assert_ok!(Subtensor::burned_register(<<Test as Config>::Origin>::signed(10), 2));
+		assert_eq!( Subtensor::get_registrations_this_block(), Subtensor::get_max_registratations_per_block() );
+
+		assert_eq!( Subtensor::burned_register(<<Test as Config>::Origin>::signed(10), 3), Err(Error::<Test>::ToManyRegistrationsThisBlock.into()) );
+	});
+}
+
+#[test]
+fn test_burned_register_prunes_when_full() {
+	new_test_ext().execute_with(|| {
+		Subtensor::set_max_allowed_uids( 1 );
+		Subtensor::set_immunity_period( 0 );
+		let neuron0 = register_ok_neuron( 1, 20 );
+		step_block( 1 );
+		Subtensor::add_balance_to_coldkey_account( &10, 10000 );
+
+		assert_ok!(Subtensor::burned_register(<<Test as Config>::Origin>::signed(10), 2));
+		assert_eq!( Subtensor::get_neuron_count(), 1 );
+		assert_eq!( Subtensor::get_neuron_by_hotkey( &2 ).unwrap().uid, neuron0.uid );
+		assert!( !Subtensor::is_hotkey_active( &1 ) );
+		assert_eq!( Subtensor::get_hotkeys_for_coldkey( &20 ).len(), 0 );
+	});
+}
+
+#[test]
+fn test_burn_adjustment() {
+	new_test_ext().execute_with(|| {
+		Subtensor::set_adjustment_interval( 1 );
+		Subtensor::set_target_registrations_per_interval( 1 );
+		Subtensor::add_balance_to_coldkey_account( &10, 1000000 );
+		assert_eq!( Subtensor::get_burn(), 1000 );
+
+		// Registrations above target double the burn, up to the maximum.
+		assert_ok!(Subtensor::burned_register(<<Test as Config>::Origin>::signed(10), 1));
+		assert_ok!(Subtensor::burned_register(<<Test as Config>::Origin>::signed(10), 2));
+		step_block( 1 );
+		assert_eq!( Subtensor::get_burn(), 2000 );
+		Subtensor::set_burn( 80000 );
+		assert_ok!(Subtensor::burned_register(<<Test as Config>::Origin>::signed(10), 3));
+		assert_ok!(Subtensor::burned_register(<<Test as Config>::Origin>::signed(10), 4));
+		step_block( 1 );
+		assert_eq!( Subtensor::get_burn(), Subtensor::get_max_burn() );
+
+		// Intervals without registrations halve it, down to the minimum.
+		Subtensor::set_burn( 1000 );
+		step_block( 1 );
+		assert_eq!( Subtensor::get_burn(), 500 );
+		step_block( 1 );
+		assert_eq!( Subtensor::get_burn(), 250 );
+		step_block( 1 );
+		assert_eq!( Subtensor::get_burn(), 125 );
+		step_block( 1 );
+		assert_eq!( Subtensor::get_burn(), Subtensor::get_min_burn() );
+	});
+}
+
+/********************************************
+	registration::check_registration_work() tests
+*********************************************/
+#[test]
+fn test_check_registration_work_ok_does_not_register() {
+	new_test_ext().execute_with(|| {
+		let (nonce, work): (u64, Vec<u8>) = Subtensor::create_work_for_hotkey( 0, 0, &1 );
+		assert_eq!( Subtensor::check_registration_work( 0, nonce, &work, &1 ), Ok(()) );
+		assert!( !Subtensor::is_hotkey_active( &1 ) );
+		assert_eq!( Subtensor::usedwork( &work ), 0 );
+		assert_eq!( Subtensor::get_registrations_this_block(), 0 );
+	});
+}
+
+#[test]
+fn test_check_registration_work_reasons() {
+	new_test_ext().execute_with(|| {
+		let (nonce, work): (u64, Vec<u8>) = Subtensor::create_work_for_hotkey( 0, 0, &1 );
+		assert_eq!( Subtensor::check_registration_work( 0, nonce, &work, &2 ), Err( RegistrationWorkError::SealMismatch ) );
+		assert_eq!( Subtensor::check_registration_work( 0, nonce, &vec![ 0; 5 ], &1 ), Err( RegistrationWorkError::SealMismatch ) );
+
+		let (future_nonce, future_work): (u64, Vec<u8>) = Subtensor::create_work_for_hotkey( 1, 0, &1 );
+		assert_eq!( Subtensor::check_registration_work( 1, future_nonce, &future_work, &1 ), Err( RegistrationWorkError::FutureBlock ) );
+
+		let (legacy_nonce, legacy_work): (u64, Vec<u8>) = Subtensor::create_work_for_block_number( 0, 0 );
+		Subtensor::set_legacy_seal_cutoff( 0 );
+		assert_eq!( Subtensor::check_registration_work( 0, legacy_nonce, &legacy_work, &1 ), Err( RegistrationWorkError::LegacySealExpired ) );
+
+		Subtensor::set_difficulty_from_u64( u64::MAX );
+		assert_eq!( Subtensor::check_registration_work( 0, nonce, &work, &1 ), Err( RegistrationWorkError::UnderDifficulty ) );
+
+		Subtensor::set_max_registratations_per_block( 0 );
+		assert_eq!( Subtensor::check_registration_work( 0, nonce, &work, &1 ), Err( RegistrationWorkError::BlockFull ) );
+	});
+}
+
+#[test]
+fn test_check_registration_work_after_register() {
+	new_test_ext().execute_with(|| {
+		let (nonce, work): (u64, Vec<u8>) = Subtensor::create_work_for_hotkey( 0, 0, &1 );
+		assert_ok!(Subtensor::register(<<Test as Config>::Origin>::signed(1), 0, nonce, work.clone(), 1, 667));
+		assert_eq!( Subtensor::check_registration_work( 0, nonce, &work, &1 ), Err( RegistrationWorkError::AlreadyRegistered ) );
+		assert_eq!( Subtensor::check_registration_work( 0, nonce, &work, &2 ), Err( RegistrationWorkError::WorkRepeated ) );
+
+		System::set_block_number( 3 );
+		assert_eq!( Subtensor::check_registration_work( 0, nonce, &work, &2 ), Err( RegistrationWorkError::TooOld ) );
+	});
+}
+
+// A runtime api call at block 3 runs on block 4 initialized on top of it, where a register included next runs too.
+#[test]
+fn test_check_registration_work_seal_age_matches_register() {
+	new_test_ext().execute_with(|| {
+		for block in 1..=4 {
+			initialize_block( block );
+		}
+		let (nonce, work): (u64, Vec<u8>) = Subtensor::create_work_for_hotkey( 2, 0, &1 );
+		let (old_nonce, old_work): (u64, Vec<u8>) = Subtensor::create_work_for_hotkey( 1, 0, &2 );
+		assert_eq!( Subtensor::check_registration_work( 2, nonce, &work, &1 ), Ok(()) );
+		assert_eq!( Subtensor::check_registration_work( 1, old_nonce, &old_work, &2 ), Err( RegistrationWorkError::TooOld ) );
+
+		assert_ok!( Subtensor::register(<<Test as Config>::Origin>::signed(1), 2, nonce, work, 1, 667) );
+		assert_eq!( Subtensor::register(<<Test as Config>::Origin>::signed(2), 1, old_nonce, old_work, 2, 667), Err(Error::<Test>::InvalidWorkBlock.into()) );
+	});
+}
+
+#[test]
+fn test_check_registration_work_block_full_matches_register() {
+	new_test_ext().execute_with(|| {
+		Subtensor::set_max_registratations_per_block( 1 );
+		initialize_block( 1 );
+		register_ok_neuron( 1, 667 );
+		let (nonce, work): (u64, Vec<u8>) = Subtensor::create_work_for_hotkey( 0, 0, &2 );
+		assert_eq!( Subtensor::check_registration_work( 0, nonce, &work, &2 ), Err( RegistrationWorkError::BlockFull ) );
+		assert_eq!( Subtensor::register(<<Test as Config>::Origin>::signed(2), 0, nonce, work.clone(), 2, 667), Err(Error::<Test>::ToManyRegistrationsThisBlock.into()) );
+
+		// Initializing the next block resets the count.
+		initialize_block( 2 );
+		assert_eq!( Subtensor::check_registration_work( 0, nonce, &work, &2 ), Ok(()) );
+		assert_ok!( Subtensor::register(<<Test as Config>::Origin>::signed(2), 0, nonce, work, 2, 667) );
+	});
+}
+
+#[test]
+fn test_check_registration_work_difficulty_matches_register() {
+	new_test_ext().execute_with(|| {
+		// Two registrations against a target of one double the difficulty when block 2 is initialized.
+		Subtensor::set_adjustment_interval( 1 );
+		Subtensor::set_target_registrations_per_interval( 1 );
+		initialize_block( 1 );
+		register_ok_neuron( 1, 667 );
+		register_ok_neuron( 2, 667 );
+		let difficulty: u64 = Subtensor::get_difficulty_as_u64();
+		initialize_block( 2 );
+		assert_eq!( Subtensor::get_difficulty_as_u64(), difficulty * 2 );
+
+		// Work meeting the old difficulty but not the adjusted one, and work meeting both.
+		let meets = | nonce: u64, difficulty: u64 | Subtensor::hash_meets_difficulty( &Subtensor::create_seal_hash_for_hotkey( 0, nonce, &3 ), U256::from( difficulty ) );
+		let easy_nonce: u64 = ( 0.. ).find( |nonce| meets( *nonce, difficulty ) && !meets( *nonce, difficulty * 2 ) ).unwrap();
+		let hard_nonce: u64 = ( 0.. ).find( |nonce| meets( *nonce, difficulty * 2 ) ).unwrap();
+		let easy_work: Vec<u8> = Subtensor::create_seal_hash_for_hotkey( 0, easy_nonce, &3 ).as_bytes().to_vec();
+		let hard_work: Vec<u8> = Subtensor::create_seal_hash_for_hotkey( 0, hard_nonce, &3 ).as_bytes().to_vec();
+		assert_eq!( Subtensor::check_registration_work( 0, easy_nonce, &easy_work, &3 ), Err( RegistrationWorkError::UnderDifficulty ) );
+		assert_eq!( Subtensor::register(<<Test as Config>::Origin>::signed(3), 0, easy_nonce, easy_work, 3, 667), Err(Error::<Test>::InvalidDifficulty.into()) );
+		assert_eq!( Subtensor::check_registration_work( 0, hard_nonce, &hard_work, &3 ), Ok(()) );
+		assert_ok!( Subtensor::register(<<Test as Config>::Origin>::signed(3), 0, hard_nonce, hard_work, 3, 667) );
+	});
+}
@@ -0,0 +1,81 @@
+use pallet_subtensor::{HYPERPARAMS_VERSION, SEAL_VERSION};
+use frame_support::{assert_ok};
+use frame_system::Config;
+mod mock;
+use mock::*;
+
+/********************************************
+	rpc_info::get_neuron() tests
+*********************************************/
+#[test]
+fn test_get_neuron_none_for_unknown_uid() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Subtensor::get_neuron(0), None);
+		assert_eq!(Subtensor::get_neurons().len(), 0);
+	});
+}
+
+#[test]
+fn test_get_neuron_matches_storage() {
+	new_test_ext().execute_with(|| {
+		let hotkey_account_id = 1;
+		let coldkey_account_id = 667;
+		let neuron = register_ok_neuron( hotkey_account_id, coldkey_account_id );
+		assert_eq!(Subtensor::get_neuron(neuron.uid), Some(neuron.clone()));
+		assert_eq!(Subtensor::get_neuron_by_hotkey(&hotkey_account_id), Some(neuron));
+		assert_eq!(Subtensor::get_neuron_by_hotkey(&coldkey_account_id), None);
+	});
+}
+
+/********************************************
+	rpc_info::get_neurons() tests
+*********************************************/
+#[test]
+fn test_get_neurons_ordered_by_uid() {
+	new_test_ext().execute_with(|| {
+		register_ok_neuron( 1, 10 );
+		register_ok_neuron( 2, 20 );
+		step_block(1);
+		register_ok_neuron( 3, 30 );
+		let neurons = Subtensor::get_neurons();
+		assert_eq!(neurons.len(), 3);
+		for (i, neuron) in neurons.iter().enumerate() {
+			assert_eq!(neuron.uid, i as u32);
+			assert_eq!(neuron.hotkey, (i + 1) as u64);
+		}
+	});
+}
+
+/********************************************
+	rpc_info::get_hyperparameters() tests
+*********************************************/
+#[test]
+fn test_get_hyperparameters_defaults() {
+	new_test_ext().execute_with(|| {
+		let hyperparams = Subtensor::get_hyperparameters();
+		assert_eq!(hyperparams.version, HYPERPARAMS_VERSION);
+		assert_eq!(hyperparams.rho, Subtensor::get_rho());
+		assert_eq!(hyperparams.kappa, Subtensor::get_kappa());
+		assert_eq!(hyperparams.immunity_period, Subtensor::get_immunity_period());
+		assert_eq!(hyperparams.max_allowed_uids, Subtensor::get_max_allowed_uids());
+		assert_eq!(hyperparams.difficulty, Subtensor::get_difficulty_as_u64());
+		assert_eq!(hyperparams.scaling_law_power, Subtensor::get_scaling_law_power());
+		assert_eq!(hyperparams.seal_version, SEAL_VERSION);
+		assert_eq!(hyperparams.legacy_seal_cutoff, Subtensor::get_legacy_seal_cutoff());
+		assert_eq!(hyperparams.min_difficulty, Subtensor::get_min_difficulty());
+		assert_eq!(hyperparams.max_difficulty, Subtensor::get_max_difficulty());
+		assert_eq!(hyperparams.min_burn, Subtensor::get_min_burn());
+		assert_eq!(hyperparams.max_burn, Subtensor::get_max_burn());
+	});
+}
+
+#[test]
+fn test_get_hyperparameters_tracks_sudo() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Subtensor::sudo_set_rho(<<Test as Config>::Origin>::root(), 11));
+		assert_ok!(Subtensor::sudo_set_validator_batch_size(<<Test as Config>::Origin>::root(), 17));
+		let hyperparams = Subtensor::get_hyperparameters();
+		assert_eq!(hyperparams.rho, 11);
+		assert_eq!(hyperparams.validator_batch_size, 17);
+	});
+}
@@ -1,4 +1,4 @@
-use pallet_subtensor::{Error};
+use pallet_subtensor::{Error, WeightInfo};
 use frame_support::{assert_ok};
 use frame_system::Config;
 mod mock;
@@ -19,7 +19,7 @@ fn test_serve_ok_dispatch_info_ok() {
         let modality = 0;
         let call = Call::Subtensor(SubtensorCall::serve_axon{version, ip, port, ip_type, modality});
 		assert_eq!(call.get_dispatch_info(), DispatchInfo {
-			weight: 0,
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::serve_axon(),
 			class: DispatchClass::Normal,
 			pays_fee: Pays::No
 		});
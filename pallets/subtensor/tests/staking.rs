@@ -1,10 +1,10 @@
-use frame_support::{assert_ok};
+use frame_support::{assert_ok, traits::Currency};
 use frame_system::{Config};
 mod mock;
 use mock::*;
 use mock::{TestXt};
 use frame_support::sp_runtime::DispatchError;
-use pallet_subtensor::{Error, Call as SubtensorCall};
+use pallet_subtensor::{Error, Call as SubtensorCall, WeightInfo, UnlockChunk, EmissionDestination, MAX_UNBONDING_CHUNKS};
 use frame_support::weights::{GetDispatchInfo, DispatchInfo, DispatchClass, Pays};
 
 /***********************************************************
@@ -19,7 +19,7 @@ fn test_add_stake_dispatch_info_ok() {
 		let ammount_staked = 5000;
         let call = Call::Subtensor(SubtensorCall::add_stake{hotkey, ammount_staked});
 		assert_eq!(call.get_dispatch_info(), DispatchInfo {
-			weight: 0,
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::add_stake(),
 			class: DispatchClass::Normal,
 			pays_fee: Pays::No
 		});
@@ -91,6 +91,25 @@ fn test_add_stake_ok_no_emission() {
 	});
 }
 
+#[test]
+fn test_add_stake_holds_stake_as_reserve() {
+	new_test_ext().execute_with(|| {
+		let hotkey_account_id = 533453;
+		let coldkey_account_id = 55453;
+		register_ok_neuron( hotkey_account_id, coldkey_account_id);
+		Subtensor::add_balance_to_coldkey_account( &coldkey_account_id, 10000 );
+		let issuance_before = Balances::total_issuance();
+
+		assert_ok!(Subtensor::add_stake(<<Test as Config>::Origin>::signed(coldkey_account_id), hotkey_account_id, 6000));
+
+		// The stake stays on the coldkey, so the balances pallet still counts it
+		assert_eq!(Subtensor::get_coldkey_balance(&coldkey_account_id), 4000);
+		assert_eq!(Subtensor::get_reserved_stake(&coldkey_account_id), 6000);
+		assert_eq!(Balances::total_balance(&coldkey_account_id), 10000);
+		assert_eq!(Balances::total_issuance(), issuance_before);
+	});
+}
+
 #[test]
 fn test_dividends_with_run_to_block() {
 	new_test_ext().execute_with(|| {
@@ -98,7 +117,7 @@ fn test_dividends_with_run_to_block() {
 		let neuron_dest_hotkey_id = 2;
 		let coldkey_account_id = 667;
 
-		let initial_stake:u64 = 5000;
+		let initial_stake:u128 = 5000;
 
 		// Subscribe neuron, this will set a self weight
 		Subtensor::set_max_registratations_per_block( 3 );
@@ -192,7 +211,7 @@ fn test_remove_stake_dispatch_info_ok() {
 		let call = Call::Subtensor(SubtensorCall::remove_stake{hotkey, ammount_unstaked});
 
 		assert_eq!(call.get_dispatch_info(), DispatchInfo {
-			weight: 0,
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::remove_stake(),
 			class: DispatchClass::Normal,
 			pays_fee: Pays::No
 		});
@@ -215,13 +234,24 @@ fn test_remove_stake_ok_no_emission() {
 		assert_eq!(Subtensor::get_coldkey_balance(&coldkey_account_id), 0);
 
 		// Give the neuron some stake to remove
-		Subtensor::add_stake_to_neuron_hotkey_account(neuron.uid, amount);
+		Subtensor::add_balance_to_coldkey_account(&coldkey_account_id, amount);
+		assert_ok!(Subtensor::add_stake(<<Test as Config>::Origin>::signed(coldkey_account_id), hotkey_account_id, amount));
 
 		// Do the magic
 		assert_ok!(Subtensor::remove_stake(<<Test as Config>::Origin>::signed(coldkey_account_id), hotkey_account_id, amount));
 
-		assert_eq!(Subtensor::get_coldkey_balance(&coldkey_account_id), amount as u128);
+		// The stake leaves consensus at once but only reaches the balance after the unbonding period
+		let unlock_block = Subtensor::get_current_block_as_u64() + Subtensor::get_unbonding_period();
 		assert_eq!(Subtensor::get_stake_of_neuron_hotkey_account_by_uid(neuron.uid), 0);
+		assert_eq!(Subtensor::get_total_stake(), 0);
+		assert_eq!(Subtensor::get_coldkey_balance(&coldkey_account_id), 0);
+		assert_eq!(Subtensor::get_reserved_stake(&coldkey_account_id), amount);
+		assert_eq!(Subtensor::get_pending_unlocks(&coldkey_account_id), vec![UnlockChunk { amount, unlock_block }]);
+
+		run_to_block(unlock_block);
+		assert_ok!(Subtensor::withdraw_unbonded(<<Test as Config>::Origin>::signed(coldkey_account_id)));
+		assert_eq!(Subtensor::get_coldkey_balance(&coldkey_account_id), amount);
+		assert_eq!(Subtensor::get_pending_unlocks(&coldkey_account_id), vec![]);
 	});
 }
 
@@ -279,6 +309,264 @@ fn test_remove_stake_no_enough_stake() {
 	});
 }
 
+#[test]
+fn test_remove_stake_err_too_many_unbonding_chunks() {
+	new_test_ext().execute_with(|| {
+		let coldkey_id = 544;
+		let hotkey_id = 54544;
+		let neuron = register_ok_neuron( hotkey_id, coldkey_id );
+		Subtensor::add_stake_to_neuron_hotkey_account(neuron.uid, 10000);
+
+		// Each block opens a new chunk
+		for _ in 0..MAX_UNBONDING_CHUNKS {
+			assert_ok!(Subtensor::remove_stake(<<Test as Config>::Origin>::signed(coldkey_id), hotkey_id, 100));
+			step_block(1);
+		}
+		// Removing twice in a block shares the newest chunk, so this one is refused
+		let result = Subtensor::remove_stake(<<Test as Config>::Origin>::signed(coldkey_id), hotkey_id, 100);
+		assert_eq!(result, Err(Error::<Test>::TooManyUnbondingChunks.into()));
+		assert_eq!(Subtensor::get_pending_unlocks(&coldkey_id).len() as u32, MAX_UNBONDING_CHUNKS);
+		assert_eq!(Subtensor::get_unbonding_stake(&coldkey_id), 100 * MAX_UNBONDING_CHUNKS as u128);
+	});
+}
+
+/***********************************************************
+	staking::move_stake() tests
+************************************************************/
+
+#[test]
+fn test_move_stake_dispatch_info_ok() {
+	new_test_ext().execute_with(|| {
+		let from_hotkey = 0;
+		let to_hotkey = 1;
+		let ammount_moved = 5000;
+		let call = Call::Subtensor(SubtensorCall::move_stake{from_hotkey, to_hotkey, ammount_moved});
+		assert_eq!(call.get_dispatch_info(), DispatchInfo {
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::move_stake(),
+			class: DispatchClass::Normal,
+			pays_fee: Pays::Yes
+		});
+	});
+}
+
+#[test]
+fn test_move_stake_ok() {
+	new_test_ext().execute_with(|| {
+		let coldkey_id = 544;
+		let from_hotkey = 54544;
+		let to_hotkey = 54545;
+		let from_neuron = register_ok_neuron( from_hotkey, coldkey_id );
+		let to_neuron = register_ok_neuron( to_hotkey, coldkey_id );
+		Subtensor::add_stake_to_neuron_hotkey_account(from_neuron.uid, 10000);
+
+		assert_ok!(Subtensor::move_stake(<<Test as Config>::Origin>::signed(coldkey_id), from_hotkey, to_hotkey, 4000));
+
+		assert_eq!(Subtensor::get_stake_of_neuron_hotkey_account_by_uid(from_neuron.uid), 6000);
+		assert_eq!(Subtensor::get_stake_of_neuron_hotkey_account_by_uid(to_neuron.uid), 4000);
+		assert_eq!(Subtensor::get_total_stake(), 10000);
+		assert_eq!(Subtensor::get_coldkey_balance(&coldkey_id), 0);
+		assert_eq!(Subtensor::get_pending_unlocks(&coldkey_id), vec![]);
+	});
+}
+
+#[test]
+fn test_move_stake_err_not_registered() {
+	new_test_ext().execute_with(|| {
+		let coldkey_id = 544;
+		let from_hotkey = 54544;
+		let neuron = register_ok_neuron( from_hotkey, coldkey_id );
+		Subtensor::add_stake_to_neuron_hotkey_account(neuron.uid, 10000);
+
+		let result = Subtensor::move_stake(<<Test as Config>::Origin>::signed(coldkey_id), from_hotkey, 999, 1000);
+		assert_eq!(result, Err(Error::<Test>::NotRegistered.into()));
+		let result = Subtensor::move_stake(<<Test as Config>::Origin>::signed(coldkey_id), 999, from_hotkey, 1000);
+		assert_eq!(result, Err(Error::<Test>::NotRegistered.into()));
+	});
+}
+
+#[test]
+fn test_move_stake_err_hotkey_of_other_coldkey() {
+	new_test_ext().execute_with(|| {
+		let coldkey_id = 544;
+		let other_coldkey_id = 545;
+		let from_hotkey = 54544;
+		let to_hotkey = 54545;
+		let from_neuron = register_ok_neuron( from_hotkey, coldkey_id );
+		let to_neuron = register_ok_neuron( to_hotkey, other_coldkey_id );
+		Subtensor::add_stake_to_neuron_hotkey_account(from_neuron.uid, 10000);
+
+		// Neither coldkey owns both hotkeys
+		let result = Subtensor::move_stake(<<Test as Config>::Origin>::signed(coldkey_id), from_hotkey, to_hotkey, 1000);
+		assert_eq!(result, Err(Error::<Test>::NonAssociatedColdKey.into()));
+		let result = Subtensor::move_stake(<<Test as Config>::Origin>::signed(other_coldkey_id), from_hotkey, to_hotkey, 1000);
+		assert_eq!(result, Err(Error::<Test>::NonAssociatedColdKey.into()));
+		assert_eq!(Subtensor::get_stake_of_neuron_hotkey_account_by_uid(from_neuron.uid), 10000);
+		assert_eq!(Subtensor::get_stake_of_neuron_hotkey_account_by_uid(to_neuron.uid), 0);
+	});
+}
+
+#[test]
+fn test_move_stake_err_not_enough_stake() {
+	new_test_ext().execute_with(|| {
+		let coldkey_id = 544;
+		let from_hotkey = 54544;
+		let to_hotkey = 54545;
+		let from_neuron = register_ok_neuron( from_hotkey, coldkey_id );
+		register_ok_neuron( to_hotkey, coldkey_id );
+		Subtensor::add_stake_to_neuron_hotkey_account(from_neuron.uid, 1000);
+
+		let result = Subtensor::move_stake(<<Test as Config>::Origin>::signed(coldkey_id), from_hotkey, to_hotkey, 1001);
+		assert_eq!(result, Err(Error::<Test>::NotEnoughStaketoWithdraw.into()));
+	});
+}
+
+/***********************************************************
+	staking::set_emission_destination() tests
+************************************************************/
+
+#[test]
+fn test_set_emission_destination_dispatch_info_ok() {
+	new_test_ext().execute_with(|| {
+		let hotkey = 0;
+		let destination = EmissionDestination::Coldkey;
+		let call = Call::Subtensor(SubtensorCall::set_emission_destination{hotkey, destination});
+		assert_eq!(call.get_dispatch_info(), DispatchInfo {
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::set_emission_destination(),
+			class: DispatchClass::Normal,
+			pays_fee: Pays::Yes
+		});
+	});
+}
+
+#[test]
+fn test_set_emission_destination_ok() {
+	new_test_ext().execute_with(|| {
+		let coldkey_id = 544;
+		let hotkey_id = 54544;
+		register_ok_neuron( hotkey_id, coldkey_id );
+		assert_eq!(Subtensor::emission_destination( &hotkey_id ), EmissionDestination::Stake);
+
+		assert_ok!(Subtensor::set_emission_destination(<<Test as Config>::Origin>::signed(coldkey_id), hotkey_id, EmissionDestination::Coldkey));
+		assert_eq!(Subtensor::emission_destination( &hotkey_id ), EmissionDestination::Coldkey);
+
+		assert_ok!(Subtensor::set_emission_destination(<<Test as Config>::Origin>::signed(coldkey_id), hotkey_id, EmissionDestination::Stake));
+		assert_eq!(Subtensor::emission_destination( &hotkey_id ), EmissionDestination::Stake);
+	});
+}
+
+#[test]
+fn test_set_emission_destination_err_not_registered() {
+	new_test_ext().execute_with(|| {
+		let result = Subtensor::set_emission_destination(<<Test as Config>::Origin>::signed(544), 54544, EmissionDestination::Coldkey);
+		assert_eq!(result, Err(Error::<Test>::NotRegistered.into()));
+	});
+}
+
+#[test]
+fn test_set_emission_destination_err_not_owner() {
+	new_test_ext().execute_with(|| {
+		let hotkey_id = 54544;
+		register_ok_neuron( hotkey_id, 544 );
+		let result = Subtensor::set_emission_destination(<<Test as Config>::Origin>::signed(545), hotkey_id, EmissionDestination::Coldkey);
+		assert_eq!(result, Err(Error::<Test>::NonAssociatedColdKey.into()));
+		assert_eq!(Subtensor::emission_destination( &hotkey_id ), EmissionDestination::Stake);
+	});
+}
+
+#[test]
+fn test_emission_paid_to_coldkey() {
+	new_test_ext().execute_with(|| {
+		let coldkey_id = 544;
+		let hotkey_id = 54544;
+		let neuron = register_ok_neuron( hotkey_id, coldkey_id );
+		Subtensor::add_balance_to_coldkey_account( &coldkey_id, 10000 );
+		assert_ok!(Subtensor::add_stake(<<Test as Config>::Origin>::signed(coldkey_id), hotkey_id, 10000));
+		assert_ok!(Subtensor::set_emission_destination(<<Test as Config>::Origin>::signed(coldkey_id), hotkey_id, EmissionDestination::Coldkey));
+		let issuance_before = Balances::total_issuance();
+
+		// The emission is minted into the free balance and the stake is unchanged.
+		assert_eq!(Subtensor::mint_emission_for_uid( neuron.uid, 500 ), 0);
+		assert_eq!(Subtensor::get_coldkey_balance( &coldkey_id ), 500);
+		assert_eq!(Subtensor::get_reserved_stake( &coldkey_id ), 10000);
+		assert_eq!(Subtensor::get_stake_of_neuron_hotkey_account_by_uid( neuron.uid ), 10000);
+		assert_eq!(Balances::total_issuance(), issuance_before + 500);
+	});
+}
+
+/***********************************************************
+	staking::withdraw_unbonded() tests
+************************************************************/
+
+#[test]
+fn test_withdraw_unbonded_dispatch_info_ok() {
+	new_test_ext().execute_with(|| {
+		let call = Call::Subtensor(SubtensorCall::withdraw_unbonded{});
+		assert_eq!(call.get_dispatch_info(), DispatchInfo {
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::withdraw_unbonded(),
+			class: DispatchClass::Normal,
+			pays_fee: Pays::Yes
+		});
+	});
+}
+
+#[test]
+fn test_withdraw_unbonded_err_nothing_unlocked() {
+	new_test_ext().execute_with(|| {
+		let coldkey_id = 544;
+		let hotkey_id = 54544;
+		let neuron = register_ok_neuron( hotkey_id, coldkey_id );
+		Subtensor::add_stake_to_neuron_hotkey_account(neuron.uid, 10000);
+
+		// Nothing queued
+		assert_eq!(Subtensor::withdraw_unbonded(<<Test as Config>::Origin>::signed(coldkey_id)), Err(Error::<Test>::NoUnlockedStake.into()));
+
+		// Queued but still locked one block before the end of the period
+		assert_ok!(Subtensor::remove_stake(<<Test as Config>::Origin>::signed(coldkey_id), hotkey_id, 10000));
+		step_block(Subtensor::get_unbonding_period() - 1);
+		assert_eq!(Subtensor::withdraw_unbonded(<<Test as Config>::Origin>::signed(coldkey_id)), Err(Error::<Test>::NoUnlockedStake.into()));
+		assert_eq!(Subtensor::get_coldkey_balance(&coldkey_id), 0);
+		assert_eq!(Subtensor::get_unbonding_stake(&coldkey_id), 10000);
+	});
+}
+
+#[test]
+fn test_withdraw_unbonded_releases_only_unlocked_chunks() {
+	new_test_ext().execute_with(|| {
+		let coldkey_id = 544;
+		let hotkey_id = 54544;
+		let neuron = register_ok_neuron( hotkey_id, coldkey_id );
+		Subtensor::add_balance_to_coldkey_account(&coldkey_id, 10000);
+		assert_ok!(Subtensor::add_stake(<<Test as Config>::Origin>::signed(coldkey_id), hotkey_id, 10000));
+		assert_eq!(Subtensor::get_coldkey_balance(&coldkey_id), 0);
+		let period = Subtensor::get_unbonding_period();
+
+		// Two removals in the same block are merged into one chunk
+		let first_unlock = Subtensor::get_current_block_as_u64() + period;
+		assert_ok!(Subtensor::remove_stake(<<Test as Config>::Origin>::signed(coldkey_id), hotkey_id, 1000));
+		assert_ok!(Subtensor::remove_stake(<<Test as Config>::Origin>::signed(coldkey_id), hotkey_id, 2000));
+		assert_eq!(Subtensor::get_stake_of_neuron_hotkey_account_by_uid(neuron.uid), 7000);
+		assert_eq!(Subtensor::get_total_stake(), 7000);
+		step_block(2);
+		let second_unlock = Subtensor::get_current_block_as_u64() + period;
+		assert_ok!(Subtensor::remove_stake(<<Test as Config>::Origin>::signed(coldkey_id), hotkey_id, 4000));
+		assert_eq!(Subtensor::get_pending_unlocks(&coldkey_id), vec![
+			UnlockChunk { amount: 3000, unlock_block: first_unlock },
+			UnlockChunk { amount: 4000, unlock_block: second_unlock },
+		]);
+
+		// Only the first chunk has unlocked
+		run_to_block(first_unlock);
+		assert_ok!(Subtensor::withdraw_unbonded(<<Test as Config>::Origin>::signed(coldkey_id)));
+		assert_eq!(Subtensor::get_coldkey_balance(&coldkey_id), 3000);
+		assert_eq!(Subtensor::get_pending_unlocks(&coldkey_id), vec![UnlockChunk { amount: 4000, unlock_block: second_unlock }]);
+
+		run_to_block(second_unlock);
+		assert_ok!(Subtensor::withdraw_unbonded(<<Test as Config>::Origin>::signed(coldkey_id)));
+		assert_eq!(Subtensor::get_coldkey_balance(&coldkey_id), 7000);
+		assert_eq!(Subtensor::get_unbonding_stake(&coldkey_id), 0);
+	});
+}
+
 
 /***********************************************************
 	staking::get_coldkey_balance() tests
@@ -322,7 +610,7 @@ fn test_add_stake_to_neuron_hotkey_account_ok() {
 	new_test_ext().execute_with(|| {
 		let hotkey_id = 5445;
 		let coldkey_id = 5443433;
-		let amount: u64 = 10000;
+		let amount: u128 = 10000;
 
 		let neuron = register_ok_neuron( hotkey_id, coldkey_id);
 
@@ -348,7 +636,7 @@ fn test_remove_stake_from_hotkey_account() {
 	new_test_ext().execute_with(|| {
         let hotkey_id = 5445;
 		let coldkey_id = 5443433;
-		let amount: u64 = 10000;
+		let amount: u128 = 10000;
 
 		let neuron = register_ok_neuron( hotkey_id, coldkey_id);
 
@@ -389,8 +677,8 @@ fn test_increase_total_stake_ok() {
 #[should_panic]
 fn test_increase_total_stake_panic_overflow() {
 	new_test_ext().execute_with(|| {
-        let initial_total_stake = u64::MAX;
-		let increment : u64 = 1;
+        let initial_total_stake = u128::MAX;
+		let increment : u128 = 1;
 
 		// Setup initial total stake
 		Subtensor::increase_total_stake(initial_total_stake);
@@ -1,5 +1,8 @@
 mod mock;
 use mock::*;
+use frame_support::traits::Hooks;
+use pallet_subtensor::{WeightInfo, EmissionDestination};
+use frame_support::assert_ok;
 
 /***********************************************************
 	staking::add_stake() tests
@@ -10,7 +13,7 @@ use mock::*;
 fn test_step_with_many() {
     new_test_ext().execute_with( || {
         Subtensor::set_max_registratations_per_block( 100 );
-        let initial_stake:u64 = 1000000000;
+        let initial_stake:u128 = 1000000000;
         for i in 0..4 { let nonce:u64 = 1000000000*i; register_ok_neuron_with_nonce(i as u64, i as u64, nonce); }
         // Set weights.
         let weights_matrix: Vec<Vec<u32>> = vec! [
@@ -56,7 +59,7 @@ fn test_step_with_many() {
 fn test_step_with_many_zero_weights() {
     new_test_ext().execute_with( || {
         Subtensor::set_max_registratations_per_block( 100 );
-        let initial_stake:u64 = 1000000000;
+        let initial_stake:u128 = 1000000000;
         for i in 0..4 { let nonce:u64 = 1000000000*i; register_ok_neuron_with_nonce(i as u64, i as u64, nonce); }
         // Set stake.
         Subtensor::set_stake_from_vector( vec![ initial_stake; 4 ] );
@@ -102,7 +105,7 @@ fn test_step_with_many_zero_weights() {
 fn test_step_with_many_self_weights() {
     new_test_ext().execute_with( || {
         Subtensor::set_max_registratations_per_block( 100 );
-        let initial_stake:u64 = 1000000000;
+        let initial_stake:u128 = 1000000000;
         for i in 0..4 { let nonce:u64 = 1000000000*i; register_ok_neuron_with_nonce(i as u64, i as u64, nonce); }
         // Set stake.
         Subtensor::set_stake_from_vector( vec![ initial_stake; 4 ] );
@@ -134,7 +137,7 @@ fn test_step_with_many_self_weights() {
     });
 }
 
-pub fn approx_equals( a:u64, b: u64, eps: u64 ) -> bool {
+pub fn approx_equals( a:u128, b: u128, eps: u128 ) -> bool {
     if a > b {
         if a - b > eps {
             println!("a({:?}) - b({:?}) > {:?}", a, b, eps);
@@ -150,16 +153,16 @@ pub fn approx_equals( a:u64, b: u64, eps: u64 ) -> bool {
     return true;
 }
 
-pub fn vec_approx_equals( a_vec: &Vec<u64>, b_vec: &Vec<u64>, eps: u64 ) -> bool {
+pub fn vec_approx_equals<N: Copy + Into<u128>>( a_vec: &Vec<N>, b_vec: &Vec<N>, eps: u128 ) -> bool {
     for (a, b) in a_vec.iter().zip(b_vec.iter()) {
-        if !approx_equals( *a, *b, eps ){
+        if !approx_equals( (*a).into(), (*b).into(), eps ){
             return false;
         }
     }
     return true;
 }
 
-pub fn mat_approx_equals( a_vec: &Vec<Vec<u64>>, b_vec: &Vec<Vec<u64>>, eps: u64 ) -> bool {
+pub fn mat_approx_equals( a_vec: &Vec<Vec<u64>>, b_vec: &Vec<Vec<u64>>, eps: u128 ) -> bool {
     for (a, b) in a_vec.iter().zip(b_vec.iter()) {
         if !vec_approx_equals( a, b, eps ){
             return false;
@@ -168,11 +171,69 @@ pub fn mat_approx_equals( a_vec: &Vec<Vec<u64>>, b_vec: &Vec<Vec<u64>>, eps: u64
     return true;
 }
 
+// Tests the step mints the emission and holds it on the coldkeys as stake.
+#[test]
+fn test_step_mints_emission_as_reserved_stake() {
+    new_test_ext().execute_with( || {
+        Subtensor::set_max_registratations_per_block( 100 );
+        for i in 0..4 { let nonce:u64 = 1000000000*i; register_ok_neuron_with_nonce(i as u64, i as u64, nonce); }
+        Subtensor::set_stake_from_vector( vec![ 1000000000; 4 ] );
+        Subtensor::set_weights_from_matrix( vec! [
+            vec! [0, u32::max_value(), 0, 0 ],
+            vec! [0, 0, u32::max_value(), 0 ],
+            vec! [0, 0, 0, u32::max_value() ], 
+            vec! [u32::max_value(), 0, 0, 0 ],
+        ]);
+
+        step_block (1);
+
+        let emission: Vec<u64> = Subtensor::get_emission();
+        let total_emission: u64 = emission.iter().sum();
+        assert!( total_emission > 0 );
+        for i in 0..4 {
+            assert_eq!( Subtensor::get_reserved_stake( &(i as u64) ), emission[ i ] as u128 );
+        }
+        // Our issuance grows by exactly what the balances pallet minted.
+        assert_eq!( Balances::total_issuance(), total_emission as u128 );
+        assert_eq!( Subtensor::get_total_issuance(), Subtensor::get_initial_total_issuance() + total_emission as u128 );
+    });
+}
+
+// Tests the step pays the emission of a neuron set to pay out into its coldkey's free balance.
+#[test]
+fn test_step_pays_emission_to_coldkey() {
+    new_test_ext().execute_with( || {
+        Subtensor::set_max_registratations_per_block( 100 );
+        for i in 0..4 { let nonce:u64 = 1000000000*i; register_ok_neuron_with_nonce(i as u64, i as u64, nonce); }
+        Subtensor::set_stake_from_vector( vec![ 1000000000; 4 ] );
+        Subtensor::set_weights_from_matrix( vec! [
+            vec! [0, u32::max_value(), 0, 0 ],
+            vec! [0, 0, u32::max_value(), 0 ],
+            vec! [0, 0, 0, u32::max_value() ], 
+            vec! [u32::max_value(), 0, 0, 0 ],
+        ]);
+        assert_ok!( Subtensor::set_emission_destination( <<Test as frame_system::Config>::Origin>::signed(0), 0, EmissionDestination::Coldkey ) );
+
+        step_block (1);
+
+        let emission: Vec<u64> = Subtensor::get_emission();
+        let total_emission: u64 = emission.iter().sum();
+        assert!( emission[ 0 ] > 0 );
+        assert_eq!( Subtensor::get_coldkey_balance( &0 ), emission[ 0 ] as u128 );
+        assert_eq!( Subtensor::get_reserved_stake( &0 ), 0 );
+        assert_eq!( Subtensor::get_stake()[ 0 ], 1000000000 );
+
+        // The payout is issued but not staked.
+        assert_eq!( Subtensor::get_total_stake(), 4000000000 + (total_emission - emission[ 0 ]) as u128 );
+        assert_eq!( Subtensor::get_total_issuance(), Subtensor::get_initial_total_issuance() + total_emission as u128 );
+    });
+}
+
 #[test]
 fn test_two_steps_with_many_outward_weights() {
     new_test_ext().execute_with( || {
         Subtensor::set_max_registratations_per_block( 100 );
-        let initial_stake:u64 = 1000000000;
+        let initial_stake:u128 = 1000000000;
         let u64m: u64 = 18446744073709551615;
         for i in 0..4 { let nonce:u64 = 1000000000*i; register_ok_neuron_with_nonce(i as u64, i as u64, nonce); }
         // Set stake.
@@ -259,7 +320,7 @@ fn test_two_steps_with_many_outward_weights() {
 fn test_two_steps_with_reset_bonds() {
     new_test_ext().execute_with( || {
         Subtensor::set_max_registratations_per_block( 100 );
-        let initial_stake:u64 = 1000000000;
+        let initial_stake:u128 = 1000000000;
         for i in 0..4 { let nonce:u64 = 1000000000*i; register_ok_neuron_with_nonce(i as u64, i as u64, nonce); }
         // Set stake.
         Subtensor::set_stake_from_vector( vec![ initial_stake; 4 ] );
@@ -309,7 +370,7 @@ fn test_two_steps_with_reset_bonds() {
 // fn test_steps_with_foundation_distribution() {
 //     new_test_ext().execute_with( || {
 //         Subtensor::set_max_registratations_per_block( 100 );
-//         let initial_stake:u64 = 1000000000;
+//         let initial_stake:u128 = 1000000000;
 //         for i in 0..4 {
 //             register_ok_neuron(i as u64, i as u64 );
 //         }
@@ -360,7 +421,7 @@ fn test_two_steps_with_reset_bonds() {
 fn test_step_only_every_3_with_many_outward_weights() {
     new_test_ext().execute_with( || {
         Subtensor::set_max_registratations_per_block( 100 );
-        let initial_stake:u64 = 1000000000;
+        let initial_stake:u128 = 1000000000;
         for i in 0..4 { let nonce:u64 = 1000000000*i; register_ok_neuron_with_nonce(i as u64, i as u64, nonce); }
         let weights_matrix: Vec<Vec<u32>> = vec! [
             vec! [0, u32::max_value(), 0, 0 ],
@@ -431,7 +492,7 @@ fn test_step_only_every_3_with_many_outward_weights() {
 fn test_two_steps_with_activity_cuttoff() {
     new_test_ext().execute_with( || {
         Subtensor::set_max_registratations_per_block( 100 );
-        let initial_stake:u64 = 1000000000;
+        let initial_stake:u128 = 1000000000;
         let u64m: u64 = 18446744073709551615;
         for i in 0..4 { let nonce:u64 = 1000000000*i; register_ok_neuron_with_nonce(i as u64, i as u64, nonce); }
         // Set stake.
@@ -518,7 +579,7 @@ fn test_two_steps_with_activity_cuttoff() {
 fn test_two_steps_with_partial_activity() {
     new_test_ext().execute_with( || {
         Subtensor::set_max_registratations_per_block( 100 );
-        let initial_stake:u64 = 1000000000;
+        let initial_stake:u128 = 1000000000;
         let u64m: u64 = 18446744073709551615;
         for i in 0..4 { let nonce:u64 = 1000000000*i; register_ok_neuron_with_nonce(i as u64, i as u64, nonce); }
         // Set stake.
@@ -640,7 +701,7 @@ fn test_step_with_neuron_no_balances() {
 fn test_step_with_neuron_with_balances() {
     let coldkey:u64 = 1;
     let hotkey:u64= 2;
-    let initial_stake:u64 = 1000000000;
+    let initial_stake:u128 = 1000000000;
     new_test_ext().execute_with( || {
         Subtensor::set_max_registratations_per_block( 100 );
         let neuron = register_ok_neuron( hotkey, coldkey );
@@ -670,3 +731,28 @@ fn test_step_with_neuron_with_balances() {
         assert_eq!( Subtensor::get_dividends(), vec![0] );
     });
 }
+
+// Tests the step is charged for the neurons and entries it actually touched.
+#[test]
+fn test_mechanism_step_weight_counts_entries() {
+    new_test_ext().execute_with( || {
+        Subtensor::set_max_registratations_per_block( 100 );
+        assert_eq!( Subtensor::mechanism_step( 0 ), <Test as pallet_subtensor::Config>::WeightInfo::mechanism_step( 0, 0, 0 ) );
+        for i in 0..4 { let nonce:u64 = 1000000000*i; register_ok_neuron_with_nonce(i as u64, i as u64, nonce); }
+        Subtensor::set_stake_from_vector( vec![ 1000000000; 4 ] );
+        // Each neuron holds a single self weight and self weights never buy bonds.
+        assert_eq!( Subtensor::mechanism_step( 0 ), <Test as pallet_subtensor::Config>::WeightInfo::mechanism_step( 4, 1, 0 ) );
+    });
+}
+
+// Tests on_initialize reports more weight once there are neurons to step over.
+#[test]
+fn test_on_initialize_weight_grows_with_neurons() {
+    new_test_ext().execute_with( || {
+        Subtensor::set_max_registratations_per_block( 100 );
+        let empty = Subtensor::on_initialize( 1 );
+        assert!( empty >= <Test as pallet_subtensor::Config>::WeightInfo::on_initialize() );
+        for i in 0..4 { let nonce:u64 = 1000000000*i; register_ok_neuron_with_nonce(i as u64, i as u64, nonce); }
+        assert!( Subtensor::on_initialize( 1 ) > empty );
+    });
+}
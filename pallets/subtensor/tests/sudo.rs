@@ -80,7 +80,7 @@ fn test_sudo_set_bonds_moving_average() {
 #[test]
 fn test_sudo_set_difficulty() {
 	new_test_ext().execute_with(|| {
-        let difficulty: u64 = 10;
+        let difficulty: u64 = 20000;
 		assert_ok!(Subtensor::sudo_set_difficulty(<<Test as Config>::Origin>::root(), difficulty));
         assert_eq!(Subtensor::get_difficulty_as_u64(), difficulty);
     });
@@ -237,6 +237,15 @@ fn test_sudo_reset_bonds() {
     });
 }
 
+#[test]
+fn test_sudo_repair_totals() {
+	new_test_ext().execute_with(|| {
+		Subtensor::increase_total_stake( 100 );
+		assert_ok!(Subtensor::sudo_repair_totals(<<Test as Config>::Origin>::root()));
+		assert_eq!(Subtensor::get_total_stake(), 0);
+    });
+}
+
 #[test]
 fn test_sudo_scaling_law_power() {
 	new_test_ext().execute_with(|| {
@@ -264,6 +273,114 @@ fn test_sudo_validator_exclude_quantile() {
     });
 }
 
+#[test]
+fn test_sudo_used_work_retention() {
+	new_test_ext().execute_with(|| {
+        let used_work_retention: u64 = 20;
+		assert_ok!(Subtensor::sudo_set_used_work_retention(<<Test as Config>::Origin>::root(), used_work_retention));
+        assert_eq!(Subtensor::get_used_work_retention(), used_work_retention);
+    });
+}
+
+#[test]
+fn test_sudo_unbonding_period() {
+	new_test_ext().execute_with(|| {
+        let unbonding_period: u64 = 20;
+		assert_ok!(Subtensor::sudo_set_unbonding_period(<<Test as Config>::Origin>::root(), unbonding_period));
+        assert_eq!(Subtensor::get_unbonding_period(), unbonding_period);
+    });
+}
+
+#[test]
+fn test_sudo_hotkey_swap_cost() {
+	new_test_ext().execute_with(|| {
+        let hotkey_swap_cost: u128 = 20;
+		assert_ok!(Subtensor::sudo_set_hotkey_swap_cost(<<Test as Config>::Origin>::root(), hotkey_swap_cost));
+        assert_eq!(Subtensor::get_hotkey_swap_cost(), hotkey_swap_cost);
+    });
+}
+
+#[test]
+fn test_sudo_coldkey_swap_delay() {
+	new_test_ext().execute_with(|| {
+        let coldkey_swap_delay: u64 = 20;
+		assert_ok!(Subtensor::sudo_set_coldkey_swap_delay(<<Test as Config>::Origin>::root(), coldkey_swap_delay));
+        assert_eq!(Subtensor::get_coldkey_swap_delay(), coldkey_swap_delay);
+    });
+}
+
+#[test]
+fn test_sudo_max_take() {
+	new_test_ext().execute_with(|| {
+        let max_take: u16 = 6553;
+		assert_ok!(Subtensor::sudo_set_max_take(<<Test as Config>::Origin>::root(), max_take));
+        assert_eq!(Subtensor::get_max_take(), max_take);
+    });
+}
+
+#[test]
+fn test_sudo_take_rate_limit() {
+	new_test_ext().execute_with(|| {
+        let take_rate_limit: u64 = 20;
+		assert_ok!(Subtensor::sudo_set_take_rate_limit(<<Test as Config>::Origin>::root(), take_rate_limit));
+        assert_eq!(Subtensor::get_take_rate_limit(), take_rate_limit);
+    });
+}
+
+#[test]
+fn test_sudo_burn() {
+	new_test_ext().execute_with(|| {
+        let burn: u128 = 200;
+		assert_ok!(Subtensor::sudo_set_burn(<<Test as Config>::Origin>::root(), burn));
+        assert_eq!(Subtensor::get_burn(), burn);
+    });
+}
+
+#[test]
+fn test_sudo_min_burn() {
+	new_test_ext().execute_with(|| {
+        let min_burn: u128 = 20;
+		assert_ok!(Subtensor::sudo_set_min_burn(<<Test as Config>::Origin>::root(), min_burn));
+        assert_eq!(Subtensor::get_min_burn(), min_burn);
+    });
+}
+
+#[test]
+fn test_sudo_max_burn() {
+	new_test_ext().execute_with(|| {
+        let max_burn: u128 = 20000;
+		assert_ok!(Subtensor::sudo_set_max_burn(<<Test as Config>::Origin>::root(), max_burn));
+        assert_eq!(Subtensor::get_max_burn(), max_burn);
+    });
+}
+
+#[test]
+fn test_sudo_min_difficulty() {
+	new_test_ext().execute_with(|| {
+        let min_difficulty: u64 = 20;
+		assert_ok!(Subtensor::sudo_set_min_difficulty(<<Test as Config>::Origin>::root(), min_difficulty));
+        assert_eq!(Subtensor::get_min_difficulty(), min_difficulty);
+    });
+}
+
+#[test]
+fn test_sudo_max_difficulty() {
+	new_test_ext().execute_with(|| {
+        let max_difficulty: u64 = 20000;
+		assert_ok!(Subtensor::sudo_set_max_difficulty(<<Test as Config>::Origin>::root(), max_difficulty));
+        assert_eq!(Subtensor::get_max_difficulty(), max_difficulty);
+    });
+}
+
+#[test]
+fn test_sudo_legacy_seal_cutoff() {
+	new_test_ext().execute_with(|| {
+        let legacy_seal_cutoff: u64 = 20;
+		assert_ok!(Subtensor::sudo_set_legacy_seal_cutoff(<<Test as Config>::Origin>::root(), legacy_seal_cutoff));
+        assert_eq!(Subtensor::get_legacy_seal_cutoff(), legacy_seal_cutoff);
+    });
+}
+
 
 //#########################
 //## sudo failure tests ###
@@ -463,6 +580,15 @@ fn test_fails_sudo_reset_bonds() {
     });
 }
 
+#[test]
+fn test_fails_sudo_repair_totals() {
+	new_test_ext().execute_with(|| {
+		Subtensor::increase_total_stake( 100 );
+		assert_eq!(Subtensor::sudo_repair_totals(<<Test as Config>::Origin>::signed(0)),  Err(DispatchError::BadOrigin.into()));
+		assert_eq!(Subtensor::get_total_stake(), 100);
+    });
+}
+
 #[test]
 fn test_fails_sudo_scaling_law_power() {
 	new_test_ext().execute_with(|| {
@@ -493,6 +619,126 @@ fn test_fails_sudo_validator_exclude_quantile() {
     });
 }
 
+#[test]
+fn test_fails_sudo_used_work_retention() {
+	new_test_ext().execute_with(|| {
+        let used_work_retention: u64 = 20;
+        let init_used_work_retention: u64 = Subtensor::get_used_work_retention();
+		assert_eq!(Subtensor::sudo_set_used_work_retention(<<Test as Config>::Origin>::signed(0), used_work_retention),  Err(DispatchError::BadOrigin.into()));
+        assert_eq!(Subtensor::get_used_work_retention(), init_used_work_retention);
+    });
+}
+
+#[test]
+fn test_fails_sudo_unbonding_period() {
+	new_test_ext().execute_with(|| {
+        let unbonding_period: u64 = 20;
+        let init_unbonding_period: u64 = Subtensor::get_unbonding_period();
+		assert_eq!(Subtensor::sudo_set_unbonding_period(<<Test as Config>::Origin>::signed(0), unbonding_period),  Err(DispatchError::BadOrigin.into()));
+        assert_eq!(Subtensor::get_unbonding_period(), init_unbonding_period);
+    });
+}
+
+#[test]
+fn test_fails_sudo_hotkey_swap_cost() {
+	new_test_ext().execute_with(|| {
+        let hotkey_swap_cost: u128 = 20;
+        let init_hotkey_swap_cost: u128 = Subtensor::get_hotkey_swap_cost();
+		assert_eq!(Subtensor::sudo_set_hotkey_swap_cost(<<Test as Config>::Origin>::signed(0), hotkey_swap_cost),  Err(DispatchError::BadOrigin.into()));
+        assert_eq!(Subtensor::get_hotkey_swap_cost(), init_hotkey_swap_cost);
+    });
+}
+
+#[test]
+fn test_fails_sudo_coldkey_swap_delay() {
+	new_test_ext().execute_with(|| {
+        let coldkey_swap_delay: u64 = 20;
+        let init_coldkey_swap_delay: u64 = Subtensor::get_coldkey_swap_delay();
+		assert_eq!(Subtensor::sudo_set_coldkey_swap_delay(<<Test as Config>::Origin>::signed(0), coldkey_swap_delay),  Err(DispatchError::BadOrigin.into()));
+        assert_eq!(Subtensor::get_coldkey_swap_delay(), init_coldkey_swap_delay);
+    });
+}
+
+#[test]
+fn test_fails_sudo_max_take() {
+	new_test_ext().execute_with(|| {
+        let max_take: u16 = 6553;
+        let init_max_take: u16 = Subtensor::get_max_take();
+		assert_eq!(Subtensor::sudo_set_max_take(<<Test as Config>::Origin>::signed(0), max_take),  Err(DispatchError::BadOrigin.into()));
+        assert_eq!(Subtensor::get_max_take(), init_max_take);
+    });
+}
+
+#[test]
+fn test_fails_sudo_take_rate_limit() {
+	new_test_ext().execute_with(|| {
+        let take_rate_limit: u64 = 20;
+        let init_take_rate_limit: u64 = Subtensor::get_take_rate_limit();
+		assert_eq!(Subtensor::sudo_set_take_rate_limit(<<Test as Config>::Origin>::signed(0), take_rate_limit),  Err(DispatchError::BadOrigin.into()));
+        assert_eq!(Subtensor::get_take_rate_limit(), init_take_rate_limit);
+    });
+}
+
+#[test]
+fn test_fails_sudo_burn() {
+	new_test_ext().execute_with(|| {
+        let burn: u128 = 20;
+        let init_burn: u128 = Subtensor::get_burn();
+		assert_eq!(Subtensor::sudo_set_burn(<<Test as Config>::Origin>::signed(0), burn),  Err(DispatchError::BadOrigin.into()));
+        assert_eq!(Subtensor::get_burn(), init_burn);
+    });
+}
+
+#[test]
+fn test_fails_sudo_min_difficulty() {
+	new_test_ext().execute_with(|| {
+        let min_difficulty: u64 = 20;
+        let init_min_difficulty: u64 = Subtensor::get_min_difficulty();
+		assert_eq!(Subtensor::sudo_set_min_difficulty(<<Test as Config>::Origin>::signed(0), min_difficulty),  Err(DispatchError::BadOrigin.into()));
+        assert_eq!(Subtensor::get_min_difficulty(), init_min_difficulty);
+    });
+}
+
+#[test]
+fn test_fails_sudo_max_difficulty() {
+	new_test_ext().execute_with(|| {
+        let max_difficulty: u64 = 20000;
+        let init_max_difficulty: u64 = Subtensor::get_max_difficulty();
+		assert_eq!(Subtensor::sudo_set_max_difficulty(<<Test as Config>::Origin>::signed(0), max_difficulty),  Err(DispatchError::BadOrigin.into()));
+        assert_eq!(Subtensor::get_max_difficulty(), init_max_difficulty);
+    });
+}
+
+#[test]
+fn test_fails_sudo_min_burn() {
+	new_test_ext().execute_with(|| {
+        let min_burn: u128 = 20;
+        let init_min_burn: u128 = Subtensor::get_min_burn();
+		assert_eq!(Subtensor::sudo_set_min_burn(<<Test as Config>::Origin>::signed(0), min_burn),  Err(DispatchError::BadOrigin.into()));
+        assert_eq!(Subtensor::get_min_burn(), init_min_burn);
+    });
+}
+
+#[test]
+fn test_fails_sudo_max_burn() {
+	new_test_ext().execute_with(|| {
+        let max_burn: u128 = 20000;
+        let init_max_burn: u128 = Subtensor::get_max_burn();
+		assert_eq!(Subtensor::sudo_set_max_burn(<<Test as Config>::Origin>::signed(0), max_burn),  Err(DispatchError::BadOrigin.into()));
+        assert_eq!(Subtensor::get_max_burn(), init_max_burn);
+    });
+}
+
+#[test]
+fn test_fails_sudo_legacy_seal_cutoff() {
+	new_test_ext().execute_with(|| {
+        let legacy_seal_cutoff: u64 = 20;
+        let init_legacy_seal_cutoff: u64 = Subtensor::get_legacy_seal_cutoff();
+		assert_eq!(Subtensor::sudo_set_legacy_seal_cutoff(<<Test as Config>::Origin>::signed(0), legacy_seal_cutoff),  Err(DispatchError::BadOrigin.into()));
+        assert_eq!(Subtensor::get_legacy_seal_cutoff(), init_legacy_seal_cutoff);
+    });
+}
+
 
 //##########################################
 //## sudo set with root; failure due to out of range ##
@@ -527,3 +773,87 @@ fn test_fails_sudo_validator_exclude_quantile_out_of_range() {
         assert_eq!(Subtensor::get_validator_exclude_quantile(), init_validator_exclude_quantile);
     });
 }
+
+#[test]
+fn test_fails_sudo_used_work_retention_out_of_range() {
+	new_test_ext().execute_with(|| {
+        let used_work_retention: u64 = 2; // min is the 3 block seal window. Should fail
+        let init_used_work_retention: u64 = Subtensor::get_used_work_retention();
+		assert_eq!(Subtensor::sudo_set_used_work_retention(<<Test as Config>::Origin>::root(), used_work_retention),  Err(Error::<Test>::StorageValueOutOfRange.into()));
+        assert_eq!(Subtensor::get_used_work_retention(), init_used_work_retention);
+    });
+}
+
+#[test]
+fn test_fails_sudo_coldkey_swap_delay_out_of_range() {
+	new_test_ext().execute_with(|| {
+        let coldkey_swap_delay: u64 = 0; // The owner needs a block to cancel in. Should fail
+        let init_coldkey_swap_delay: u64 = Subtensor::get_coldkey_swap_delay();
+		assert_eq!(Subtensor::sudo_set_coldkey_swap_delay(<<Test as Config>::Origin>::root(), coldkey_swap_delay),  Err(Error::<Test>::StorageValueOutOfRange.into()));
+        assert_eq!(Subtensor::get_coldkey_swap_delay(), init_coldkey_swap_delay);
+    });
+}
+
+#[test]
+fn test_fails_sudo_min_difficulty_above_max() {
+	new_test_ext().execute_with(|| {
+        let min_difficulty: u64 = Subtensor::get_max_difficulty() + 1;
+        let init_min_difficulty: u64 = Subtensor::get_min_difficulty();
+		assert_eq!(Subtensor::sudo_set_min_difficulty(<<Test as Config>::Origin>::root(), min_difficulty),  Err(Error::<Test>::StorageValueOutOfRange.into()));
+        assert_eq!(Subtensor::get_min_difficulty(), init_min_difficulty);
+    });
+}
+
+#[test]
+fn test_fails_sudo_max_difficulty_below_min() {
+	new_test_ext().execute_with(|| {
+        let max_difficulty: u64 = Subtensor::get_min_difficulty() - 1;
+        let init_max_difficulty: u64 = Subtensor::get_max_difficulty();
+		assert_eq!(Subtensor::sudo_set_max_difficulty(<<Test as Config>::Origin>::root(), max_difficulty),  Err(Error::<Test>::StorageValueOutOfRange.into()));
+        assert_eq!(Subtensor::get_max_difficulty(), init_max_difficulty);
+    });
+}
+
+#[test]
+fn test_fails_sudo_difficulty_out_of_bounds() {
+	new_test_ext().execute_with(|| {
+        let init_difficulty: u64 = Subtensor::get_difficulty_as_u64();
+		assert_eq!(Subtensor::sudo_set_difficulty(<<Test as Config>::Origin>::root(), Subtensor::get_min_difficulty() - 1),  Err(Error::<Test>::StorageValueOutOfRange.into()));
+		assert_eq!(Subtensor::sudo_set_difficulty(<<Test as Config>::Origin>::root(), Subtensor::get_max_difficulty() + 1),  Err(Error::<Test>::StorageValueOutOfRange.into()));
+        assert_eq!(Subtensor::get_difficulty_as_u64(), init_difficulty);
+		assert_ok!(Subtensor::sudo_set_difficulty(<<Test as Config>::Origin>::root(), Subtensor::get_min_difficulty()));
+		assert_ok!(Subtensor::sudo_set_difficulty(<<Test as Config>::Origin>::root(), Subtensor::get_max_difficulty()));
+    });
+}
+
+#[test]
+fn test_fails_sudo_min_burn_above_max() {
+	new_test_ext().execute_with(|| {
+        let min_burn: u128 = Subtensor::get_max_burn() + 1;
+        let init_min_burn: u128 = Subtensor::get_min_burn();
+		assert_eq!(Subtensor::sudo_set_min_burn(<<Test as Config>::Origin>::root(), min_burn),  Err(Error::<Test>::StorageValueOutOfRange.into()));
+        assert_eq!(Subtensor::get_min_burn(), init_min_burn);
+    });
+}
+
+#[test]
+fn test_fails_sudo_max_burn_below_min() {
+	new_test_ext().execute_with(|| {
+        let max_burn: u128 = Subtensor::get_min_burn() - 1;
+        let init_max_burn: u128 = Subtensor::get_max_burn();
+		assert_eq!(Subtensor::sudo_set_max_burn(<<Test as Config>::Origin>::root(), max_burn),  Err(Error::<Test>::StorageValueOutOfRange.into()));
+        assert_eq!(Subtensor::get_max_burn(), init_max_burn);
+    });
+}
+
+#[test]
+fn test_fails_sudo_burn_out_of_bounds() {
+	new_test_ext().execute_with(|| {
+        let init_burn: u128 = Subtensor::get_burn();
+		assert_eq!(Subtensor::sudo_set_burn(<<Test as Config>::Origin>::root(), Subtensor::get_min_burn() - 1),  Err(Error::<Test>::StorageValueOutOfRange.into()));
+		assert_eq!(Subtensor::sudo_set_burn(<<Test as Config>::Origin>::root(), Subtensor::get_max_burn() + 1),  Err(Error::<Test>::StorageValueOutOfRange.into()));
+        assert_eq!(Subtensor::get_burn(), init_burn);
+		assert_ok!(Subtensor::sudo_set_burn(<<Test as Config>::Origin>::root(), Subtensor::get_min_burn()));
+		assert_ok!(Subtensor::sudo_set_burn(<<Test as Config>::Origin>::root(), Subtensor::get_max_burn()));
+    });
+}
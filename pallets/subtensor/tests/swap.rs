@@ -0,0 +1,382 @@
+use frame_support::{assert_ok};
+use frame_system::{Config};
+mod mock;
+use mock::*;
+use pallet_subtensor::{Error, Call as SubtensorCall, WeightInfo, EmissionDestination, MAX_COLDKEY_SWAPS_PER_BLOCK, MAX_COLDKEY_SWAP_HOTKEYS_PER_BLOCK};
+use frame_support::weights::{GetDispatchInfo, DispatchInfo, DispatchClass, Pays};
+
+/***********************************************************
+	swap::swap_hotkey() tests
+************************************************************/
+
+#[test]
+fn test_swap_hotkey_dispatch_info_ok() {
+	new_test_ext().execute_with(|| {
+		let old_hotkey = 0;
+		let new_hotkey = 1;
+		let call = Call::Subtensor(SubtensorCall::swap_hotkey{old_hotkey, new_hotkey});
+		assert_eq!(call.get_dispatch_info(), DispatchInfo {
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::swap_hotkey(),
+			class: DispatchClass::Normal,
+			pays_fee: Pays::Yes
+		});
+	});
+}
+
+#[test]
+fn test_swap_hotkey_keeps_neuron() {
+	new_test_ext().execute_with(|| {
+		let old_hotkey = 1;
+		let new_hotkey = 2;
+		let coldkey = 10;
+		let neuron = register_ok_neuron( old_hotkey, coldkey );
+		Subtensor::add_stake_to_neuron_hotkey_account( neuron.uid, 5000 );
+		assert_ok!(Subtensor::serve_axon(<<Test as Config>::Origin>::signed(old_hotkey), 0, 0x08080808, 8883, 4, 0));
+		Subtensor::add_balance_to_coldkey_account( &coldkey, 1001 );
+		let before = Subtensor::get_neuron( neuron.uid ).unwrap();
+
+		assert_ok!(Subtensor::swap_hotkey(<<Test as Config>::Origin>::signed(coldkey), old_hotkey, new_hotkey));
+
+		// Same uid and state under the new hotkey
+		let after = Subtensor::get_neuron_by_hotkey( &new_hotkey ).unwrap();
+		assert_eq!(after.uid, neuron.uid);
+		assert_eq!(after.hotkey, new_hotkey);
+		assert_eq!(after.coldkey, coldkey);
+		assert_eq!(after.stake, 5000);
+		assert_eq!(after.ip, before.ip);
+		assert_eq!(after.weights, before.weights);
+		assert_eq!(Subtensor::get_neuron_by_hotkey( &old_hotkey ), None);
+		assert!(!Subtensor::is_hotkey_active( &old_hotkey ));
+		assert_eq!(Subtensor::get_neuron_count(), 1);
+		assert_eq!(Subtensor::get_total_stake(), 5000);
+
+		// The coldkey index follows and the cost is burned
+		let owned = Subtensor::get_hotkeys_for_coldkey( &coldkey );
+		assert_eq!(owned.len(), 1);
+		assert_eq!(owned[0].hotkey, new_hotkey);
+		assert_eq!(Subtensor::get_coldkey_balance( &coldkey ), 1001 - Subtensor::get_hotkey_swap_cost());
+
+		// The new hotkey serves and the old one no longer can
+		assert_ok!(Subtensor::serve_axon(<<Test as Config>::Origin>::signed(new_hotkey), 0, 0x08080809, 8883, 4, 0));
+		assert_eq!(Subtensor::serve_axon(<<Test as Config>::Origin>::signed(old_hotkey), 0, 0x08080809, 8883, 4, 0), Err(Error::<Test>::NotRegistered.into()));
+	});
+}
+
+#[test]
+fn test_swap_hotkey_moves_emission_destination() {
+	new_test_ext().execute_with(|| {
+		let old_hotkey = 1;
+		let new_hotkey = 2;
+		let coldkey = 10;
+		register_ok_neuron( old_hotkey, coldkey );
+		Subtensor::add_balance_to_coldkey_account( &coldkey, 1001 );
+		assert_ok!(Subtensor::set_emission_destination(<<Test as Config>::Origin>::signed(coldkey), old_hotkey, EmissionDestination::Coldkey));
+
+		assert_ok!(Subtensor::swap_hotkey(<<Test as Config>::Origin>::signed(coldkey), old_hotkey, new_hotkey));
+		assert_eq!(Subtensor::emission_destination( &new_hotkey ), EmissionDestination::Coldkey);
+		assert_eq!(Subtensor::emission_destination( &old_hotkey ), EmissionDestination::Stake);
+	});
+}
+
+#[test]
+fn test_swap_hotkey_err_not_owner() {
+	new_test_ext().execute_with(|| {
+		let old_hotkey = 1;
+		let coldkey = 10;
+		let other_coldkey = 20;
+		register_ok_neuron( old_hotkey, coldkey );
+		Subtensor::add_balance_to_coldkey_account( &other_coldkey, 10000 );
+
+		assert_eq!(Subtensor::swap_hotkey(<<Test as Config>::Origin>::signed(other_coldkey), old_hotkey, 2), Err(Error::<Test>::NonAssociatedColdKey.into()));
+		assert_eq!(Subtensor::swap_hotkey(<<Test as Config>::Origin>::signed(coldkey), 3, 2), Err(Error::<Test>::NotRegistered.into()));
+		assert!(Subtensor::is_hotkey_active( &old_hotkey ));
+	});
+}
+
+#[test]
+fn test_swap_hotkey_err_new_hotkey_registered() {
+	new_test_ext().execute_with(|| {
+		let old_hotkey = 1;
+		let new_hotkey = 2;
+		let coldkey = 10;
+		register_ok_neuron( old_hotkey, coldkey );
+		register_ok_neuron( new_hotkey, 20 );
+		Subtensor::add_balance_to_coldkey_account( &coldkey, 10000 );
+
+		assert_eq!(Subtensor::swap_hotkey(<<Test as Config>::Origin>::signed(coldkey), old_hotkey, new_hotkey), Err(Error::<Test>::AlreadyRegistered.into()));
+	});
+}
+
+#[test]
+fn test_swap_hotkey_err_cannot_pay_cost() {
+	new_test_ext().execute_with(|| {
+		let old_hotkey = 1;
+		let coldkey = 10;
+		register_ok_neuron( old_hotkey, coldkey );
+		Subtensor::add_balance_to_coldkey_account( &coldkey, Subtensor::get_hotkey_swap_cost() - 1 );
+
+		assert_eq!(Subtensor::swap_hotkey(<<Test as Config>::Origin>::signed(coldkey), old_hotkey, 2), Err(Error::<Test>::NotEnoughBalanceToSwapHotkey.into()));
+		assert!(Subtensor::is_hotkey_active( &old_hotkey ));
+	});
+}
+
+#[test]
+fn test_swap_hotkey_moves_nominators() {
+	new_test_ext().execute_with(|| {
+		let old_hotkey = 1;
+		let new_hotkey = 2;
+		let coldkey = 10;
+		let nominator = 20;
+		register_ok_neuron( old_hotkey, coldkey );
+		assert_ok!(Subtensor::become_delegate(<<Test as Config>::Origin>::signed(coldkey), old_hotkey));
+		Subtensor::add_balance_to_coldkey_account( &nominator, 10001 );
+		assert_ok!(Subtensor::delegate_stake(<<Test as Config>::Origin>::signed(nominator), old_hotkey, 10000));
+		Subtensor::add_balance_to_coldkey_account( &coldkey, 10000 );
+
+		assert_ok!(Subtensor::swap_hotkey(<<Test as Config>::Origin>::signed(coldkey), old_hotkey, new_hotkey));
+
+		assert!(Subtensor::is_delegate( &new_hotkey ));
+		assert!(!Subtensor::is_delegate( &old_hotkey ));
+		assert_eq!(Subtensor::get_nominator_stake( &new_hotkey, &nominator ), 10000);
+		assert_eq!(Subtensor::get_nominator_stake( &old_hotkey, &nominator ), 0);
+		assert_eq!(Subtensor::get_nominations_for_coldkey( &nominator ), vec![(new_hotkey, 10000)]);
+		assert_eq!(Subtensor::get_delegates().len(), 1);
+
+		// The nominator withdraws through the new hotkey
+		assert_ok!(Subtensor::undelegate_stake(<<Test as Config>::Origin>::signed(nominator), new_hotkey, 10000));
+		assert_eq!(Subtensor::get_total_stake(), 0);
+	});
+}
+
+/***********************************************************
+	swap::announce_coldkey_swap() tests
+************************************************************/
+
+#[test]
+fn test_announce_coldkey_swap_dispatch_info_ok() {
+	new_test_ext().execute_with(|| {
+		let new_coldkey = 1;
+		let call = Call::Subtensor(SubtensorCall::announce_coldkey_swap{new_coldkey});
+		assert_eq!(call.get_dispatch_info(), DispatchInfo {
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::announce_coldkey_swap(),
+			class: DispatchClass::Normal,
+			pays_fee: Pays::Yes
+		});
+	});
+}
+
+#[test]
+fn test_coldkey_swap_executes_after_delay() {
+	new_test_ext().execute_with(|| {
+		let coldkey = 10;
+		let new_coldkey = 20;
+		let neuron_a = register_ok_neuron( 1, coldkey );
+		let neuron_b = register_ok_neuron( 2, coldkey );
+		Subtensor::add_balance_to_coldkey_account( &coldkey, 5000 );
+		assert_ok!(Subtensor::add_stake(<<Test as Config>::Origin>::signed(coldkey), 1, 5000));
+		let execution_block = Subtensor::get_current_block_as_u64() + Subtensor::get_coldkey_swap_delay();
+
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey), new_coldkey));
+
+		// Nothing moves before the delay has passed
+		run_to_block( execution_block - 1 );
+		assert_eq!(Subtensor::get_neuron( neuron_a.uid ).unwrap().coldkey, coldkey);
+
+		run_to_block( execution_block );
+		assert_eq!(Subtensor::get_neuron( neuron_a.uid ).unwrap().coldkey, new_coldkey);
+		assert_eq!(Subtensor::get_neuron( neuron_b.uid ).unwrap().coldkey, new_coldkey);
+		assert_eq!(Subtensor::get_hotkeys_for_coldkey( &coldkey ).len(), 0);
+		assert_eq!(Subtensor::get_hotkeys_for_coldkey( &new_coldkey ).len(), 2);
+
+		// The stake is held on the new coldkey
+		let stake: u128 = Subtensor::get_stake_of_neuron_hotkey_account_by_uid( neuron_a.uid ) + Subtensor::get_stake_of_neuron_hotkey_account_by_uid( neuron_b.uid );
+		assert_eq!(Subtensor::get_reserved_stake( &coldkey ), 0);
+		assert_eq!(Subtensor::get_reserved_stake( &new_coldkey ), stake);
+
+		// The stake is now controlled by the new coldkey only
+		assert_eq!(Subtensor::remove_stake(<<Test as Config>::Origin>::signed(coldkey), 1, 1000), Err(Error::<Test>::NonAssociatedColdKey.into()));
+		assert_ok!(Subtensor::remove_stake(<<Test as Config>::Origin>::signed(new_coldkey), 1, 1000));
+	});
+}
+
+#[test]
+fn test_coldkey_swap_cancelled_below_existential_deposit() {
+	new_test_ext().execute_with(|| {
+		set_existential_deposit( 500 );
+		let coldkey = 10;
+		let new_coldkey = 20;
+		let neuron = register_ok_neuron( 1, coldkey );
+		Subtensor::add_balance_to_coldkey_account( &coldkey, 1000 );
+		assert_ok!(Subtensor::add_stake(<<Test as Config>::Origin>::signed(coldkey), 1, 100));
+
+		// The stake would be burned on the new coldkey, so the swap is cancelled when due
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey), new_coldkey));
+		step_block( Subtensor::get_coldkey_swap_delay() );
+		assert_eq!(Subtensor::get_neuron( neuron.uid ).unwrap().coldkey, coldkey);
+		assert_eq!(Subtensor::get_hotkeys_for_coldkey( &coldkey ).len(), 1);
+		assert_eq!(Subtensor::get_reserved_stake( &coldkey ), 100);
+		assert_eq!(Subtensor::get_reserved_stake( &new_coldkey ), 0);
+		assert_eq!(Subtensor::cancel_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey)), Err(Error::<Test>::NoColdkeySwapAnnounced.into()));
+
+		// Once the new coldkey exists the stake moves in full
+		Subtensor::add_balance_to_coldkey_account( &new_coldkey, 500 );
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey), new_coldkey));
+		step_block( Subtensor::get_coldkey_swap_delay() );
+		assert_eq!(Subtensor::get_neuron( neuron.uid ).unwrap().coldkey, new_coldkey);
+		assert_eq!(Subtensor::get_reserved_stake( &new_coldkey ), 100);
+	});
+}
+
+#[test]
+fn test_coldkey_swap_joins_neurons_of_new_coldkey() {
+	new_test_ext().execute_with(|| {
+		let coldkey = 10;
+		let new_coldkey = 20;
+		register_ok_neuron( 1, coldkey );
+		register_ok_neuron( 2, new_coldkey );
+
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey), new_coldkey));
+		step_block( Subtensor::get_coldkey_swap_delay() );
+
+		let owned: Vec<u64> = Subtensor::get_hotkeys_for_coldkey( &new_coldkey ).iter().map( |info| info.hotkey ).collect();
+		assert_eq!(owned, vec![2, 1]);
+	});
+}
+
+#[test]
+fn test_announce_coldkey_swap_errors() {
+	new_test_ext().execute_with(|| {
+		let coldkey = 10;
+		let new_coldkey = 20;
+
+		assert_eq!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey), new_coldkey), Err(Error::<Test>::NoOwnedHotkeys.into()));
+		register_ok_neuron( 1, coldkey );
+		assert_eq!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey), coldkey), Err(Error::<Test>::ColdkeySwapToSelf.into()));
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey), new_coldkey));
+		assert_eq!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey), 30), Err(Error::<Test>::ColdkeySwapAlreadyAnnounced.into()));
+	});
+}
+
+#[test]
+fn test_announce_coldkey_swap_err_block_full() {
+	new_test_ext().execute_with(|| {
+		Subtensor::set_max_registratations_per_block( 100 );
+		let swaps = MAX_COLDKEY_SWAPS_PER_BLOCK as u64;
+		for i in 0..=swaps {
+			register_ok_neuron_with_nonce( i, 1000 + i, 1000000000 * i );
+		}
+		for i in 0..swaps {
+			assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(1000 + i), 5000 + i));
+		}
+		assert_eq!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(1000 + swaps), 5000), Err(Error::<Test>::TooManyColdkeySwapsThisBlock.into()));
+
+		// The next block executes at a later block
+		step_block( 1 );
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(1000 + swaps), 5000));
+	});
+}
+
+#[test]
+fn test_announce_coldkey_swap_err_too_many_hotkeys() {
+	new_test_ext().execute_with(|| {
+		let hotkeys = MAX_COLDKEY_SWAP_HOTKEYS_PER_BLOCK as u64;
+		Subtensor::set_difficulty_from_u64( 1 );
+		Subtensor::set_max_allowed_uids( hotkeys + 1 );
+		Subtensor::set_max_registratations_per_block( hotkeys + 1 );
+		for hotkey in 0..hotkeys {
+			register_ok_neuron( hotkey, 1000 + hotkey % 2 );
+		}
+		register_ok_neuron( hotkeys, 2000 );
+
+		// Two swaps move every hotkey the execution block allows
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(1000), 5000));
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(1001), 5001));
+		assert_eq!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(2000), 5002), Err(Error::<Test>::TooManyColdkeySwapHotkeysThisBlock.into()));
+
+		// The next block executes at a later block
+		step_block( 1 );
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(2000), 5002));
+	});
+}
+
+#[test]
+fn test_register_err_coldkey_swap_pending() {
+	new_test_ext().execute_with(|| {
+		let coldkey = 10;
+		let new_coldkey = 20;
+		register_ok_neuron( 1, coldkey );
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey), new_coldkey));
+
+		// The swap was bounded by the hotkeys owned when it was announced
+		let block_number: u64 = Subtensor::get_current_block_as_u64();
+		let (nonce, work): (u64, Vec<u8>) = Subtensor::create_work_for_hotkey( block_number, 0, &2 );
+		assert_eq!(Subtensor::register(<<Test as Config>::Origin>::signed(2), block_number, nonce, work, 2, coldkey), Err(Error::<Test>::ColdkeySwapPending.into()));
+		Subtensor::add_balance_to_coldkey_account( &coldkey, 10000 );
+		assert_eq!(Subtensor::burned_register(<<Test as Config>::Origin>::signed(coldkey), 3), Err(Error::<Test>::ColdkeySwapPending.into()));
+
+		// Once the swap has executed the old coldkey can register again
+		step_block( Subtensor::get_coldkey_swap_delay() );
+		register_ok_neuron( 2, coldkey );
+	});
+}
+
+#[test]
+fn test_announce_coldkey_swap_err_pending() {
+	new_test_ext().execute_with(|| {
+		let coldkey = 10;
+		let new_coldkey = 20;
+		Subtensor::set_max_registratations_per_block( 3 );
+		register_ok_neuron( 1, coldkey );
+		register_ok_neuron( 2, new_coldkey );
+		register_ok_neuron( 3, 30 );
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey), new_coldkey));
+
+		// Neither a coldkey being swapped nor one receiving neurons can be swapped onto or away from
+		assert_eq!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(30), coldkey), Err(Error::<Test>::ColdkeySwapPending.into()));
+		assert_eq!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(new_coldkey), 40), Err(Error::<Test>::ColdkeySwapPending.into()));
+
+		// Once the swap has executed the new coldkey can move its neurons on
+		step_block( Subtensor::get_coldkey_swap_delay() );
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(new_coldkey), 40));
+	});
+}
+
+/***********************************************************
+	swap::cancel_coldkey_swap() tests
+************************************************************/
+
+#[test]
+fn test_cancel_coldkey_swap_dispatch_info_ok() {
+	new_test_ext().execute_with(|| {
+		let call = Call::Subtensor(SubtensorCall::cancel_coldkey_swap{});
+		assert_eq!(call.get_dispatch_info(), DispatchInfo {
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::cancel_coldkey_swap(),
+			class: DispatchClass::Normal,
+			pays_fee: Pays::Yes
+		});
+	});
+}
+
+#[test]
+fn test_cancel_coldkey_swap_ok() {
+	new_test_ext().execute_with(|| {
+		let coldkey = 10;
+		let new_coldkey = 20;
+		let neuron = register_ok_neuron( 1, coldkey );
+
+		assert_eq!(Subtensor::cancel_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey)), Err(Error::<Test>::NoColdkeySwapAnnounced.into()));
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey), new_coldkey));
+		step_block( 1 );
+		assert_ok!(Subtensor::cancel_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey)));
+
+		// The swap never executes
+		step_block( Subtensor::get_coldkey_swap_delay() );
+		assert_eq!(Subtensor::get_neuron( neuron.uid ).unwrap().coldkey, coldkey);
+
+		// A new swap can be announced, and the new coldkey is free to announce its own
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey), new_coldkey));
+		assert_ok!(Subtensor::cancel_coldkey_swap(<<Test as Config>::Origin>::signed(coldkey)));
+		register_ok_neuron( 2, new_coldkey );
+		assert_ok!(Subtensor::announce_coldkey_swap(<<Test as Config>::Origin>::signed(new_coldkey), 30));
+	});
+}
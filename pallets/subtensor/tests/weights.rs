@@ -1,6 +1,6 @@
 mod mock;
 use mock::*;
-use pallet_subtensor::{Error};
+use pallet_subtensor::{Error, WeightInfo};
 use frame_support::weights::{GetDispatchInfo, DispatchInfo, DispatchClass, Pays};
 use frame_support::{assert_ok};
 use sp_runtime::DispatchError;
@@ -20,7 +20,7 @@ fn test_set_weights_dispatch_info_ok() {
 		let call = Call::Subtensor(SubtensorCall::set_weights{dests, weights});
 
 		assert_eq!(call.get_dispatch_info(), DispatchInfo {
-			weight: 0,
+			weight: <Test as pallet_subtensor::Config>::WeightInfo::set_weights(2),
 			class: DispatchClass::Normal,
 			pays_fee: Pays::No
 		});
@@ -44,8 +44,8 @@ fn set_weights_ok_no_weights() {
 		let weight_values : Vec<u32> = vec![];
 
 		// == Expectations ==
-		let expect_stake:u64 = 10000; // The stake for the neuron should remain the same
-		let expect_total_stake:u64 = 10000; // The total stake should remain the same
+		let expect_stake:u128 = 10000; // The stake for the neuron should remain the same
+		let expect_total_stake:u128 = 10000; // The total stake should remain the same
 
 		// Let's subscribe a new neuron to the chain
 		let neuron = register_ok_neuron( hotkey_account_id, 66);
@@ -98,7 +98,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 111,
+	spec_version: 134,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -237,13 +237,13 @@ impl pallet_timestamp::Config for Runtime {
 
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = ConstU32<50>;
-	type MaxReserves = ();
+	type MaxReserves = ConstU32<50>;
 	type ReserveIdentifier = [u8; 8];
 	/// The type for recording an account's balance.
 	type Balance = Balance;
 	/// The ubiquitous event type.
 	type Event = Event;
-	type DustRemoval = ();
+	type DustRemoval = SubtensorModule;
 	type ExistentialDeposit = ConstU128<500>;
 	type AccountStore = System;
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
@@ -254,7 +254,7 @@ parameter_types! {
 }
 
 impl pallet_transaction_payment::Config for Runtime {
-	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	type OnChargeTransaction = CurrencyAdapter<Balances, SubtensorModule>;
 	type TransactionByteFee = TransactionByteFee;
 	type OperationalFeeMultiplier = ConstU8<5>;
 	type WeightToFee = IdentityFee<Balance>;
@@ -294,12 +294,22 @@ parameter_types! {
 	pub const InitialStakePruningMin: u64 = 0;
 	pub const InitialFoundationDistribution: u64 = 0;
 	pub const InitialDifficulty: u64 = 10000000;
-	pub const MinimumDifficulty: u64 = 10000000;
+	pub const InitialMinDifficulty: u64 = 10000000;
 	pub const InitialActivityCutoff: u64 = 5000;
-	pub const MaximumDifficulty: u64 = u64::MAX/4;
+	pub const InitialMaxDifficulty: u64 = u64::MAX/4;
+	pub const InitialBurn: Balance = 1_000_000_000;
+	pub const InitialMinBurn: Balance = 1_000_000_000;
+	pub const InitialMaxBurn: Balance = 100_000_000_000;
+	pub const InitialLegacySealCutoff: u64 = u64::MAX;
 	pub const InitialAdjustmentInterval: u64 = 100;
 	pub const InitialMaxRegistrationsPerBlock: u64 = 2;
 	pub const InitialTargetRegistrationsPerInterval: u64 = 2;
+	pub const InitialUsedWorkRetention: u64 = 100;
+	pub const InitialUnbondingPeriod: u64 = DAYS as u64;
+	pub const InitialHotkeySwapCost: Balance = 1_000_000_000;
+	pub const InitialColdkeySwapDelay: u64 = DAYS as u64;
+	pub const InitialMaxTake: u16 = 13107; // 20%
+	pub const InitialTakeRateLimit: u64 = DAYS as u64;
 }
 
 /// Configure the pallet-template in pallets/template.
@@ -331,12 +341,23 @@ impl pallet_subtensor::Config for Runtime {
 	type InitialBlocksPerStep = InitialBlocksPerStep;
 	type InitialIssuance = InitialIssuance;
 	type InitialDifficulty = InitialDifficulty;
-	type MinimumDifficulty = MinimumDifficulty;
-	type MaximumDifficulty = MaximumDifficulty;
+	type InitialMinDifficulty = InitialMinDifficulty;
+	type InitialMaxDifficulty = InitialMaxDifficulty;
+	type InitialBurn = InitialBurn;
+	type InitialMinBurn = InitialMinBurn;
+	type InitialMaxBurn = InitialMaxBurn;
+	type InitialLegacySealCutoff = InitialLegacySealCutoff;
 	type InitialActivityCutoff = InitialActivityCutoff;
 	type InitialAdjustmentInterval = InitialAdjustmentInterval;
 	type InitialMaxRegistrationsPerBlock = InitialMaxRegistrationsPerBlock;
 	type InitialTargetRegistrationsPerInterval = InitialTargetRegistrationsPerInterval;
+	type InitialUsedWorkRetention = InitialUsedWorkRetention;
+	type InitialUnbondingPeriod = InitialUnbondingPeriod;
+	type InitialHotkeySwapCost = InitialHotkeySwapCost;
+	type InitialColdkeySwapDelay = InitialColdkeySwapDelay;
+	type InitialMaxTake = InitialMaxTake;
+	type InitialTakeRateLimit = InitialTakeRateLimit;
+	type WeightInfo = pallet_subtensor::weight_info::SubstrateWeight<Runtime>;
 
 }
 
@@ -535,6 +556,52 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl subtensor_custom_rpc_runtime_api::SubtensorApi<Block, AccountId, Balance> for Runtime {
+		fn get_neuron(uid: u32) -> Option<pallet_subtensor::NeuronMetadata<AccountId, Balance>> {
+			SubtensorModule::get_neuron(uid)
+		}
+
+		fn get_neurons() -> Vec<pallet_subtensor::NeuronMetadata<AccountId, Balance>> {
+			SubtensorModule::get_neurons()
+		}
+
+		fn get_neuron_by_hotkey(hotkey: AccountId) -> Option<pallet_subtensor::NeuronMetadata<AccountId, Balance>> {
+			SubtensorModule::get_neuron_by_hotkey(&hotkey)
+		}
+
+		fn get_hotkeys_for_coldkey(coldkey: AccountId) -> Vec<pallet_subtensor::OwnedHotkeyInfo<AccountId, Balance>> {
+			SubtensorModule::get_hotkeys_for_coldkey(&coldkey)
+		}
+
+		fn get_hyperparameters() -> pallet_subtensor::SubtensorHyperparams<Balance> {
+			SubtensorModule::get_hyperparameters()
+		}
+
+		fn get_delegates() -> Vec<pallet_subtensor::DelegateInfo<AccountId, Balance>> {
+			SubtensorModule::get_delegates()
+		}
+
+		fn get_delegate(hotkey: AccountId) -> Option<pallet_subtensor::DelegateInfo<AccountId, Balance>> {
+			SubtensorModule::get_delegate(&hotkey)
+		}
+
+		fn get_nominations_for_coldkey(coldkey: AccountId) -> Vec<(AccountId, Balance)> {
+			SubtensorModule::get_nominations_for_coldkey(&coldkey)
+		}
+
+		fn get_pending_unlocks(coldkey: AccountId) -> Vec<pallet_subtensor::UnlockChunk<Balance>> {
+			SubtensorModule::get_pending_unlocks(&coldkey)
+		}
+
+		fn get_invariant_violations() -> Vec<pallet_subtensor::InvariantViolation<Balance>> {
+			SubtensorModule::check_invariants()
+		}
+
+		fn check_registration_work(block_number: u64, nonce: u64, work: Vec<u8>, hotkey: AccountId) -> Option<pallet_subtensor::RegistrationWorkError> {
+			SubtensorModule::check_registration_work(block_number, nonce, &work, &hotkey).err()
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -581,9 +648,21 @@ impl_runtime_apis! {
 			let mut batches = Vec::<BenchmarkBatch>::new();
 			let params = (&config, &whitelist);
 			add_benchmarks!(params, batches);
-			add_benchmark!(params, batches, pallet_subtensor, SubtensorModule);
 
 			Ok(batches)
 		}
 	}
+
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryRuntime<Block> for Runtime {
+		fn on_runtime_upgrade() -> (Weight, Weight) {
+			// Panics if a pre_upgrade or post_upgrade check fails, which is what try-runtime wants.
+			let weight = Executive::try_runtime_upgrade().unwrap();
+			(weight, BlockWeights::get().max_block)
+		}
+
+		fn execute_block_no_check(block: Block) -> Weight {
+			Executive::execute_block_no_check(block)
+		}
+	}
 }
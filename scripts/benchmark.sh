@@ -0,0 +1,26 @@
+#!/bin/bash
+# This script is meant to be run on Unix/Linux based systems, on the reference hardware the
+# weights are meant for. It rebuilds the node with the benchmarks compiled in and regenerates
+# pallets/subtensor/src/weight_info.rs from pallets/subtensor/src/benchmarking.rs.
+
+set -e
+
+cd "$(dirname "$0")/.."
+
+echo "*** Building node-subtensor with runtime-benchmarks"
+cargo build --release -p node-subtensor --features runtime-benchmarks
+
+echo "*** Benchmarking pallet_subtensor"
+./target/release/node-subtensor benchmark \
+    --chain=dev \
+    --execution=wasm \
+    --wasm-execution=compiled \
+    --pallet=pallet_subtensor \
+    --extrinsic='*' \
+    --steps=50 \
+    --repeat=20 \
+    --heap-pages=4096 \
+    --template=.maintain/frame-weight-template.hbs \
+    --output=pallets/subtensor/src/weight_info.rs
+
+echo "[+] Wrote pallets/subtensor/src/weight_info.rs"